@@ -0,0 +1,162 @@
+//! Derive macros for `intervals-rs`.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, GenericArgument, PathArguments, Type};
+
+/// Derive the delegation of a domain newtype wrapping an `Interval<T>`.
+///
+/// The type must be a tuple struct with a single `Interval<T>` field, such as `struct AgeRange(Interval<u8>);`.
+/// The following items are generated:
+///
+/// - the constructors `new`, `closed`, `open` and `over`
+/// - the accessors `as_interval` and `into_interval`
+/// - the relational methods `includes`, `covers`, `intersects`, `intersect`, `gap`, `is_empty` and `is_single_element`,
+///   which take and return the newtype instead of `Interval<T>`
+/// - `Display`, `AsRef<Interval<T>>` and the conversions from and into `Interval<T>`
+#[proc_macro_derive(IntervalNewtype)]
+pub fn derive_interval_newtype(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  expand(&input)
+    .unwrap_or_else(|e| e.to_compile_error())
+    .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+  let interval = interval_field_of(input)?;
+  let element = element_type_of(interval)?;
+  Ok(quote! {
+    impl #impl_generics #name #ty_generics #where_clause {
+      /// Generate an instance from an interval.
+      pub fn new(interval: #interval) -> Self {
+        Self(interval)
+      }
+
+      /// Generate an instance from a closed interval.
+      pub fn closed(lower: ::intervals_rs::LimitValue<#element>, upper: ::intervals_rs::LimitValue<#element>) -> Self {
+        Self(::intervals_rs::Interval::closed(lower, upper))
+      }
+
+      /// Generate an instance from an open interval.
+      pub fn open(lower: ::intervals_rs::LimitValue<#element>, upper: ::intervals_rs::LimitValue<#element>) -> Self {
+        Self(::intervals_rs::Interval::open(lower, upper))
+      }
+
+      /// Generate an instance from an interval with the given limits.
+      pub fn over(
+        lower: ::intervals_rs::LimitValue<#element>,
+        lower_included: bool,
+        upper: ::intervals_rs::LimitValue<#element>,
+        upper_included: bool,
+      ) -> Self {
+        Self(::intervals_rs::Interval::over(lower, lower_included, upper, upper_included))
+      }
+
+      /// Gets the wrapped interval.
+      pub fn as_interval(&self) -> &#interval {
+        &self.0
+      }
+
+      /// Return the wrapped interval.
+      pub fn into_interval(self) -> #interval {
+        self.0
+      }
+
+      /// Verify whether the specified value `value` is included in this interval.
+      pub fn includes(&self, value: &::intervals_rs::LimitValue<#element>) -> bool {
+        self.0.includes(value)
+      }
+
+      /// Verify that this interval completely encloses the specified interval `other`.
+      pub fn covers(&self, other: &Self) -> bool {
+        self.0.covers(&other.0)
+      }
+
+      /// Verify whether there is a common part between this interval and the given interval `other`.
+      pub fn intersects(&self, other: &Self) -> bool {
+        self.0.intersects(&other.0)
+      }
+
+      /// Return the product set (common part) of this interval and the given interval `other`.
+      pub fn intersect(&self, other: &Self) -> Self {
+        Self(self.0.intersect(&other.0))
+      }
+
+      /// Get the interval that lies between this interval and the given interval `other`.
+      pub fn gap(&self, other: &Self) -> Self {
+        Self(self.0.gap(&other.0))
+      }
+
+      /// Verify whether this interval is empty.
+      pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+      }
+
+      /// Verify whether this interval is a single-element interval.
+      pub fn is_single_element(&self) -> bool {
+        self.0.is_single_element()
+      }
+    }
+
+    impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+      fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::std::fmt::Display::fmt(&self.0, f)
+      }
+    }
+
+    impl #impl_generics ::std::convert::AsRef<#interval> for #name #ty_generics #where_clause {
+      fn as_ref(&self) -> &#interval {
+        &self.0
+      }
+    }
+
+    impl #impl_generics ::std::convert::From<#interval> for #name #ty_generics #where_clause {
+      fn from(interval: #interval) -> Self {
+        Self(interval)
+      }
+    }
+
+    impl #impl_generics ::std::convert::From<#name #ty_generics> for #interval #where_clause {
+      fn from(value: #name #ty_generics) -> Self {
+        value.0
+      }
+    }
+  })
+}
+
+fn interval_field_of(input: &DeriveInput) -> Result<&Type, Error> {
+  match &input.data {
+    Data::Struct(data) => match &data.fields {
+      Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Ok(&fields.unnamed[0].ty),
+      _ => Err(Error::new_spanned(
+        &input.ident,
+        "IntervalNewtype requires a tuple struct with a single Interval<T> field",
+      )),
+    },
+    _ => Err(Error::new_spanned(
+      &input.ident,
+      "IntervalNewtype can only be derived for structs",
+    )),
+  }
+}
+
+fn element_type_of(interval: &Type) -> Result<&Type, Error> {
+  if let Type::Path(path) = interval {
+    if let Some(segment) = path.path.segments.last() {
+      if segment.ident == "Interval" {
+        if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+          if let Some(GenericArgument::Type(element)) = arguments.args.first() {
+            return Ok(element);
+          }
+        }
+      }
+    }
+  }
+  Err(Error::new(
+    Span::call_site(),
+    "IntervalNewtype requires the field type to be Interval<T>",
+  ))
+}
@@ -0,0 +1,45 @@
+use intervals_rs::{Interval, LimitValue};
+use intervals_rs_derive::IntervalNewtype;
+
+#[derive(Debug, Clone, PartialEq, IntervalNewtype)]
+struct AgeRange(Interval<u8>);
+
+#[test]
+fn test01_constructors() {
+  let teens = AgeRange::closed(LimitValue::Limit(13), LimitValue::Limit(19));
+  assert_eq!(
+    teens.as_interval(),
+    &Interval::closed(LimitValue::Limit(13), LimitValue::Limit(19))
+  );
+  assert_eq!(
+    AgeRange::over(LimitValue::Limit(13), true, LimitValue::Limit(20), false),
+    AgeRange::from(Interval::over(
+      LimitValue::Limit(13),
+      true,
+      LimitValue::Limit(20),
+      false
+    ))
+  );
+  assert!(AgeRange::open(LimitValue::Limit(3), LimitValue::Limit(3)).is_empty());
+  assert_eq!(Interval::from(teens.clone()), teens.clone().into_interval());
+  assert_eq!(teens.to_string(), teens.as_interval().to_string());
+}
+
+#[test]
+fn test02_relations() {
+  let teens = AgeRange::closed(LimitValue::Limit(13), LimitValue::Limit(19));
+  let adults = AgeRange::new(Interval::and_more(LimitValue::Limit(18)));
+  assert!(teens.includes(&LimitValue::Limit(15)));
+  assert!(teens.intersects(&adults));
+  assert!(!adults.covers(&teens));
+  assert_eq!(
+    teens.intersect(&adults),
+    AgeRange::closed(LimitValue::Limit(18), LimitValue::Limit(19))
+  );
+  let children = AgeRange::closed(LimitValue::Limit(0), LimitValue::Limit(12));
+  assert_eq!(
+    children.gap(&teens),
+    AgeRange::open(LimitValue::Limit(12), LimitValue::Limit(13))
+  );
+  assert!(!children.is_single_element());
+}
@@ -0,0 +1,9 @@
+#![no_main]
+use intervals_rs_fuzz::{check_interval_laws, Spec};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Spec, Spec)| {
+  if let (Some(a), Some(b)) = (input.0.to_interval(), input.1.to_interval()) {
+    check_interval_laws(&a, &b);
+  }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+use intervals_rs_fuzz::{check_seq_laws, Spec};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Vec<Spec>, Spec)| {
+  let intervals = input
+    .0
+    .into_iter()
+    .filter_map(Spec::to_interval)
+    .collect::<Vec<_>>();
+  if let Some(bounds) = input.1.to_interval() {
+    check_seq_laws(&intervals, &bounds);
+  }
+});
@@ -0,0 +1,217 @@
+//! A brute-force reference model of intervals over a small integer domain,
+//! and the invariants that the fuzz targets check against it.
+//!
+//! Limit values are the even numbers `0, 2, ..., 2 * MAX_STEP`, so the odd sample points lie strictly between them
+//! and the sample points `-1` and `2 * MAX_STEP + 1` lie beyond them. An interval is then modeled as the set of
+//! the sample points it contains, which makes the open/closed corner cases observable with plain integers.
+use std::collections::BTreeSet;
+
+use arbitrary::Arbitrary;
+use intervals_rs::{Interval, IntervalSeq, LimitValue, TimelineSegment};
+
+/// the number of steps between the smallest and the largest limit value
+pub const MAX_STEP: u8 = 4;
+
+/// A set of sample points.
+pub type Model = BTreeSet<i32>;
+
+/// The specification of an interval generated by the fuzzer.
+#[derive(Debug, Clone, Copy, Arbitrary)]
+pub struct Spec {
+  pub lower: Option<u8>,
+  pub lower_closed: bool,
+  pub upper: Option<u8>,
+  pub upper_closed: bool,
+}
+
+impl Spec {
+  /// Build the interval, or `None` if the limits are out of order.
+  pub fn to_interval(self) -> Option<Interval<i32>> {
+    let value_of = |step: Option<u8>| match step {
+      Some(step) => LimitValue::Limit(2 * (step % (MAX_STEP + 1)) as i32),
+      None => LimitValue::Limitless,
+    };
+    let (lower, upper) = (value_of(self.lower), value_of(self.upper));
+    if let (LimitValue::Limit(l), LimitValue::Limit(u)) = (&lower, &upper) {
+      if l > u {
+        return None;
+      }
+    }
+    Some(Interval::over(
+      lower,
+      self.lower_closed,
+      upper,
+      self.upper_closed,
+    ))
+  }
+
+  /// Enumerate all the specifications.
+  pub fn all() -> Vec<Spec> {
+    let steps = std::iter::once(None).chain((0..=MAX_STEP).map(Some));
+    let mut specs = vec![];
+    for lower in steps.clone() {
+      for upper in steps.clone() {
+        for &lower_closed in &[false, true] {
+          for &upper_closed in &[false, true] {
+            specs.push(Spec {
+              lower,
+              lower_closed,
+              upper,
+              upper_closed,
+            });
+          }
+        }
+      }
+    }
+    specs
+  }
+}
+
+/// Gets all the sample points.
+pub fn samples() -> impl Iterator<Item = i32> + Clone {
+  -1..=(2 * MAX_STEP as i32 + 1)
+}
+
+/// Model an interval by checking each sample point against its limits, without using `Interval::includes`.
+pub fn model_of(interval: &Interval<i32>) -> Model {
+  if interval.is_empty() {
+    return Model::new();
+  }
+  samples()
+    .filter(|p| {
+      let above_lower = match interval.as_lower_limit() {
+        LimitValue::Limit(l) if interval.includes_lower_limit() => p >= l,
+        LimitValue::Limit(l) => p > l,
+        LimitValue::Limitless => true,
+      };
+      let below_upper = match interval.as_upper_limit() {
+        LimitValue::Limit(u) if interval.includes_upper_limit() => p <= u,
+        LimitValue::Limit(u) => p < u,
+        LimitValue::Limitless => true,
+      };
+      above_lower && below_upper
+    })
+    .collect()
+}
+
+/// Model the union of the given intervals.
+pub fn union_of<'a>(intervals: impl IntoIterator<Item = &'a Interval<i32>>) -> Model {
+  intervals.into_iter().flat_map(model_of).collect()
+}
+
+/// Model the parts of the given interval `bounds` that are not covered by the given interval, using `timeline`.
+fn uncovered_of(interval: &Interval<i32>, bounds: &Interval<i32>) -> Model {
+  IntervalSeq::new(vec![interval.clone()])
+    .timeline(bounds)
+    .filter(|e| !e.is_covered())
+    .flat_map(|e| model_of(e.as_interval()))
+    .collect()
+}
+
+/// Check the laws of the operations between two intervals.
+pub fn check_interval_laws(a: &Interval<i32>, b: &Interval<i32>) {
+  let (ma, mb) = (model_of(a), model_of(b));
+  for p in samples() {
+    assert_eq!(a.includes(&LimitValue::Limit(p)), ma.contains(&p), "{} includes {}", a, p);
+  }
+  let intersection = ma.intersection(&mb).cloned().collect::<Model>();
+  assert_eq!(model_of(&a.intersect(b)), intersection, "{} intersect {}", a, b);
+  assert_eq!(a.intersect(b), b.intersect(a), "{} intersect {} is symmetric", a, b);
+  assert_eq!(a.intersects(b), !intersection.is_empty(), "{} intersects {}", a, b);
+  if !b.is_empty() {
+    assert_eq!(a.covers(b), mb.is_subset(&ma), "{} covers {}", a, b);
+  }
+  if !a.intersects(b) {
+    let gap = a.gap(b);
+    assert_eq!(model_of(&gap), model_of(&b.gap(a)), "{} gap {} is symmetric", a, b);
+    assert!(model_of(&gap).is_disjoint(&ma) && model_of(&gap).is_disjoint(&mb));
+  }
+
+  let union = ma.union(&mb).cloned().collect::<Model>();
+  let coalesced = IntervalSeq::new(vec![a.clone(), b.clone()]).coalesce();
+  assert_eq!(union_of(coalesced.as_slice()), union, "union of {} and {}", a, b);
+  let difference = ma.difference(&mb).cloned().collect::<Model>();
+  assert_eq!(uncovered_of(b, a), difference, "{} minus {}", a, b);
+  let all = Interval::closed(LimitValue::Limitless, LimitValue::Limitless);
+  let complement = samples().filter(|p| !ma.contains(p)).collect::<Model>();
+  assert_eq!(uncovered_of(a, &all), complement, "complement of {}", a);
+
+  let merged = a.union(b);
+  assert_eq!(union_of(merged.as_slice()), union, "{} union {}", a, b);
+  if !a.is_empty() && !b.is_empty() && (a.intersects(b) || a.abuts(b)) {
+    assert_eq!(merged.len(), 1, "{} union {} is merged", a, b);
+  }
+  let (covered, uncovered) = a.split_by(&IntervalSeq::new(vec![b.clone()]));
+  assert_eq!(union_of(covered.as_slice()), intersection, "{} split by {} (covered)", a, b);
+  assert_eq!(union_of(uncovered.as_slice()), difference, "{} split by {} (uncovered)", a, b);
+  assert_eq!(union_of(a.subtract(b).as_slice()), difference, "{} subtract {}", a, b);
+  assert_eq!(union_of(a.complement().as_slice()), complement, "{} complement", a);
+  let symmetric_difference = ma.symmetric_difference(&mb).cloned().collect::<Model>();
+  assert_eq!(
+    union_of(a.symmetric_difference(b).as_slice()),
+    symmetric_difference,
+    "{} symmetric difference {}",
+    a,
+    b
+  );
+}
+
+/// Check the laws of the set operations of an interval sequence against the given bounds.
+pub fn check_seq_laws(intervals: &[Interval<i32>], bounds: &Interval<i32>) {
+  let union = union_of(intervals);
+  let seq = IntervalSeq::new(intervals.iter().cloned());
+  let coalesced = seq.coalesce().into_vec();
+  assert_eq!(union_of(&coalesced), union, "coalesce of {:?}", intervals);
+  for (x, y) in coalesced.iter().zip(coalesced.iter().skip(1)) {
+    assert!(!x.intersects(y), "coalesced {} and {} intersect", x, y);
+  }
+
+  let (mut covered, mut uncovered) = (Model::new(), Model::new());
+  for segment in seq.timeline(bounds) {
+    let model = model_of(segment.as_interval());
+    let target = match segment {
+      TimelineSegment::Covered(_) => &mut covered,
+      TimelineSegment::Uncovered(_) => &mut uncovered,
+    };
+    assert!(target.is_disjoint(&model), "timeline of {:?} overlaps", intervals);
+    target.extend(model);
+  }
+  let mb = model_of(bounds);
+  let difference = mb.difference(&union).cloned().collect::<Model>();
+  let intersection = mb.intersection(&union).cloned().collect::<Model>();
+  assert_eq!(covered, intersection, "covered part of {} by {:?}", bounds, intervals);
+  assert_eq!(uncovered, difference, "uncovered part of {} by {:?}", bounds, intervals);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn exhaustive_interval_laws() {
+    let intervals = Spec::all()
+      .into_iter()
+      .filter_map(Spec::to_interval)
+      .collect::<Vec<_>>();
+    for a in &intervals {
+      for b in &intervals {
+        check_interval_laws(a, b);
+      }
+    }
+  }
+
+  #[test]
+  fn exhaustive_seq_laws() {
+    let intervals = Spec::all()
+      .into_iter()
+      .filter_map(Spec::to_interval)
+      .collect::<Vec<_>>();
+    let all = Interval::closed(LimitValue::Limitless, LimitValue::Limitless);
+    for a in &intervals {
+      for b in &intervals {
+        check_seq_laws(&[a.clone(), b.clone()], &all);
+        check_seq_laws(std::slice::from_ref(a), b);
+      }
+    }
+  }
+}
@@ -0,0 +1,9 @@
+/// An interval of signed integers.
+pub type IntInterval = crate::Interval<i64>;
+
+/// An interval of positions, e.g. indices into a buffer or offsets into a text.
+pub type Span = crate::Interval<usize>;
+
+/// An interval of calendar dates.
+#[cfg(feature = "chrono")]
+pub type DateInterval = crate::Interval<chrono::NaiveDate>;
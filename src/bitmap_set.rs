@@ -0,0 +1,232 @@
+use crate::discrete::closed_run_of;
+use crate::{Interval, IntervalSeq, LimitValue};
+
+const WORD_BITS: u32 = 64;
+
+/// A structure that represents a set of `u32` values in the bounded domain `[0, size)`, backed by a bitmap.
+///
+/// Membership checks and bulk set operations are performed with a constant number of word operations,
+/// which is faster than `RangeSet` or `IntervalSeq` for dense sets over a small domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitmapSet {
+  /// the number of values in the domain
+  size: u32,
+  /// bits of the values, from the least significant bit of the first word
+  words: Vec<u64>,
+}
+
+impl BitmapSet {
+  /// Generate an empty set over the domain `[0, size)`.
+  ///
+  /// - params
+  ///     - size: the number of values in the domain
+  /// - return: `BitmapSet`
+  pub fn new(size: u32) -> Self {
+    Self {
+      size,
+      words: vec![0; (size as usize).div_ceil(WORD_BITS as usize)],
+    }
+  }
+
+  /// Generate a set over the domain `[0, size)` from the given interval sequence.
+  ///
+  /// The values outside the domain are ignored.
+  ///
+  /// - params
+  ///     - size: the number of values in the domain
+  ///     - seq: an interval sequence
+  /// - return: `BitmapSet`
+  pub fn from_interval_seq(size: u32, seq: &IntervalSeq<u32>) -> Self {
+    let mut result = Self::new(size);
+    for interval in seq.as_slice() {
+      result.insert_range(interval);
+    }
+    result
+  }
+
+  /// Gets the number of values in the domain of this set.
+  pub fn size(&self) -> u32 {
+    self.size
+  }
+
+  /// Return whether this set is empty.
+  pub fn is_empty(&self) -> bool {
+    self.words.iter().all(|e| *e == 0)
+  }
+
+  /// Gets the number of values in this set.
+  pub fn len(&self) -> usize {
+    self.words.iter().map(|e| e.count_ones() as usize).sum()
+  }
+
+  /// Verify whether the specified value `value` is included in this set.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `true` if included, `false` otherwise (including values outside the domain)
+  pub fn contains(&self, value: u32) -> bool {
+    value < self.size && self.words[(value / WORD_BITS) as usize] & (1 << (value % WORD_BITS)) != 0
+  }
+
+  /// Add a value to this set.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `true` if the value was not included yet, `false` otherwise
+  /// - panic
+  ///     - if `value` is outside the domain
+  pub fn insert(&mut self, value: u32) -> bool {
+    self.check_domain(value);
+    let included = self.contains(value);
+    self.words[(value / WORD_BITS) as usize] |= 1 << (value % WORD_BITS);
+    !included
+  }
+
+  /// Remove a value from this set.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `true` if the value was included, `false` otherwise
+  pub fn remove(&mut self, value: u32) -> bool {
+    let included = self.contains(value);
+    if included {
+      self.words[(value / WORD_BITS) as usize] &= !(1 << (value % WORD_BITS));
+    }
+    included
+  }
+
+  /// Add all the values of the domain included in the given interval `interval` to this set.
+  ///
+  /// - params
+  ///     - interval: an interval
+  pub fn insert_range(&mut self, interval: &Interval<u32>) {
+    if let Some((start, end)) = self.clamp(interval) {
+      self.fill(start, end, true);
+    }
+  }
+
+  /// Remove all the values included in the given interval `interval` from this set.
+  ///
+  /// - params
+  ///     - interval: an interval
+  pub fn remove_range(&mut self, interval: &Interval<u32>) {
+    if let Some((start, end)) = self.clamp(interval) {
+      self.fill(start, end, false);
+    }
+  }
+
+  /// Return the union of this set and the given set `other`.
+  ///
+  /// - panic
+  ///     - if the domains of the sets differ
+  pub fn union(&self, other: &Self) -> Self {
+    self.combine(other, |a, b| a | b)
+  }
+
+  /// Return the intersection of this set and the given set `other`.
+  ///
+  /// - panic
+  ///     - if the domains of the sets differ
+  pub fn intersection(&self, other: &Self) -> Self {
+    self.combine(other, |a, b| a & b)
+  }
+
+  /// Return the difference of this set and the given set `other` (the values of this set not included in `other`).
+  ///
+  /// - panic
+  ///     - if the domains of the sets differ
+  pub fn difference(&self, other: &Self) -> Self {
+    self.combine(other, |a, b| a & !b)
+  }
+
+  /// Gets an iterator of the maximal runs of this set as closed intervals, in ascending order.
+  pub fn iter(&self) -> impl Iterator<Item = Interval<u32>> + '_ {
+    let mut from = 0;
+    std::iter::from_fn(move || {
+      let start = self.next_with(from, true)?;
+      let end = self.next_with(start, false).unwrap_or(self.size) - 1;
+      from = end + 1;
+      Some(Interval::closed(
+        LimitValue::Limit(start),
+        LimitValue::Limit(end),
+      ))
+    })
+  }
+
+  /// Return the maximal runs of this set as an interval sequence.
+  pub fn to_interval_seq(&self) -> IntervalSeq<u32> {
+    IntervalSeq::new(self.iter())
+  }
+
+  fn check_domain(&self, value: u32) {
+    if value >= self.size {
+      panic!("{} is outside the domain [0, {})", value, self.size)
+    }
+  }
+
+  fn clamp(&self, interval: &Interval<u32>) -> Option<(u32, u32)> {
+    let (start, end) = closed_run_of(interval)?;
+    if self.size == 0 || start >= self.size {
+      None
+    } else {
+      Some((start, end.min(self.size - 1)))
+    }
+  }
+
+  fn fill(&mut self, start: u32, end: u32, value: bool) {
+    for index in (start / WORD_BITS)..=(end / WORD_BITS) {
+      let from = if index == start / WORD_BITS {
+        start % WORD_BITS
+      } else {
+        0
+      };
+      let to = if index == end / WORD_BITS {
+        end % WORD_BITS
+      } else {
+        WORD_BITS - 1
+      };
+      let mask = (u64::MAX >> (WORD_BITS - 1 - to)) & (u64::MAX << from);
+      if value {
+        self.words[index as usize] |= mask;
+      } else {
+        self.words[index as usize] &= !mask;
+      }
+    }
+  }
+
+  fn combine(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+    if self.size != other.size {
+      panic!("the domain sizes differ: {} and {}", self.size, other.size)
+    }
+    Self {
+      size: self.size,
+      words: self
+        .words
+        .iter()
+        .zip(other.words.iter())
+        .map(|(a, b)| f(*a, *b))
+        .collect(),
+    }
+  }
+
+  /// Find the first value at or after `from` whose membership is `value`.
+  fn next_with(&self, from: u32, value: bool) -> Option<u32> {
+    let mut index = from / WORD_BITS;
+    let mut skip = from % WORD_BITS;
+    while (index as usize) < self.words.len() {
+      let word = if value {
+        self.words[index as usize]
+      } else {
+        !self.words[index as usize]
+      };
+      let masked = word & (u64::MAX << skip);
+      if masked != 0 {
+        let found = index * WORD_BITS + masked.trailing_zeros();
+        return if found < self.size { Some(found) } else { None };
+      }
+      index += 1;
+      skip = 0;
+    }
+    None
+  }
+}
@@ -0,0 +1,80 @@
+use crate::{BitmapSet, Interval, IntervalSeq, LimitValue};
+
+fn closed(lower: u32, upper: u32) -> Interval<u32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_insert_and_remove() {
+  let mut set = BitmapSet::new(100);
+  assert!(set.is_empty());
+  assert!(set.insert(3));
+  assert!(!set.insert(3));
+  assert!(set.insert(64));
+  assert!(set.contains(3));
+  assert!(set.contains(64));
+  assert!(!set.contains(4));
+  assert!(!set.contains(1000));
+  assert_eq!(set.len(), 2);
+  assert!(set.remove(3));
+  assert!(!set.remove(3));
+  assert_eq!(set.iter().collect::<Vec<_>>(), vec![closed(64, 64)]);
+}
+
+#[test]
+#[should_panic]
+fn test02_insert_outside_domain() {
+  BitmapSet::new(10).insert(10);
+}
+
+#[test]
+fn test03_insert_range() {
+  let mut set = BitmapSet::new(200);
+  set.insert_range(&closed(10, 130));
+  set.insert_range(&Interval::and_more(LimitValue::Limit(190)));
+  assert_eq!(set.len(), 121 + 10);
+  set.remove_range(&Interval::open(
+    LimitValue::Limit(20),
+    LimitValue::Limit(64),
+  ));
+  assert_eq!(
+    set.iter().collect::<Vec<_>>(),
+    vec![closed(10, 20), closed(64, 130), closed(190, 199)]
+  );
+}
+
+#[test]
+fn test04_set_algebra() {
+  let mut a = BitmapSet::new(128);
+  a.insert_range(&closed(0, 10));
+  a.insert_range(&closed(60, 70));
+  let mut b = BitmapSet::new(128);
+  b.insert_range(&closed(5, 65));
+
+  assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), vec![closed(0, 70)]);
+  assert_eq!(
+    a.intersection(&b).iter().collect::<Vec<_>>(),
+    vec![closed(5, 10), closed(60, 65)]
+  );
+  assert_eq!(
+    a.difference(&b).iter().collect::<Vec<_>>(),
+    vec![closed(0, 4), closed(66, 70)]
+  );
+}
+
+#[test]
+fn test05_interval_seq_conversion() {
+  let seq = IntervalSeq::new(vec![
+    closed(1, 3),
+    Interval::over(LimitValue::Limit(3), false, LimitValue::Limit(8), false),
+    closed(40, 60),
+  ]);
+  let set = BitmapSet::from_interval_seq(50, &seq);
+  assert_eq!(
+    set.iter().collect::<Vec<_>>(),
+    vec![closed(1, 7), closed(40, 49)]
+  );
+  assert!(set
+    .to_interval_seq()
+    .set_eq(&IntervalSeq::new(vec![closed(1, 7), closed(40, 49)])));
+}
@@ -0,0 +1,163 @@
+use std::fmt::Debug;
+
+use crate::{Interval, IntervalSeq};
+
+/// A node of a `ContainmentForest`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainmentNode<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// the interval of this node
+  interval: Interval<T>,
+  /// the id of the parent node
+  parent: Option<usize>,
+  /// the ids of the child nodes in ascending order
+  children: Vec<usize>,
+  /// the number of ancestors
+  depth: usize,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> ContainmentNode<T> {
+  /// Gets the interval of this node.
+  pub fn as_interval(&self) -> &Interval<T> {
+    &self.interval
+  }
+
+  /// Gets the id of the parent node, or `None` for a root.
+  pub fn parent(&self) -> Option<usize> {
+    self.parent
+  }
+
+  /// Gets the ids of the child nodes in ascending order of their lower limits.
+  pub fn children(&self) -> &[usize] {
+    &self.children
+  }
+
+  /// Gets the number of ancestors of this node, `0` for a root.
+  pub fn depth(&self) -> usize {
+    self.depth
+  }
+}
+
+/// A structure that organizes intervals into a forest by containment.
+///
+/// The interval of each node is covered by the interval of its parent. The ids of the nodes are their positions
+/// in ascending order of the lower limits, so a parent always has a smaller id than its children.
+/// When intervals overlap without nesting, a node is attached to the most recently started interval that covers it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainmentForest<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// nodes in ascending order of the lower limits
+  nodes: Vec<ContainmentNode<T>>,
+  /// the ids of the root nodes
+  roots: Vec<usize>,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> ContainmentForest<T> {
+  /// Generate a containment forest from intervals.
+  ///
+  /// Empty intervals are ignored, and equal intervals are nested in each other.
+  ///
+  /// - params
+  ///     - intervals: intervals
+  /// - return: `ContainmentForest`
+  pub fn new(intervals: impl IntoIterator<Item = Interval<T>>) -> Self {
+    let mut sorted = intervals
+      .into_iter()
+      .filter(|e| !e.is_empty())
+      .collect::<Vec<Interval<T>>>();
+    sorted.sort_by(|a, b| a.lower.cmp(&b.lower).then_with(|| b.upper.cmp(&a.upper)));
+    let mut nodes: Vec<ContainmentNode<T>> = Vec::with_capacity(sorted.len());
+    let mut roots = vec![];
+    let mut open: Vec<usize> = vec![];
+    for interval in sorted {
+      while let Some(top) = open.last() {
+        if nodes[*top].interval.covers(&interval) {
+          break;
+        }
+        open.pop();
+      }
+      let id = nodes.len();
+      let parent = open.last().copied();
+      match parent {
+        Some(parent) => nodes[parent].children.push(id),
+        None => roots.push(id),
+      }
+      nodes.push(ContainmentNode {
+        interval,
+        parent,
+        children: vec![],
+        depth: open.len(),
+      });
+      open.push(id);
+    }
+    Self { nodes, roots }
+  }
+
+  /// Return whether this forest has no nodes.
+  pub fn is_empty(&self) -> bool {
+    self.nodes.is_empty()
+  }
+
+  /// Gets the number of nodes.
+  pub fn len(&self) -> usize {
+    self.nodes.len()
+  }
+
+  /// Gets the ids of the root nodes in ascending order of their lower limits.
+  pub fn roots(&self) -> &[usize] {
+    &self.roots
+  }
+
+  /// Gets the node of the given id.
+  ///
+  /// - params
+  ///     - id: a node id
+  /// - return: the node, or `None` if the id is out of bounds
+  pub fn node(&self, id: usize) -> Option<&ContainmentNode<T>> {
+    self.nodes.get(id)
+  }
+
+  /// Gets an iterator of the ancestors of the given node, from its parent up to its root.
+  ///
+  /// - params
+  ///     - id: a node id
+  pub fn ancestors(&self, id: usize) -> impl Iterator<Item = (usize, &ContainmentNode<T>)> {
+    let mut current = self.nodes.get(id).and_then(|e| e.parent);
+    std::iter::from_fn(move || {
+      let id = current?;
+      current = self.nodes[id].parent;
+      Some((id, &self.nodes[id]))
+    })
+  }
+
+  /// Gets an iterator of all the nodes in depth-first pre-order, i.e. each parent before its children.
+  ///
+  /// Since a parent always starts no later than its children, this is the ascending order of the ids.
+  pub fn iter(&self) -> impl Iterator<Item = (usize, &ContainmentNode<T>)> {
+    self.nodes.iter().enumerate()
+  }
+
+  /// Gets an iterator of the descendants of the given node in depth-first pre-order.
+  ///
+  /// - params
+  ///     - id: a node id
+  pub fn descendants(&self, id: usize) -> impl Iterator<Item = (usize, &ContainmentNode<T>)> {
+    let mut stack = self
+      .nodes
+      .get(id)
+      .map(|e| e.children.iter().rev().copied().collect::<Vec<usize>>())
+      .unwrap_or_default();
+    std::iter::from_fn(move || {
+      let id = stack.pop()?;
+      stack.extend(self.nodes[id].children.iter().rev());
+      Some((id, &self.nodes[id]))
+    })
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalSeq<T> {
+  /// Organize the element intervals into a forest by containment.
+  ///
+  /// - return: `ContainmentForest`
+  pub fn containment_forest(&self) -> ContainmentForest<T> {
+    ContainmentForest::new(self.as_slice().iter().cloned())
+  }
+}
@@ -0,0 +1,56 @@
+use crate::{ContainmentForest, Interval, IntervalSeq, LimitValue};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_nesting() {
+  let spans = IntervalSeq::new(vec![
+    closed(2, 4),
+    closed(0, 10),
+    closed(20, 30),
+    closed(1, 5),
+    closed(6, 9),
+    closed(22, 25),
+    Interval::open(LimitValue::Limit(7), LimitValue::Limit(7)),
+  ]);
+  let forest = spans.containment_forest();
+  assert_eq!(forest.len(), 6);
+  assert_eq!(forest.roots(), &[0, 4]);
+
+  let intervals = forest
+    .iter()
+    .map(|(_, e)| (e.as_interval().clone(), e.depth()))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    intervals,
+    vec![
+      (closed(0, 10), 0),
+      (closed(1, 5), 1),
+      (closed(2, 4), 2),
+      (closed(6, 9), 1),
+      (closed(20, 30), 0),
+      (closed(22, 25), 1),
+    ]
+  );
+  assert_eq!(forest.node(0).unwrap().children(), &[1, 3]);
+  assert_eq!(forest.node(2).unwrap().parent(), Some(1));
+  assert_eq!(
+    forest.ancestors(2).map(|(id, _)| id).collect::<Vec<_>>(),
+    vec![1, 0]
+  );
+  assert_eq!(
+    forest.descendants(0).map(|(id, _)| id).collect::<Vec<_>>(),
+    vec![1, 2, 3]
+  );
+  assert!(forest.node(6).is_none());
+}
+
+#[test]
+fn test02_overlapping_without_nesting() {
+  let forest = ContainmentForest::new(vec![closed(0, 5), closed(3, 8), closed(4, 5)]);
+  assert_eq!(forest.roots(), &[0, 1]);
+  assert_eq!(forest.node(2).unwrap().parent(), Some(1));
+  assert!(ContainmentForest::<i32>::new(vec![]).is_empty());
+}
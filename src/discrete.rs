@@ -0,0 +1,161 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::{Interval, LimitValue};
+
+/// A trait for element types whose values are discrete, i.e. each value has an immediate successor and predecessor.
+pub trait Discrete: Sized {
+  /// Get the smallest value of this type.
+  fn min_value() -> Self;
+
+  /// Get the largest value of this type.
+  fn max_value() -> Self;
+
+  /// Get the value immediately after this value.
+  ///
+  /// - return: the successor, or `None` if this value is the largest value
+  fn succ(&self) -> Option<Self>;
+
+  /// Get the value immediately before this value.
+  ///
+  /// - return: the predecessor, or `None` if this value is the smallest value
+  fn pred(&self) -> Option<Self>;
+}
+
+macro_rules! impl_discrete {
+  ($($t:ty),*) => {
+    $(
+      impl Discrete for $t {
+        fn min_value() -> Self {
+          <$t>::MIN
+        }
+
+        fn max_value() -> Self {
+          <$t>::MAX
+        }
+
+        fn succ(&self) -> Option<Self> {
+          self.checked_add(1)
+        }
+
+        fn pred(&self) -> Option<Self> {
+          self.checked_sub(1)
+        }
+      }
+    )*
+  };
+}
+
+impl_discrete!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Return the first and the last values included in the given interval `interval` of discrete values.
+///
+/// - params
+///     - interval: an interval
+/// - return: the first and the last values, or `None` if no value is included
+pub(crate) fn closed_run_of<T>(interval: &Interval<T>) -> Option<(T, T)>
+where
+  T: Discrete + Clone + Eq + Ord + PartialEq + PartialOrd,
+{
+  if interval.is_empty() {
+    return None;
+  }
+  let start = match interval.as_lower_limit() {
+    LimitValue::Limit(value) if interval.includes_lower_limit() => value.clone(),
+    LimitValue::Limit(value) => value.succ()?,
+    LimitValue::Limitless => T::min_value(),
+  };
+  let end = match interval.as_upper_limit() {
+    LimitValue::Limit(value) if interval.includes_upper_limit() => value.clone(),
+    LimitValue::Limit(value) => value.pred()?,
+    LimitValue::Limitless => T::max_value(),
+  };
+  if start <= end {
+    Some((start, end))
+  } else {
+    None
+  }
+}
+
+/// A policy on how the limits of a continuous interval are rounded to integers.
+///
+/// Rounding inward excludes an open limit that is already an integer, while rounding outward keeps it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiscretizePolicy {
+  /// round both limits down, i.e. the lower limit outward and the upper limit inward
+  Floor,
+  /// round both limits up, i.e. the lower limit inward and the upper limit outward
+  Ceil,
+  /// round both limits outward, i.e. the smallest integer interval that covers the interval
+  Expand,
+  /// round both limits inward, i.e. the integers included in the interval
+  Shrink,
+}
+
+impl<T> Interval<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + ToPrimitive,
+{
+  /// Convert this continuous interval into a closed interval of integers.
+  ///
+  /// Limitless limits stay Limitless.
+  ///
+  /// - params
+  ///     - policy: how the limits are rounded
+  /// - return: the interval of integers, or `None` if it contains no integer or a limit does not fit in `I`
+  pub fn to_integer_interval<I>(&self, policy: DiscretizePolicy) -> Option<Interval<I>>
+  where
+    I: Discrete + Clone + Eq + Ord + PartialEq + PartialOrd + NumCast,
+    T: From<I>,
+  {
+    let (lower_down, upper_down) = match policy {
+      DiscretizePolicy::Floor => (true, true),
+      DiscretizePolicy::Ceil => (false, false),
+      DiscretizePolicy::Expand => (true, false),
+      DiscretizePolicy::Shrink => (false, true),
+    };
+    let lower = match self.as_lower_limit() {
+      LimitValue::Limit(value) => LimitValue::Limit(Self::round(
+        value,
+        lower_down,
+        !lower_down && !self.includes_lower_limit(),
+      )?),
+      LimitValue::Limitless => LimitValue::Limitless,
+    };
+    let upper = match self.as_upper_limit() {
+      LimitValue::Limit(value) => LimitValue::Limit(Self::round(
+        value,
+        upper_down,
+        upper_down && !self.includes_upper_limit(),
+      )?),
+      LimitValue::Limitless => LimitValue::Limitless,
+    };
+    if lower.is_limit() && upper.is_limit() && lower > upper {
+      return None;
+    }
+    Some(Interval::closed(lower, upper))
+  }
+
+  /// Round `value` to an integer, down or up, moving past it if it is an integer and `open`.
+  fn round<I>(value: &T, down: bool, open: bool) -> Option<I>
+  where
+    I: Discrete + Clone + NumCast,
+    T: From<I>,
+  {
+    let truncated: I = NumCast::from(value.clone())?;
+    let back = T::from(truncated.clone());
+    if down {
+      if back > *value || (back == *value && open) {
+        truncated.pred()
+      } else {
+        Some(truncated)
+      }
+    } else if back < *value || (back == *value && open) {
+      truncated.succ()
+    } else {
+      Some(truncated)
+    }
+  }
+}
@@ -0,0 +1,53 @@
+use rust_decimal::Decimal;
+
+use crate::{DiscretizePolicy, Interval, LimitValue};
+
+fn decimal(value: &str) -> LimitValue<Decimal> {
+  LimitValue::Limit(value.parse().unwrap())
+}
+
+fn closed(lower: i64, upper: i64) -> Interval<i64> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_to_integer_interval() {
+  let interval = Interval::over(decimal("-1.5"), true, decimal("2.5"), false);
+  let convert = |policy| interval.to_integer_interval::<i64>(policy);
+  assert_eq!(convert(DiscretizePolicy::Floor), Some(closed(-2, 2)));
+  assert_eq!(convert(DiscretizePolicy::Ceil), Some(closed(-1, 3)));
+  assert_eq!(convert(DiscretizePolicy::Expand), Some(closed(-2, 3)));
+  assert_eq!(convert(DiscretizePolicy::Shrink), Some(closed(-1, 2)));
+}
+
+#[test]
+fn test02_open_integer_limits() {
+  let interval = Interval::open(decimal("1"), decimal("5"));
+  let convert = |policy| interval.to_integer_interval::<i64>(policy);
+  assert_eq!(convert(DiscretizePolicy::Shrink), Some(closed(2, 4)));
+  assert_eq!(convert(DiscretizePolicy::Expand), Some(closed(1, 5)));
+  assert_eq!(convert(DiscretizePolicy::Floor), Some(closed(1, 4)));
+  assert_eq!(convert(DiscretizePolicy::Ceil), Some(closed(2, 5)));
+}
+
+#[test]
+fn test03_no_integers() {
+  let interval = Interval::closed(decimal("1.2"), decimal("1.8"));
+  assert_eq!(
+    interval.to_integer_interval::<i64>(DiscretizePolicy::Shrink),
+    None
+  );
+  assert_eq!(
+    interval.to_integer_interval::<i64>(DiscretizePolicy::Expand),
+    Some(closed(1, 2))
+  );
+  assert_eq!(
+    Interval::and_more(decimal("0.5")).to_integer_interval::<u8>(DiscretizePolicy::Shrink),
+    Some(Interval::and_more(LimitValue::Limit(1u8)))
+  );
+  assert_eq!(
+    Interval::closed(decimal("300"), decimal("400"))
+      .to_integer_interval::<u8>(DiscretizePolicy::Shrink),
+    None
+  );
+}
@@ -0,0 +1,190 @@
+//! A compact binary encoding of intervals and interval sequences, independent of serde.
+//!
+//! All multi-byte numbers are little-endian. The layout of version 1 is as follows:
+//!
+//! - `Interval`: the version byte `1`, followed by the interval body
+//! - `IntervalSeq`: the version byte `1`, the number of intervals as `u32`, followed by the interval bodies
+//!   in the storage order
+//! - interval body: a flags byte, followed by the encoded lower limit value unless it is Limitless,
+//!   and the encoded upper limit value unless it is Limitless
+//! - flags byte: bit 0 is set if the lower limit is closed, bit 1 if the lower limit is Limitless,
+//!   bit 2 if the upper limit is closed, bit 3 if the upper limit is Limitless; the other bits are zero
+//! - limit value: the encoding of `Encode`, e.g. 4 bytes for `i32`
+use std::convert::{TryFrom, TryInto};
+use std::ops::Bound;
+
+use crate::{Error, Interval, IntervalSeq, LimitValue};
+
+/// The version of the encoding written by `to_bytes`.
+pub const ENCODING_VERSION: u8 = 1;
+
+const LOWER_CLOSED: u8 = 1;
+const LOWER_LIMITLESS: u8 = 1 << 1;
+const UPPER_CLOSED: u8 = 1 << 2;
+const UPPER_LIMITLESS: u8 = 1 << 3;
+
+/// A trait for values that have a stable binary encoding.
+pub trait Encode: Sized {
+  /// Append the encoding of this value to `out`.
+  ///
+  /// - params
+  ///     - out: a buffer
+  fn encode(&self, out: &mut Vec<u8>);
+
+  /// Decode a value from the head of `input`, advancing it past the consumed bytes.
+  ///
+  /// - params
+  ///     - input: the remaining bytes
+  /// - return: the value, or `Err(Error::Decode)` if the bytes are malformed
+  fn decode(input: &mut &[u8]) -> Result<Self, Error>;
+}
+
+macro_rules! impl_encode {
+  ($($t:ty),*) => {
+    $(
+      impl Encode for $t {
+        fn encode(&self, out: &mut Vec<u8>) {
+          out.extend_from_slice(&self.to_le_bytes());
+        }
+
+        fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+          let bytes = take(input, std::mem::size_of::<$t>())?;
+          Ok(<$t>::from_le_bytes(bytes.try_into().unwrap()))
+        }
+      }
+    )*
+  };
+}
+
+impl_encode!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+  if input.len() < len {
+    return Err(Error::Decode(format!(
+      "expected {} more bytes, found {}",
+      len,
+      input.len()
+    )));
+  }
+  let (head, tail) = input.split_at(len);
+  *input = tail;
+  Ok(head)
+}
+
+fn decode_version(input: &mut &[u8]) -> Result<(), Error> {
+  let version = u8::decode(input)?;
+  if version == ENCODING_VERSION {
+    Ok(())
+  } else {
+    Err(Error::Decode(format!("unsupported version {}", version)))
+  }
+}
+
+fn check_consumed(input: &[u8]) -> Result<(), Error> {
+  if input.is_empty() {
+    Ok(())
+  } else {
+    Err(Error::Decode(format!("{} trailing bytes", input.len())))
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Encode> Interval<T> {
+  /// Encode this interval in the binary encoding described in the `encoding` module.
+  ///
+  /// - return: the encoded bytes
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut out = vec![ENCODING_VERSION];
+    self.encode_body(&mut out);
+    out
+  }
+
+  /// Decode an interval from the bytes written by `to_bytes`.
+  ///
+  /// - params
+  ///     - bytes: the encoded bytes
+  /// - return: the interval, or `Err(Error::Decode)` if the bytes are malformed
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+    let mut input = bytes;
+    decode_version(&mut input)?;
+    let result = Self::decode_body(&mut input)?;
+    check_consumed(input)?;
+    Ok(result)
+  }
+
+  fn encode_body(&self, out: &mut Vec<u8>) {
+    let mut flags = 0;
+    if self.includes_lower_limit() {
+      flags |= LOWER_CLOSED;
+    }
+    if !self.has_lower_limit() {
+      flags |= LOWER_LIMITLESS;
+    }
+    if self.includes_upper_limit() {
+      flags |= UPPER_CLOSED;
+    }
+    if !self.has_upper_limit() {
+      flags |= UPPER_LIMITLESS;
+    }
+    out.push(flags);
+    for limit in [self.as_lower_limit(), self.as_upper_limit()] {
+      if let LimitValue::Limit(value) = limit {
+        value.encode(out);
+      }
+    }
+  }
+
+  fn decode_body(input: &mut &[u8]) -> Result<Self, Error> {
+    let flags = u8::decode(input)?;
+    if flags & !(LOWER_CLOSED | LOWER_LIMITLESS | UPPER_CLOSED | UPPER_LIMITLESS) != 0 {
+      return Err(Error::Decode(format!("invalid flags {:#04x}", flags)));
+    }
+    let mut bound_of = |closed: u8, limitless: u8| -> Result<Bound<T>, Error> {
+      if flags & limitless != 0 {
+        Ok(Bound::Unbounded)
+      } else if flags & closed != 0 {
+        Ok(Bound::Included(T::decode(input)?))
+      } else {
+        Ok(Bound::Excluded(T::decode(input)?))
+      }
+    };
+    let lower = bound_of(LOWER_CLOSED, LOWER_LIMITLESS)?;
+    let upper = bound_of(UPPER_CLOSED, UPPER_LIMITLESS)?;
+    Self::from_bounds(lower, upper)
+      .map_err(|_| Error::Decode("the upper limit is below the lower limit".to_string()))
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Encode> IntervalSeq<T> {
+  /// Encode this interval sequence in the binary encoding described in the `encoding` module.
+  ///
+  /// - return: the encoded bytes
+  /// - panic
+  ///     - if there are more than `u32::MAX` intervals
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut out = vec![ENCODING_VERSION];
+    u32::try_from(self.len())
+      .expect("too many intervals to encode")
+      .encode(&mut out);
+    for interval in self.as_slice() {
+      interval.encode_body(&mut out);
+    }
+    out
+  }
+
+  /// Decode an interval sequence from the bytes written by `to_bytes`.
+  ///
+  /// - params
+  ///     - bytes: the encoded bytes
+  /// - return: the interval sequence, or `Err(Error::Decode)` if the bytes are malformed
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+    let mut input = bytes;
+    decode_version(&mut input)?;
+    let len = u32::decode(&mut input)?;
+    let mut intervals = vec![];
+    for _ in 0..len {
+      intervals.push(Interval::decode_body(&mut input)?);
+    }
+    check_consumed(input)?;
+    Ok(Self::from_vec(intervals))
+  }
+}
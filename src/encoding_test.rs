@@ -0,0 +1,61 @@
+use crate::{Error, Interval, IntervalSeq, LimitValue};
+
+#[test]
+fn test01_interval_layout() {
+  let interval = Interval::over(LimitValue::Limit(1i32), true, LimitValue::Limit(258), false);
+  assert_eq!(interval.to_bytes(), vec![1, 0b0001, 1, 0, 0, 0, 2, 1, 0, 0]);
+  assert_eq!(
+    Interval::<u16>::and_more(LimitValue::Limit(7)).to_bytes(),
+    vec![1, 0b1001, 7, 0]
+  );
+}
+
+#[test]
+fn test02_interval_round_trip() {
+  let intervals = vec![
+    Interval::closed(LimitValue::Limit(-5i64), LimitValue::Limit(5)),
+    Interval::open(LimitValue::Limit(3), LimitValue::Limit(3)),
+    Interval::closed(LimitValue::Limit(3), LimitValue::Limit(3)),
+    Interval::under(LimitValue::Limit(i64::MIN + 1)),
+    Interval::open(LimitValue::Limitless, LimitValue::Limitless),
+  ];
+  for interval in intervals {
+    assert_eq!(
+      Interval::from_bytes(&interval.to_bytes()).unwrap(),
+      interval
+    );
+  }
+}
+
+#[test]
+fn test03_seq_round_trip() {
+  let seq = IntervalSeq::new(vec![
+    Interval::closed(LimitValue::Limit(10u8), LimitValue::Limit(20)),
+    Interval::and_more(LimitValue::Limit(30)),
+  ]);
+  let bytes = seq.to_bytes();
+  assert_eq!(bytes, vec![1, 2, 0, 0, 0, 0b0101, 10, 20, 0b1001, 30]);
+  let mut decoded = IntervalSeq::<u8>::from_bytes(&bytes).unwrap();
+  assert_eq!(decoded.len(), 2);
+  assert_eq!(
+    decoded.iter().next(),
+    Some(&Interval::closed(
+      LimitValue::Limit(10),
+      LimitValue::Limit(20)
+    ))
+  );
+}
+
+#[test]
+fn test04_malformed() {
+  let decode = |bytes: &[u8]| Interval::<u8>::from_bytes(bytes);
+  assert!(matches!(decode(&[2, 0, 1, 2]), Err(Error::Decode(_))));
+  assert!(matches!(decode(&[1, 0x10, 1, 2]), Err(Error::Decode(_))));
+  assert!(matches!(decode(&[1, 0b0101, 1]), Err(Error::Decode(_))));
+  assert!(matches!(decode(&[1, 0b0101, 2, 1]), Err(Error::Decode(_))));
+  assert!(matches!(decode(&[1, 0b1010, 0]), Err(Error::Decode(_))));
+  assert!(matches!(
+    IntervalSeq::<u8>::from_bytes(&[1, 1, 0, 0, 0]),
+    Err(Error::Decode(_))
+  ));
+}
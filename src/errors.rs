@@ -1,4 +1,17 @@
 #[derive(Debug)]
 pub enum Error {
   NotFoundError,
+  OverlapError,
+  /// the interval is empty
+  EmptyIntervalError,
+  /// the interval is not sorted after the preceding ones
+  UnsortedError,
+  /// the upper limit is below the lower limit
+  UpperBelowLower,
+  /// the value is not comparable with itself, such as NaN
+  Incomparable,
+  /// the string could not be parsed as an interval
+  Parse(String),
+  /// the bytes could not be decoded as an interval
+  Decode(String),
 }
@@ -0,0 +1,121 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+
+use num_traits::ToPrimitive;
+
+use crate::{Interval, LimitValue};
+
+/// A structure that represents an interval with soft edges, i.e. a trapezoidal fuzzy set.
+///
+/// The values in the core interval are full members, the values outside the support interval are not members,
+/// and the degree of membership changes linearly in the transition zones between them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FuzzyInterval<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// the interval of full membership
+  core: Interval<T>,
+  /// the interval of non-zero membership
+  support: Interval<T>,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> FuzzyInterval<T> {
+  /// Generate a fuzzy interval from its core and support intervals.
+  ///
+  /// - params
+  ///     - core: the interval of full membership
+  ///     - support: the interval of non-zero membership
+  /// - return: `FuzzyInterval`
+  /// - panic
+  ///     - if `support` does not cover `core`
+  ///     - if `support` is unbounded on a side where `core` is bounded
+  pub fn new(core: Interval<T>, support: Interval<T>) -> Self {
+    if !support.covers(&core) {
+      panic!("the support does not cover the core")
+    }
+    if (core.has_lower_limit() && !support.has_lower_limit())
+      || (core.has_upper_limit() && !support.has_upper_limit())
+    {
+      panic!("the support has an unbounded transition zone around the core")
+    }
+    Self { core, support }
+  }
+
+  /// Generate a fuzzy interval whose transition zones extend the core by the given widths.
+  ///
+  /// - params
+  ///     - core: the interval of full membership
+  ///     - lower_width: the width of the transition zone below the core
+  ///     - upper_width: the width of the transition zone above the core
+  /// - return: `FuzzyInterval`
+  /// - panic
+  ///     - if either width is negative
+  pub fn with_margins(core: Interval<T>, lower_width: T, upper_width: T) -> Self
+  where
+    T: Add<Output = T> + Sub<Output = T>,
+  {
+    let support = Interval::over(
+      core.as_lower_limit().clone(),
+      true,
+      core.as_upper_limit().clone(),
+      true,
+    )
+    .pad(lower_width, upper_width);
+    Self::new(core, support)
+  }
+
+  /// Gets the interval of full membership.
+  pub fn as_core(&self) -> &Interval<T> {
+    &self.core
+  }
+
+  /// Gets the interval of non-zero membership.
+  pub fn as_support(&self) -> &Interval<T> {
+    &self.support
+  }
+
+  /// Get the degree of membership of the specified value `value`.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `1.0` in the core, `0.0` outside the support, and a linear degree in between
+  pub fn membership<D>(&self, value: &LimitValue<T>) -> f64
+  where
+    T: Sub<Output = D>,
+    D: ToPrimitive,
+  {
+    if self.core.includes(value) {
+      return 1.0;
+    }
+    if !self.support.includes(value) {
+      return 0.0;
+    }
+    let value = match value {
+      LimitValue::Limit(value) => value.clone(),
+      LimitValue::Limitless => return 0.0,
+    };
+    let limit_of = |e: Option<&T>| e.unwrap().clone();
+    if self.core.is_above(&LimitValue::Limit(value.clone())) {
+      let lower = limit_of(self.support.lower_value());
+      Self::ratio(
+        value - lower.clone(),
+        limit_of(self.core.lower_value()) - lower,
+      )
+    } else {
+      let upper = limit_of(self.support.upper_value());
+      Self::ratio(
+        upper.clone() - value,
+        upper - limit_of(self.core.upper_value()),
+      )
+    }
+  }
+
+  /// Get the ratio of the distance into a transition zone to the width of the zone, in `0.0..=1.0`.
+  ///
+  /// A zone of zero width gives `1.0`.
+  fn ratio<D: ToPrimitive>(distance: D, width: D) -> f64 {
+    match (distance.to_f64(), width.to_f64()) {
+      (Some(distance), Some(width)) if width > 0.0 => (distance / width).clamp(0.0, 1.0),
+      _ => 1.0,
+    }
+  }
+}
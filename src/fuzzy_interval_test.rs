@@ -0,0 +1,41 @@
+use crate::{FuzzyInterval, Interval, LimitValue};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_membership() {
+  let comfortable = FuzzyInterval::new(closed(20, 25), closed(10, 30));
+  assert_eq!(comfortable.membership(&LimitValue::Limit(22)), 1.0);
+  assert_eq!(comfortable.membership(&LimitValue::Limit(15)), 0.5);
+  assert_eq!(comfortable.membership(&LimitValue::Limit(10)), 0.0);
+  assert_eq!(comfortable.membership(&LimitValue::Limit(29)), 0.2);
+  assert_eq!(comfortable.membership(&LimitValue::Limit(40)), 0.0);
+  assert_eq!(comfortable.membership(&LimitValue::Limitless), 0.0);
+}
+
+#[test]
+fn test02_with_margins() {
+  let warm = FuzzyInterval::with_margins(Interval::and_more(LimitValue::Limit(25)), 4, 0);
+  assert_eq!(
+    warm.as_support(),
+    &Interval::and_more(LimitValue::Limit(21))
+  );
+  assert_eq!(warm.membership(&LimitValue::Limit(24)), 0.75);
+  assert_eq!(warm.membership(&LimitValue::Limit(1000)), 1.0);
+
+  let open = FuzzyInterval::with_margins(
+    Interval::open(LimitValue::Limit(0), LimitValue::Limit(10)),
+    0,
+    0,
+  );
+  assert_eq!(open.as_support(), &closed(0, 10));
+  assert_eq!(open.membership(&LimitValue::Limit(10)), 1.0);
+}
+
+#[test]
+#[should_panic]
+fn test03_support_not_covering_core() {
+  FuzzyInterval::new(closed(0, 10), closed(5, 10));
+}
@@ -1,18 +1,23 @@
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
+use std::ops::{
+  Add, Bound, Mul, Neg, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive, Sub,
+};
+
+use num_traits::{CheckedAdd, CheckedSub, FromPrimitive, Num, SaturatingAdd, SaturatingSub, Zero};
 
 use crate::interval_limit::IntervalLimit;
-use crate::LimitValue;
+use crate::interval_seq::{IntervalSeq, Ordered, TimelineSegment};
+use crate::{Error, LimitValue};
 
-#[derive(Debug, Clone, Hash, Eq)]
-pub struct Interval<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> {
+#[derive(Debug, Clone, Eq)]
+pub struct Interval<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
   pub(crate) lower: IntervalLimit<T>,
   pub(crate) upper: IntervalLimit<T>,
 }
 
-impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> PartialEq
-  for Interval<T>
-{
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq for Interval<T> {
   /// Verify the identity of this interval and the given interval `other`.
   ///
   /// It returns `true` if both intervals are empty, and `false` if only one of them is empty.
@@ -37,15 +42,73 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Part
   }
 }
 
-impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Interval<T> {
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Hash> Hash for Interval<T> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    if self.is_empty() {
+      "Empty".hash(state);
+    } else {
+      self.lower.hash(state);
+      self.upper.hash(state);
+    }
+  }
+}
+
+/// Intervals are ordered by their lower limits, then by their upper limits, and empty intervals come after all the others.
+///
+/// This is the order of `Ordered::LowerUpper`, so intervals can be sorted with `sort()` and used as keys of a `BTreeMap`.
+/// It is consistent with `PartialEq`, in which all the empty intervals are equal.
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Ord for Interval<T> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    Ordered::LowerUpper {
+      inverse_lower: false,
+      inverse_upper: false,
+    }
+    .compare(self, other)
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialOrd for Interval<T> {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// The default interval is the full interval `(Infinity, Infinity)`, which includes every value.
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Default for Interval<T> {
+  fn default() -> Self {
+    Self::all()
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Interval<T> {
   /// Generate an interval.
   ///
   /// - params
   ///     - lower: lower interval limit
   ///     - upper: upper interval limit
   /// - return: an interval
+  /// - panic
+  ///     - if the lower limit is greater than the upper limit
   pub fn new(lower: IntervalLimit<T>, upper: IntervalLimit<T>) -> Interval<T> {
     Self::check_lower_is_less_than_or_equal_upper(&lower, &upper);
+    Self::normalized(lower, upper)
+  }
+
+  /// Generate an interval, like `new` but without panicking.
+  ///
+  /// - params
+  ///     - lower: lower interval limit
+  ///     - upper: upper interval limit
+  /// - return: an interval, or `Err(Error::UpperBelowLower)` if the upper limit is below the lower limit
+  pub fn try_new(lower: IntervalLimit<T>, upper: IntervalLimit<T>) -> Result<Interval<T>, Error> {
+    if lower.is_lower() && upper.is_upper() && lower <= upper {
+      Ok(Self::normalized(lower, upper))
+    } else {
+      Err(Error::UpperBelowLower)
+    }
+  }
+
+  fn normalized(lower: IntervalLimit<T>, upper: IntervalLimit<T>) -> Interval<T> {
     let mut l = lower.clone();
     let mut u = upper.clone();
     if !upper.is_infinity()
@@ -87,6 +150,26 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     )
   }
 
+  /// Generate an interval, like `over` but without panicking.
+  ///
+  /// - params
+  ///     - lower: lower limit, Limitless means there is no limit.
+  ///     - lower_included: specify `true` if the lower limit is included in the interval (closed lower limit).
+  ///     - upper: upper limit, Limitless means there is no limit.
+  ///     - upper_included: specify `true` if the upper limit is included in the interval (closed upper limit)
+  /// - return: an interval, or `Err(Error::UpperBelowLower)` if the upper limit is below the lower limit
+  pub fn try_over(
+    lower: LimitValue<T>,
+    lower_included: bool,
+    upper: LimitValue<T>,
+    upper_included: bool,
+  ) -> Result<Self, Error> {
+    Self::try_new(
+      IntervalLimit::lower(lower_included, lower),
+      IntervalLimit::upper(upper_included, upper),
+    )
+  }
+
   /// Generate an interval with only the lower limit.
   ///
   /// The lower limit is the interval that is included (closed) in the interval.
@@ -110,6 +193,16 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     Self::over(lower, true, upper, true)
   }
 
+  /// Generate a closed interval, like `closed` but without panicking.
+  ///
+  /// - params
+  ///     - lower: lower limit, Limitless means there is no limit.
+  ///     - upper: upper limit, Limitless means there is no limit.
+  /// - return: a closed interval, or `Err(Error::UpperBelowLower)` if the upper limit is below the lower limit
+  pub fn try_closed(lower: LimitValue<T>, upper: LimitValue<T>) -> Result<Self, Error> {
+    Self::try_over(lower, true, upper, true)
+  }
+
   /// Generate an interval with only the lower limit.
   ///
   /// The lower limit is the interval that is not included in the (open) interval.
@@ -127,15 +220,39 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
   ///     - lower: lower limit, Limitless means there is no limit.
   ///     - upper: upper limit, Limitless means there is no limit.
   /// - return: an open interval
+  /// - panic
+  ///     - if the lower limit is greater than the upper limit
   pub fn open(lower: LimitValue<T>, upper: LimitValue<T>) -> Self {
     Self::over(lower, false, upper, false)
   }
 
+  /// Generate an open interval, like `open` but without panicking.
+  ///
+  /// - params
+  ///     - lower: lower limit, Limitless means there is no limit.
+  ///     - upper: upper limit, Limitless means there is no limit.
+  /// - return: an open interval, or `Err(Error::UpperBelowLower)` if the upper limit is below the lower limit
+  pub fn try_open(lower: LimitValue<T>, upper: LimitValue<T>) -> Result<Self, Error> {
+    Self::try_over(lower, false, upper, false)
+  }
+
   /// Generate a single-element interval.
   ///
   /// - params
+  ///     - value: the element
+  /// - return: an interval
+  pub fn single(value: T) -> Self {
+    Self::closed(LimitValue::Limit(value.clone()), LimitValue::Limit(value))
+  }
+
+  /// Generate a single-element interval.
+  ///
+  /// `single_element(LimitValue::Limitless)` does not represent a single element, so use `single` instead.
+  ///
+  /// - params
   ///     - element: an limit value
   /// - return: an interval
+  #[deprecated(note = "use `Interval::single` instead")]
   pub fn single_element(element: LimitValue<T>) -> Self {
     Self::closed(element.clone(), element)
   }
@@ -162,6 +279,125 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     Self::closed(LimitValue::<T>::Limitless, upper)
   }
 
+  /// Generate the full interval `(Infinity, Infinity)`, which includes every value.
+  ///
+  /// - return: an interval
+  pub fn all() -> Self {
+    Self::open(LimitValue::Limitless, LimitValue::Limitless)
+  }
+
+  /// Generate the empty interval `(value, value)` at the default value of `T`.
+  ///
+  /// - return: an empty interval
+  pub fn empty() -> Self
+  where
+    T: Default,
+  {
+    Self::empty_at(T::default())
+  }
+
+  /// Generate the empty interval `(pivot, pivot)` for types without a default value.
+  ///
+  /// - params
+  ///     - pivot: the value of both limits
+  /// - return: an empty interval
+  pub fn empty_at(pivot: T) -> Self {
+    Self::open(LimitValue::Limit(pivot.clone()), LimitValue::Limit(pivot))
+  }
+
+  /// Generate an interval from a pair of `std::ops::Bound`s.
+  ///
+  /// `Included` is mapped to a closed limit, `Excluded` to an open limit, and `Unbounded` to Limitless.
+  ///
+  /// - params
+  ///     - lower: lower bound
+  ///     - upper: upper bound
+  /// - return: an interval, or `Err(Error::UpperBelowLower)` if the upper limit is below the lower limit
+  pub fn from_bounds(lower: Bound<T>, upper: Bound<T>) -> Result<Self, Error> {
+    let limit_of = |bound: Bound<T>, lower: bool| match bound {
+      Bound::Included(value) => IntervalLimit::new(true, lower, LimitValue::Limit(value)),
+      Bound::Excluded(value) => IntervalLimit::new(false, lower, LimitValue::Limit(value)),
+      Bound::Unbounded => IntervalLimit::new(false, lower, LimitValue::Limitless),
+    };
+    Self::try_new(limit_of(lower, true), limit_of(upper, false))
+  }
+
+  /// Generate a closed interval from its center and radius, i.e. `[center - radius, center + radius]`.
+  ///
+  /// - params
+  ///     - center: the center value
+  ///     - radius: the distance from the center to each limit
+  /// - return: an interval
+  /// - panic
+  ///     - if `radius` is negative
+  pub fn from_center(center: T, radius: T) -> Self
+  where
+    T: Add<Output = T> + Sub<Output = T>,
+  {
+    Self::closed(
+      LimitValue::Limit(center.clone() - radius.clone()),
+      LimitValue::Limit(center + radius),
+    )
+  }
+
+  /// Generate the acceptance band `[value - tolerance, value + tolerance]` around a nominal value.
+  ///
+  /// - params
+  ///     - value: the nominal value
+  ///     - tolerance: the absolute tolerance
+  /// - return: an interval
+  /// - panic
+  ///     - if `tolerance` is negative
+  pub fn tolerance(value: T, tolerance: T) -> Self
+  where
+    T: Add<Output = T> + Sub<Output = T>,
+  {
+    Self::from_center(value, tolerance)
+  }
+
+  /// Generate the acceptance band around a nominal value, with a tolerance given in percent of the value.
+  ///
+  /// For example, `relative_tolerance(200, 5)` is `[190, 210]`.
+  ///
+  /// - params
+  ///     - value: the nominal value
+  ///     - percent: the tolerance in percent of the magnitude of `value`
+  /// - return: an interval
+  /// - panic
+  ///     - if `percent` is negative
+  ///     - if the band does not fit in `T`, e.g. around `i32::MIN` (arithmetic overflow, in debug builds)
+  pub fn relative_tolerance(value: T, percent: T) -> Self
+  where
+    T: Num + FromPrimitive,
+  {
+    let hundred = T::from_u8(100).unwrap();
+    // Divide before multiplying so that the intermediate values do not exceed the magnitude of the result.
+    // For integers, the remainder of the division is scaled separately to keep the precision.
+    let quotient = value.clone() / hundred.clone();
+    let remainder = value.clone() - quotient.clone() * hundred.clone();
+    let tolerance = quotient * percent.clone() + remainder * percent / hundred;
+    let tolerance = if tolerance < T::zero() {
+      T::zero() - tolerance
+    } else {
+      tolerance
+    };
+    Self::from_center(value, tolerance)
+  }
+
+  /// Get the lower limit, which carries both the value and the closedness.
+  ///
+  /// - return: the lower limit
+  pub fn lower_limit(&self) -> &IntervalLimit<T> {
+    &self.lower
+  }
+
+  /// Get the upper limit, which carries both the value and the closedness.
+  ///
+  /// - return: the upper limit
+  pub fn upper_limit(&self) -> &IntervalLimit<T> {
+    &self.upper
+  }
+
   pub fn as_upper_limit(&self) -> &LimitValue<T> {
     self.upper.as_value()
   }
@@ -170,17 +406,269 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     self.lower.as_value()
   }
 
+  /// Get the upper limit value, skipping the `LimitValue` layer.
+  ///
+  /// - return: the upper limit value, or `None` if there is no upper limit
+  pub fn upper_value(&self) -> Option<&T> {
+    self.as_upper_limit().as_value().ok()
+  }
+
+  /// Get the lower limit value, skipping the `LimitValue` layer.
+  ///
+  /// - return: the lower limit value, or `None` if there is no lower limit
+  pub fn lower_value(&self) -> Option<&T> {
+    self.as_lower_limit().as_value().ok()
+  }
+
+  /// Convert this interval into its limit values and their open/closed states, without cloning.
+  ///
+  /// - return: a tuple of the lower limit, `true` if the lower limit is closed, the upper limit, and `true` if the upper limit is closed
+  pub fn into_parts(self) -> (LimitValue<T>, bool, LimitValue<T>, bool) {
+    let lower_closed = self.lower.is_closed();
+    let upper_closed = self.upper.is_closed();
+    (
+      self.lower.into_value(),
+      lower_closed,
+      self.upper.into_value(),
+      upper_closed,
+    )
+  }
+
+  /// Convert this interval into its lower and upper interval limits, without cloning.
+  ///
+  /// - return: a tuple of the lower interval limit and the upper interval limit
+  pub fn into_limits(self) -> (IntervalLimit<T>, IntervalLimit<T>) {
+    (self.lower, self.upper)
+  }
+
+  /// Convert this interval into a pair of `std::ops::Bound`s, the inverse of `from_bounds`.
+  ///
+  /// - return: a tuple of the lower bound and the upper bound
+  pub fn into_bounds(self) -> (Bound<T>, Bound<T>) {
+    (self.lower.into_bound(), self.upper.into_bound())
+  }
+
+  /// Get the length of this interval, i.e. the upper limit minus the lower limit.
+  ///
+  /// The open/closed state of the limits does not affect the length.
+  ///
+  /// - return: the length, or `None` if either limit is Limitless
+  pub fn length<D>(&self) -> Option<D>
+  where
+    T: Sub<Output = D>,
+  {
+    match (self.as_lower_limit(), self.as_upper_limit()) {
+      (LimitValue::Limit(lower), LimitValue::Limit(upper)) => Some(upper.clone() - lower.clone()),
+      _ => None,
+    }
+  }
+
+  /// Get the center and radius of this interval, i.e. the midpoint of the limits and half the length.
+  ///
+  /// For integer types, the radius is rounded toward zero.
+  ///
+  /// - return: a tuple of the center and the radius, or `None` if either limit is Limitless
+  pub fn center_and_radius(&self) -> Option<(T, T)>
+  where
+    T: Num,
+  {
+    match (self.as_lower_limit(), self.as_upper_limit()) {
+      (LimitValue::Limit(lower), LimitValue::Limit(upper)) => {
+        let radius = (upper.clone() - lower.clone()) / (T::one() + T::one());
+        Some((lower.clone() + radius.clone(), radius))
+      }
+      _ => None,
+    }
+  }
+
+  /// Reflect this interval about zero, i.e. map `[a, b)` to `(-b, -a]`.
+  ///
+  /// The open/closed states of the limits are swapped along with the limits, and Limitless limits stay Limitless.
+  ///
+  /// - return: negated interval
+  pub fn negate(&self) -> Self
+  where
+    T: Neg<Output = T>,
+  {
+    self.map_values(|e| -e.clone(), true)
+  }
+
+  /// Move the lower limit down by `lower_by` and the upper limit up by `upper_by`.
+  ///
+  /// Negative amounts shrink this interval, and if the limits cross, the result is an empty interval
+  /// at the moved lower limit. Limitless limits stay Limitless.
+  ///
+  /// - params
+  ///     - lower_by: the amount to be subtracted from the lower limit
+  ///     - upper_by: the amount to be added to the upper limit
+  /// - return: padded interval
+  pub fn pad(&self, lower_by: T, upper_by: T) -> Self
+  where
+    T: Add<Output = T> + Sub<Output = T>,
+  {
+    let lower = |e: &T| e.clone() - lower_by.clone();
+    self
+      .try_map_limits(|e| Some(lower(e)), |e| Some(e.clone() + upper_by.clone()))
+      .unwrap_or_else(|| {
+        let value = self.as_lower_limit().as_value().map(lower).unwrap();
+        Self::open(LimitValue::Limit(value.clone()), LimitValue::Limit(value))
+      })
+  }
+
+  /// Multiply the limits of this interval by `factor`.
+  ///
+  /// A negative factor swaps the limits along with their open/closed states. Limitless limits stay Limitless.
+  ///
+  /// - params
+  ///     - factor: the multiplier
+  /// - return: scaled interval
+  pub fn scale(&self, factor: T) -> Self
+  where
+    T: Mul<Output = T> + Zero,
+  {
+    self.map_values(|e| e.clone() * factor.clone(), factor < T::zero())
+  }
+
+  /// Multiply the distances of the limits of this interval from `center` by `factor`.
+  ///
+  /// A negative factor swaps the limits along with their open/closed states. Limitless limits stay Limitless.
+  ///
+  /// - params
+  ///     - center: the fixed point of the scaling
+  ///     - factor: the multiplier
+  /// - return: scaled interval
+  pub fn scale_about(&self, center: T, factor: T) -> Self
+  where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Zero,
+  {
+    self.map_values(
+      |e| (e.clone() - center.clone()) * factor.clone() + center.clone(),
+      factor < T::zero(),
+    )
+  }
+
+  /// Move this interval by `delta`, detecting overflow.
+  ///
+  /// Limitless limits stay Limitless.
+  ///
+  /// - params
+  ///     - delta: the amount to be added to both limits
+  /// - return: the shifted interval, or `None` if a limit overflows
+  pub fn checked_shift(&self, delta: T) -> Option<Self>
+  where
+    T: CheckedAdd,
+  {
+    self.try_map_limits(|e| e.checked_add(&delta), |e| e.checked_add(&delta))
+  }
+
+  /// Widen this interval by `margin` on both sides, detecting overflow.
+  ///
+  /// A negative margin narrows this interval. Limitless limits stay Limitless.
+  ///
+  /// - params
+  ///     - margin: the amount to be subtracted from the lower limit and added to the upper limit
+  /// - return: the expanded interval, or `None` if a limit overflows or the lower limit exceeds the upper limit
+  pub fn checked_expand(&self, margin: T) -> Option<Self>
+  where
+    T: CheckedAdd + CheckedSub,
+  {
+    self.try_map_limits(|e| e.checked_sub(&margin), |e| e.checked_add(&margin))
+  }
+
+  /// Move this interval by `delta`, clamping the limits to the bounds of the numeric type.
+  ///
+  /// Limitless limits stay Limitless.
+  ///
+  /// - params
+  ///     - delta: the amount to be added to both limits
+  /// - return: the shifted interval
+  pub fn saturating_shift(&self, delta: T) -> Self
+  where
+    T: SaturatingAdd,
+  {
+    self
+      .try_map_limits(
+        |e| Some(e.saturating_add(&delta)),
+        |e| Some(e.saturating_add(&delta)),
+      )
+      .unwrap()
+  }
+
+  /// Widen this interval by `margin` on both sides, clamping the limits to the bounds of the numeric type.
+  ///
+  /// A negative margin narrows this interval, and if the limits cross, the result is an empty interval
+  /// at the moved lower limit, like `pad`. Limitless limits stay Limitless.
+  ///
+  /// - params
+  ///     - margin: the amount to be subtracted from the lower limit and added to the upper limit
+  /// - return: the expanded interval
+  pub fn saturating_expand(&self, margin: T) -> Self
+  where
+    T: SaturatingAdd + SaturatingSub,
+  {
+    let lower = |e: &T| e.saturating_sub(&margin);
+    self
+      .try_map_limits(|e| Some(lower(e)), |e| Some(e.saturating_add(&margin)))
+      .unwrap_or_else(|| Self::empty_at(self.as_lower_limit().as_value().map(lower).unwrap()))
+  }
+
+  /// Widen this interval by `margin` on both sides, turning the limits that overflow into Limitless.
+  ///
+  /// A negative margin narrows this interval, and if the limits cross, the result is an empty interval
+  /// at the moved lower limit, or at the moved upper limit if the lower limit cannot be moved.
+  ///
+  /// - params
+  ///     - margin: the amount to be subtracted from the lower limit and added to the upper limit
+  /// - return: the expanded interval
+  pub fn expand_or_limitless(&self, margin: T) -> Self
+  where
+    T: CheckedAdd + CheckedSub + Zero,
+  {
+    if margin < T::zero() {
+      // Narrowing a limit can only overflow if it passes the other limit.
+      return self.checked_expand(margin.clone()).unwrap_or_else(|| {
+        let lower = self
+          .as_lower_limit()
+          .as_value()
+          .ok()
+          .and_then(|e| e.checked_sub(&margin));
+        let upper = || {
+          self
+            .as_upper_limit()
+            .as_value()
+            .ok()
+            .and_then(|e| e.checked_add(&margin))
+        };
+        Self::empty_at(lower.or_else(upper).unwrap())
+      });
+    }
+    let lower = match self.as_lower_limit() {
+      LimitValue::Limit(value) => value
+        .checked_sub(&margin)
+        .map_or(LimitValue::Limitless, LimitValue::Limit),
+      LimitValue::Limitless => LimitValue::Limitless,
+    };
+    let upper = match self.as_upper_limit() {
+      LimitValue::Limit(value) => value
+        .checked_add(&margin)
+        .map_or(LimitValue::Limitless, LimitValue::Limit),
+      LimitValue::Limitless => LimitValue::Limitless,
+    };
+    self.new_of_same_type(
+      lower,
+      self.includes_lower_limit(),
+      upper,
+      self.includes_upper_limit(),
+    )
+  }
+
   /// Verify that this interval completely encloses the specified interval `other`.
   ///
   /// - params
   ///     - other: an `Interval`
   /// - return: `true` for full comprehension, `false` otherwise
   pub fn covers(&self, other: &Interval<T>) -> bool {
-    let lower_pass = self.includes(other.as_lower_limit())
-      || self.as_lower_limit() == other.as_lower_limit() && !other.includes_lower_limit();
-    let upper_pass = self.includes(other.as_upper_limit())
-      || self.as_upper_limit() == other.as_upper_limit() && !other.includes_upper_limit();
-    lower_pass && upper_pass
+    self.lower <= other.lower && other.upper <= self.upper
   }
 
   /// Get the interval that lies between this interval and the given interval `other`.
@@ -211,15 +699,35 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
   ///
   /// - return: `true` if it's a single element interval, `false` otherwise
   pub fn is_single_element(&self) -> bool {
-    if !self.has_upper_limit() {
-      false
-    } else if !self.has_lower_limit() {
+    if !self.has_upper_limit() || !self.has_lower_limit() {
       false
     } else {
       self.as_upper_limit() == self.as_lower_limit() && !self.is_empty()
     }
   }
 
+  /// Get the value of this interval if it is a single-element interval.
+  ///
+  /// - return: the single value, or `None` if this is not a single-element interval
+  pub fn as_single_element(&self) -> Option<&T> {
+    if self.is_single_element() {
+      self.lower_value()
+    } else {
+      None
+    }
+  }
+
+  /// Convert this interval into its value if it is a single-element interval.
+  ///
+  /// - return: the single value, or `None` if this is not a single-element interval
+  pub fn into_single_element(self) -> Option<T> {
+    let single = self.is_single_element();
+    match self.lower.into_value() {
+      LimitValue::Limit(value) if single => Some(value),
+      _ => None,
+    }
+  }
+
   /// Generate a new open interval with the same limits as this interval.
   ///
   /// - return: a new interval
@@ -323,8 +831,13 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
   pub fn intersect(&self, other: &Interval<T>) -> Interval<T> {
     let intersect_lower_bound = self.greater_of_lower_limits(other);
     let intersect_upper_bound = self.lesser_of_upper_limits(other);
-    if *intersect_lower_bound > *intersect_upper_bound {
-      self.empty_of_same_type()
+    if intersect_upper_bound.is_limit() && *intersect_lower_bound > *intersect_upper_bound {
+      self.new_of_same_type(
+        intersect_lower_bound.clone(),
+        false,
+        intersect_lower_bound.clone(),
+        false,
+      )
     } else {
       self.new_of_same_type(
         intersect_lower_bound.clone(),
@@ -335,15 +848,79 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     }
   }
 
+  /// Return the product set (common part) of all the given intervals.
+  ///
+  /// The folding stops as soon as the common part becomes empty.
+  ///
+  /// - params
+  ///     - intervals: intervals
+  /// - return: the common part, or the full interval if `intervals` is empty
+  pub fn intersect_all<'a>(intervals: impl IntoIterator<Item = &'a Interval<T>>) -> Interval<T>
+  where
+    T: 'a,
+  {
+    let mut intervals = intervals.into_iter();
+    let mut result = match intervals.next() {
+      Some(first) => first.clone(),
+      None => return Interval::default(),
+    };
+    for interval in intervals {
+      if result.is_empty() {
+        break;
+      }
+      result = result.intersect(interval);
+    }
+    result
+  }
+
+  /// Return the smallest interval that encompasses all the given intervals.
+  ///
+  /// Like `span`, empty intervals are ignored.
+  ///
+  /// - params
+  ///     - intervals: intervals
+  /// - return: the smallest interval that encompasses them, or `None` if none of `intervals` is non-empty
+  pub fn enclose_all<'a>(
+    intervals: impl IntoIterator<Item = &'a Interval<T>>,
+  ) -> Option<Interval<T>>
+  where
+    T: 'a,
+  {
+    Self::enclose_limits(intervals.into_iter().filter(|e| !e.is_empty()))
+  }
+
+  /// Return the smallest interval that encompasses the limits of all the given intervals, including empty ones.
+  pub(crate) fn enclose_limits<'a>(
+    intervals: impl IntoIterator<Item = &'a Interval<T>>,
+  ) -> Option<Interval<T>>
+  where
+    T: 'a,
+  {
+    let mut intervals = intervals.into_iter();
+    let first = intervals.next()?;
+    let (lower, upper) = intervals.fold((&first.lower, &first.upper), |(lower, upper), e| {
+      (
+        IntervalLimit::min(lower, &e.lower),
+        IntervalLimit::max(upper, &e.upper),
+      )
+    });
+    Some(first.new_of_same_type(
+      lower.as_value().clone(),
+      lower.is_closed(),
+      upper.as_value().clone(),
+      upper.is_closed(),
+    ))
+  }
+
   /// Verify if there is a common part between this interval and the given interval `other`.
   ///
   /// - params
   ///     - other: a target interval
   /// - return: `true` if the common part exists, `false` otherwise
   pub fn intersects(&self, other: &Interval<T>) -> bool {
-    if self.equal_both_limitless(self.as_upper_limit(), other.as_upper_limit()) {
-      true
-    } else if self.equal_both_limitless(self.as_lower_limit(), self.as_lower_limit()) {
+    if self.equal_both_limitless(self.as_upper_limit(), other.as_upper_limit())
+      || self.equal_both_limitless(self.as_lower_limit(), other.as_lower_limit())
+    {
       true
     } else {
       let g = self.greater_of_lower_limits(other);
@@ -417,6 +994,121 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     self.lower.is_closed()
   }
 
+  /// Return the smallest interval that encompasses both this interval and the given interval `other`,
+  /// even if they are disjoint.
+  ///
+  /// This is the pairwise analogue of `IntervalSeq::extent`, except that an empty operand is ignored.
+  ///
+  /// - params
+  ///     - other: an interval to be spanned
+  /// - return: spanning interval
+  pub fn span(&self, other: &Interval<T>) -> Interval<T> {
+    if self.is_empty() {
+      other.clone()
+    } else if other.is_empty() {
+      self.clone()
+    } else {
+      Interval::new(
+        std::cmp::min(&self.lower, &other.lower).clone(),
+        std::cmp::max(&self.upper, &other.upper).clone(),
+      )
+    }
+  }
+
+  /// Verify whether this interval and the given interval `other` do not intersect but touch at a limit,
+  /// e.g. `[1, 3)` and `[3, 5]`.
+  ///
+  /// The shared limit must be closed on exactly one side. Empty intervals never abut.
+  ///
+  /// - params
+  ///     - other: an interval to be compared
+  /// - return: `true` if they are adjacent, `false` otherwise
+  pub fn abuts(&self, other: &Interval<T>) -> bool {
+    let touches = |left: &Interval<T>, right: &Interval<T>| {
+      left.has_upper_limit()
+        && left.as_upper_limit() == right.as_lower_limit()
+        && (left.includes_upper_limit() ^ right.includes_lower_limit())
+    };
+    !self.is_empty()
+      && !other.is_empty()
+      && !self.intersects(other)
+      && (touches(self, other) || touches(other, self))
+  }
+
+  /// Split this interval into the parts covered by the given interval sequence `seq` and the parts not covered.
+  ///
+  /// Both results are coalesced and sorted by the lower limits.
+  ///
+  /// - params
+  ///     - seq: an interval sequence
+  /// - return: a pair of the covered parts and the uncovered parts
+  pub fn split_by(&self, seq: &IntervalSeq<T>) -> (IntervalSeq<T>, IntervalSeq<T>) {
+    let (covered, uncovered): (Vec<TimelineSegment<T>>, Vec<TimelineSegment<T>>) =
+      seq.timeline(self).partition(TimelineSegment::is_covered);
+    let intervals_of = |segments: Vec<TimelineSegment<T>>| {
+      IntervalSeq::new(segments.into_iter().map(|e| match e {
+        TimelineSegment::Covered(interval) | TimelineSegment::Uncovered(interval) => interval,
+      }))
+    };
+    (intervals_of(covered), intervals_of(uncovered))
+  }
+
+  /// Return the union of this interval and the given interval `other`.
+  ///
+  /// Empty operands are ignored.
+  ///
+  /// - params
+  ///     - other: an interval
+  /// - return: one merged interval if they intersect or abut, both intervals otherwise
+  pub fn union(&self, other: &Interval<T>) -> IntervalSeq<T> {
+    if self.is_empty() || other.is_empty() || self.intersects(other) || self.abuts(other) {
+      IntervalSeq::new(Some(self.span(other)).into_iter().filter(|e| !e.is_empty()))
+    } else {
+      IntervalSeq::new(vec![self.clone(), other.clone()])
+    }
+  }
+
+  /// Return what is left of this interval after removing the given interval `other`.
+  ///
+  /// - params
+  ///     - other: an interval to be removed
+  /// - return: no interval if `other` covers this interval, two intervals if `other` is enclosed in it,
+  ///   and one interval otherwise
+  pub fn subtract(&self, other: &Interval<T>) -> IntervalSeq<T> {
+    IntervalSeq::new(
+      other
+        .complement_relative_to(self)
+        .into_iter()
+        .filter(|e| !e.is_empty()),
+    )
+  }
+
+  /// Return the complement of this interval against the whole line, e.g. `(Infinity, 3)` and `[5, Infinity)`
+  /// for `[3, 5)`.
+  ///
+  /// - return: up to two intervals that cover everything outside this interval
+  pub fn complement(&self) -> IntervalSeq<T> {
+    Interval::all().subtract(self)
+  }
+
+  /// Return the parts covered by exactly one of this interval and the given interval `other`.
+  ///
+  /// Adjacent parts are merged, e.g. for `[1, 3)` and `[3, 5]`.
+  ///
+  /// - params
+  ///     - other: an interval
+  /// - return: the parts sorted by the lower limits
+  pub fn symmetric_difference(&self, other: &Interval<T>) -> IntervalSeq<T> {
+    IntervalSeq::new(
+      self
+        .subtract(other)
+        .into_vec()
+        .into_iter()
+        .chain(other.subtract(self).into_vec()),
+    )
+    .coalesce()
+  }
+
   pub(crate) fn complement_relative_to(&self, other: &Interval<T>) -> Vec<Interval<T>> {
     let mut interval_sequence: Vec<Interval<T>> = vec![];
     if !self.intersects(other) {
@@ -433,9 +1125,57 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     }
   }
 
+  /// Apply the given functions to the limit values, keeping the open/closed states and Limitless limits.
+  ///
+  /// - return: the mapped interval, or `None` if a function fails or the limits are out of order
+  fn try_map_limits(
+    &self,
+    lower: impl FnOnce(&T) -> Option<T>,
+    upper: impl FnOnce(&T) -> Option<T>,
+  ) -> Option<Self> {
+    let lower_value = match self.as_lower_limit() {
+      LimitValue::Limit(value) => LimitValue::Limit(lower(value)?),
+      LimitValue::Limitless => LimitValue::Limitless,
+    };
+    let upper_value = match self.as_upper_limit() {
+      LimitValue::Limit(value) => LimitValue::Limit(upper(value)?),
+      LimitValue::Limitless => LimitValue::Limitless,
+    };
+    let lower = IntervalLimit::lower(self.includes_lower_limit(), lower_value);
+    let upper = IntervalLimit::upper(self.includes_upper_limit(), upper_value);
+    if lower <= upper {
+      Some(Interval::new(lower, upper))
+    } else {
+      None
+    }
+  }
+
+  /// Apply the given function to the limit values, swapping the limits if the function is decreasing.
+  fn map_values(&self, f: impl Fn(&T) -> T, decreasing: bool) -> Self {
+    let map = |value: &LimitValue<T>| match value {
+      LimitValue::Limit(value) => LimitValue::Limit(f(value)),
+      LimitValue::Limitless => LimitValue::Limitless,
+    };
+    if decreasing {
+      Self::over(
+        map(self.as_upper_limit()),
+        self.includes_upper_limit(),
+        map(self.as_lower_limit()),
+        self.includes_lower_limit(),
+      )
+    } else {
+      Self::over(
+        map(self.as_lower_limit()),
+        self.includes_lower_limit(),
+        map(self.as_upper_limit()),
+        self.includes_upper_limit(),
+      )
+    }
+  }
+
   fn check_lower_is_less_than_or_equal_upper(lower: &IntervalLimit<T>, upper: &IntervalLimit<T>) {
     if !(lower.is_lower() && upper.is_upper() && lower <= upper) {
-      panic!("{} is not before or equal to {}", lower, upper)
+      panic!("the lower limit is not before or equal to the upper limit")
     }
   }
 
@@ -451,9 +1191,9 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
   pub(crate) fn greater_of_lower_limits<'a>(&'a self, other: &'a Interval<T>) -> &'a LimitValue<T> {
     if *self.as_lower_limit() == LimitValue::Limitless {
       other.as_lower_limit()
-    } else if *other.as_lower_limit() == LimitValue::Limitless {
-      self.as_lower_limit()
-    } else if self.as_lower_limit() >= other.as_lower_limit() {
+    } else if *other.as_lower_limit() == LimitValue::Limitless
+      || self.as_lower_limit() >= other.as_lower_limit()
+    {
       self.as_lower_limit()
     } else {
       other.as_lower_limit()
@@ -468,9 +1208,9 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
   pub(crate) fn lesser_of_upper_limits<'a>(&'a self, other: &'a Interval<T>) -> &'a LimitValue<T> {
     if *self.as_upper_limit() == LimitValue::Limitless {
       other.as_upper_limit()
-    } else if *other.as_upper_limit() == LimitValue::Limitless {
-      self.as_upper_limit()
-    } else if self.as_upper_limit() <= other.as_upper_limit() {
+    } else if *other.as_upper_limit() == LimitValue::Limitless
+      || self.as_upper_limit() <= other.as_upper_limit()
+    {
       self.as_upper_limit()
     } else {
       other.as_upper_limit()
@@ -479,29 +1219,29 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
 
   fn greater_of_lower_included_in_intersection(&self, other: &Interval<T>) -> bool {
     let limit = self.greater_of_lower_limits(other);
-    self.includes(&limit) && other.includes(&limit)
+    self.includes(limit) && other.includes(limit)
   }
 
   fn greater_of_lower_included_in_union(&self, other: &Interval<T>) -> bool {
     let limit = self.greater_of_lower_limits(other);
-    self.includes(&limit) || other.includes(&limit)
+    self.includes(limit) || other.includes(limit)
   }
 
   fn lesser_of_upper_included_in_intersection(&self, other: &Interval<T>) -> bool {
     let limit = self.lesser_of_upper_limits(other);
-    self.includes(&limit) && other.includes(&limit)
+    self.includes(limit) && other.includes(limit)
   }
 
   fn lesser_of_upper_included_in_union(&self, other: &Interval<T>) -> bool {
     let limit = self.lesser_of_upper_limits(other);
-    self.includes(&limit) || other.includes(&limit)
+    self.includes(limit) || other.includes(limit)
   }
 
   /// この区間の下側補区間と与えた区間 `other` の共通部分を返す。
   ///
   /// other 比較対象の区間
   /// return この区間の下側の補区間と、与えた区間の共通部分。存在しない場合は `None`
-  fn left_complement_relative_to(&self, other: &Interval<T>) -> Option<Interval<T>> {
+  pub(crate) fn left_complement_relative_to(&self, other: &Interval<T>) -> Option<Interval<T>> {
     // この区間の下側限界値の方が小さいか等しい場合、下側の補区間に共通部分は無い
     if self.lower <= other.lower {
       None
@@ -515,7 +1255,7 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     }
   }
 
-  fn right_complement_relative_to(&self, other: &Interval<T>) -> Option<Interval<T>> {
+  pub(crate) fn right_complement_relative_to(&self, other: &Interval<T>) -> Option<Interval<T>> {
     // この区間の上側限界値の方が大きいか等しい場合、上側の補区間に共通部分は無い
     if self.upper >= other.upper {
       None
@@ -530,14 +1270,12 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
   }
 }
 
-impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Display
-  for Interval<T>
-{
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display> Display for Interval<T> {
   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     if self.is_empty() {
       write!(f, "{{}}")
     } else if self.is_single_element() {
-      write!(f, "{{{}}}", self.as_lower_limit().to_string())
+      write!(f, "{{{}}}", self.as_lower_limit())
     } else {
       let mut str = String::new();
       if self.includes_lower_limit() {
@@ -548,13 +1286,13 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Disp
       if self.has_lower_limit() {
         str.push_str(&self.as_lower_limit().to_string());
       } else {
-        str.push_str(&"Infinity");
+        str.push_str("Infinity");
       }
-      str.push_str(&", ");
+      str.push_str(", ");
       if self.has_upper_limit() {
         str.push_str(&self.as_upper_limit().to_string());
       } else {
-        str.push_str(&"Infinity");
+        str.push_str("Infinity");
       }
       if self.includes_upper_limit() {
         str.push(']');
@@ -565,3 +1303,91 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Disp
     }
   }
 }
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq<Range<T>> for Interval<T> {
+  /// Verify that this interval denotes the same set of values as the half-open range `other`.
+  fn eq(&self, other: &Range<T>) -> bool {
+    if other.start < other.end {
+      *self
+        == Interval::over(
+          LimitValue::Limit(other.start.clone()),
+          true,
+          LimitValue::Limit(other.end.clone()),
+          false,
+        )
+    } else {
+      self.is_empty()
+    }
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq<RangeInclusive<T>> for Interval<T> {
+  /// Verify that this interval denotes the same set of values as the closed range `other`.
+  fn eq(&self, other: &RangeInclusive<T>) -> bool {
+    if other.start() <= other.end() {
+      *self
+        == Interval::closed(
+          LimitValue::Limit(other.start().clone()),
+          LimitValue::Limit(other.end().clone()),
+        )
+    } else {
+      self.is_empty()
+    }
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq<RangeFrom<T>> for Interval<T> {
+  /// Verify that this interval denotes the same set of values as the range `other` without an upper limit.
+  fn eq(&self, other: &RangeFrom<T>) -> bool {
+    *self == Interval::and_more(LimitValue::Limit(other.start.clone()))
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq<RangeTo<T>> for Interval<T> {
+  /// Verify that this interval denotes the same set of values as the range `other` without a lower limit.
+  fn eq(&self, other: &RangeTo<T>) -> bool {
+    *self == Interval::under(LimitValue::Limit(other.end.clone()))
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq<RangeToInclusive<T>> for Interval<T> {
+  /// Verify that this interval denotes the same set of values as the range `other` without a lower limit.
+  fn eq(&self, other: &RangeToInclusive<T>) -> bool {
+    *self == Interval::up_to(LimitValue::Limit(other.end.clone()))
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq<RangeFull> for Interval<T> {
+  /// Verify that this interval has neither a lower limit nor an upper limit.
+  fn eq(&self, _: &RangeFull) -> bool {
+    !self.has_lower_limit() && !self.has_upper_limit()
+  }
+}
+
+macro_rules! impl_range_partial_eq_interval {
+  ($($range:ty),*) => {
+    $(
+      impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq<Interval<T>>
+        for $range
+      {
+        fn eq(&self, other: &Interval<T>) -> bool {
+          other == self
+        }
+      }
+    )*
+  };
+}
+
+impl_range_partial_eq_interval!(
+  Range<T>,
+  RangeInclusive<T>,
+  RangeFrom<T>,
+  RangeTo<T>,
+  RangeToInclusive<T>
+);
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq<Interval<T>> for RangeFull {
+  fn eq(&self, other: &Interval<T>) -> bool {
+    other == self
+  }
+}
@@ -0,0 +1,114 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{Interval, IntervalNotation, IntervalSeq};
+
+/// A structure that represents the regions covered by only one of two interval sequences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalDiff<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// regions covered only by the left side
+  only_left: Vec<Interval<T>>,
+  /// regions covered only by the right side
+  only_right: Vec<Interval<T>>,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalDiff<T> {
+  /// Compare the values covered by the given interval sequences.
+  ///
+  /// - params
+  ///     - left: an interval sequence
+  ///     - right: an interval sequence
+  /// - return: `IntervalDiff`
+  pub fn between(left: &IntervalSeq<T>, right: &IntervalSeq<T>) -> Self {
+    let only = |this: &IntervalSeq<T>, that: &IntervalSeq<T>| {
+      this
+        .coalesced_intervals()
+        .iter()
+        .flat_map(|e| that.uncovered_parts_of(e))
+        .collect::<Vec<Interval<T>>>()
+    };
+    Self {
+      only_left: only(left, right),
+      only_right: only(right, left),
+    }
+  }
+
+  /// Gets the regions covered only by the left side, in ascending order.
+  pub fn as_only_left(&self) -> &[Interval<T>] {
+    &self.only_left
+  }
+
+  /// Gets the regions covered only by the right side, in ascending order.
+  pub fn as_only_right(&self) -> &[Interval<T>] {
+    &self.only_right
+  }
+
+  /// Return whether both sides cover the same values.
+  pub fn is_empty(&self) -> bool {
+    self.only_left.is_empty() && self.only_right.is_empty()
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display> Display for IntervalDiff<T> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let join = |intervals: &[Interval<T>]| {
+      if intervals.is_empty() {
+        "-".to_string()
+      } else {
+        intervals
+          .iter()
+          .map(|e| e.format_with(IntervalNotation::Standard))
+          .collect::<Vec<String>>()
+          .join(", ")
+      }
+    };
+    write!(
+      f,
+      " only in left: {}\nonly in right: {}",
+      join(&self.only_left),
+      join(&self.only_right)
+    )
+  }
+}
+
+/// Assert that two intervals are equal.
+///
+/// On failure, the panic message shows both intervals and the regions covered by only one of them.
+#[macro_export]
+macro_rules! assert_interval_eq {
+  ($left:expr, $right:expr $(,)?) => {
+    match (&$left, &$right) {
+      (left, right) => {
+        if !(*left == *right) {
+          panic!(
+            "assertion failed: `(left == right)`\n  left: {}\n right: {}\n{}",
+            left.format_with($crate::IntervalNotation::Standard),
+            right.format_with($crate::IntervalNotation::Standard),
+            $crate::IntervalDiff::between(
+              &$crate::IntervalSeq::new(vec![left.clone()]),
+              &$crate::IntervalSeq::new(vec![right.clone()])
+            )
+          )
+        }
+      }
+    }
+  };
+}
+
+/// Assert that two interval sequences cover the same set of values (see [`IntervalSeq::set_eq`]).
+///
+/// On failure, the panic message shows the regions covered by only one of them.
+#[macro_export]
+macro_rules! assert_seq_set_eq {
+  ($left:expr, $right:expr $(,)?) => {
+    match (&$left, &$right) {
+      (left, right) => {
+        if !left.set_eq(right) {
+          panic!(
+            "assertion failed: `left.set_eq(right)`\n{}",
+            $crate::IntervalDiff::between(left, right)
+          )
+        }
+      }
+    }
+  };
+}
@@ -0,0 +1,60 @@
+use crate::{assert_interval_eq, assert_seq_set_eq};
+use crate::{Interval, IntervalDiff, IntervalSeq, LimitValue};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_between() {
+  let left = IntervalSeq::new(vec![closed(1, 5), closed(10, 20)]);
+  let right = IntervalSeq::new(vec![closed(1, 3), closed(3, 5), closed(12, 25)]);
+  let diff = IntervalDiff::between(&left, &right);
+  assert_eq!(
+    diff.as_only_left(),
+    &[Interval::over(
+      LimitValue::Limit(10),
+      true,
+      LimitValue::Limit(12),
+      false
+    )]
+  );
+  assert_eq!(
+    diff.as_only_right(),
+    &[Interval::over(
+      LimitValue::Limit(20),
+      false,
+      LimitValue::Limit(25),
+      true
+    )]
+  );
+  assert_eq!(
+    diff.to_string(),
+    " only in left: [10, 12)\nonly in right: (20, 25]"
+  );
+  assert!(IntervalDiff::between(&left, &left).is_empty());
+}
+
+#[test]
+fn test02_assert_macros() {
+  assert_interval_eq!(closed(1, 5), closed(1, 5));
+  assert_seq_set_eq!(
+    IntervalSeq::new(vec![closed(1, 5)]),
+    IntervalSeq::new(vec![closed(3, 5), closed(1, 4)])
+  );
+}
+
+#[test]
+#[should_panic(expected = "only in right: (5, 6]")]
+fn test03_assert_interval_eq_failure() {
+  assert_interval_eq!(closed(1, 5), closed(1, 6));
+}
+
+#[test]
+#[should_panic(expected = " only in left: [8, 9]\nonly in right: -")]
+fn test04_assert_seq_set_eq_failure() {
+  assert_seq_set_eq!(
+    IntervalSeq::new(vec![closed(1, 5), closed(8, 9)]),
+    IntervalSeq::new(vec![closed(1, 5)])
+  );
+}
@@ -0,0 +1,96 @@
+use std::ops::{
+  Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+
+use crate::{Interval, LimitValue};
+
+/// A trait for interval-like values, which have a lower bound and an upper bound that may be open, closed or unbounded.
+///
+/// It is implemented by `Interval`, the std range types and `(Bound<T>, Bound<T>)`.
+/// User range newtypes only need to implement `lower_bound` and `upper_bound`.
+pub trait IntervalLike<T> {
+  /// Gets the lower bound.
+  fn lower_bound(&self) -> Bound<&T>;
+
+  /// Gets the upper bound.
+  fn upper_bound(&self) -> Bound<&T>;
+
+  /// Return whether the lower bound is included.
+  fn is_lower_closed(&self) -> bool {
+    matches!(self.lower_bound(), Bound::Included(_))
+  }
+
+  /// Return whether the upper bound is included.
+  fn is_upper_closed(&self) -> bool {
+    matches!(self.upper_bound(), Bound::Included(_))
+  }
+
+  /// Convert this value into an `Interval`.
+  ///
+  /// Reversed bounds, such as `5..3`, are converted into an empty interval like the std ranges consider them.
+  ///
+  /// - return: an interval
+  fn to_interval(&self) -> Interval<T>
+  where
+    T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  {
+    let lower = self.lower_bound().cloned();
+    let upper = self.upper_bound().cloned();
+    Interval::from_bounds(lower.clone(), upper).unwrap_or_else(|_| match lower {
+      Bound::Included(value) | Bound::Excluded(value) => {
+        Interval::open(LimitValue::Limit(value.clone()), LimitValue::Limit(value))
+      }
+      Bound::Unbounded => unreachable!(),
+    })
+  }
+}
+
+macro_rules! impl_interval_like_for_range_bounds {
+  ($($range:ty),*) => {
+    $(
+      impl<T> IntervalLike<T> for $range {
+        fn lower_bound(&self) -> Bound<&T> {
+          self.start_bound()
+        }
+
+        fn upper_bound(&self) -> Bound<&T> {
+          self.end_bound()
+        }
+      }
+    )*
+  };
+}
+
+impl_interval_like_for_range_bounds!(
+  Range<T>,
+  RangeInclusive<T>,
+  RangeFrom<T>,
+  RangeTo<T>,
+  RangeToInclusive<T>,
+  RangeFull,
+  (Bound<T>, Bound<T>)
+);
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalLike<T> for Interval<T> {
+  fn lower_bound(&self) -> Bound<&T> {
+    self.lower_limit().as_bound()
+  }
+
+  fn upper_bound(&self) -> Bound<&T> {
+    self.upper_limit().as_bound()
+  }
+}
+
+/// `Limitless` limits are mapped to `Bound::Unbounded`, open limits to `Excluded` and closed limits to `Included`,
+/// so an interval can be passed to `BTreeMap::range`, `Vec::drain` and the like.
+///
+/// Note that `BTreeMap::range` panics for an empty interval `(x, x)`, as it does for `(Excluded(x), Excluded(x))`.
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> RangeBounds<T> for Interval<T> {
+  fn start_bound(&self) -> Bound<&T> {
+    self.lower_limit().as_bound()
+  }
+
+  fn end_bound(&self) -> Bound<&T> {
+    self.upper_limit().as_bound()
+  }
+}
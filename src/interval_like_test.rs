@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use std::ops::{Bound, RangeBounds};
+
+use crate::{Interval, IntervalLike, LimitValue};
+
+struct Span {
+  from: u32,
+  to: u32,
+}
+
+impl IntervalLike<u32> for Span {
+  fn lower_bound(&self) -> Bound<&u32> {
+    Bound::Included(&self.from)
+  }
+
+  fn upper_bound(&self) -> Bound<&u32> {
+    Bound::Excluded(&self.to)
+  }
+}
+
+fn width_of(range: &impl IntervalLike<i32>) -> Option<i32> {
+  range.to_interval().length()
+}
+
+#[test]
+fn test01_bounds() {
+  let interval = Interval::over(LimitValue::Limit(1), false, LimitValue::Limit(10), true);
+  assert_eq!(interval.lower_bound(), Bound::Excluded(&1));
+  assert_eq!(interval.upper_bound(), Bound::Included(&10));
+  assert!(!interval.is_lower_closed());
+  assert!(interval.is_upper_closed());
+  assert_eq!((1..=10).lower_bound(), Bound::Included(&1));
+  assert_eq!((..10).upper_bound(), Bound::Excluded(&10));
+  assert_eq!((..10).lower_bound(), Bound::Unbounded);
+  assert_eq!(
+    (Bound::Excluded(1), Bound::Unbounded).lower_bound(),
+    Bound::Excluded(&1)
+  );
+}
+
+#[test]
+fn test02_to_interval() {
+  assert_eq!(width_of(&(1..10)), Some(9));
+  assert_eq!(width_of(&(1..=10)), Some(9));
+  assert_eq!(width_of(&(1..)), None);
+  assert_eq!(
+    width_of(&Interval::closed(
+      LimitValue::Limit(3),
+      LimitValue::Limit(5)
+    )),
+    Some(2)
+  );
+  let (start, end) = (5, 3);
+  assert!((start..end).to_interval().is_empty());
+  assert_eq!(
+    Span { from: 2, to: 4 }.to_interval(),
+    Interval::over(LimitValue::Limit(2), true, LimitValue::Limit(4), false)
+  );
+}
+
+#[test]
+fn test03_range_bounds() {
+  let interval = Interval::over(LimitValue::Limit(1), false, LimitValue::Limit(3), true);
+  assert_eq!(interval.start_bound(), Bound::Excluded(&1));
+  assert_eq!(interval.end_bound(), Bound::Included(&3));
+  assert_eq!(
+    Interval::<i32>::and_more(LimitValue::Limit(2)).end_bound(),
+    Bound::Unbounded
+  );
+
+  let map = (0..6).map(|e| (e, e * 10)).collect::<BTreeMap<i32, i32>>();
+  let values = map.range(interval).map(|(_, v)| *v).collect::<Vec<_>>();
+  assert_eq!(values, vec![20, 30]);
+  let keys = map
+    .range(Interval::under(LimitValue::Limit(2)))
+    .map(|(k, _)| *k)
+    .collect::<Vec<_>>();
+  assert_eq!(keys, vec![0, 1]);
+
+  let mut values = vec![0, 1, 2, 3, 4, 5];
+  let drained = values
+    .drain(Interval::closed(
+      LimitValue::Limit(1usize),
+      LimitValue::Limit(2),
+    ))
+    .collect::<Vec<_>>();
+  assert_eq!(drained, vec![1, 2]);
+  assert_eq!(values, vec![0, 3, 4, 5]);
+}
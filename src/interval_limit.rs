@@ -2,7 +2,8 @@ use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, Debug};
 
 use crate::LimitValue;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
+use std::ops::Bound;
 
 /// A struct that represents a "limit" in an interval.
 ///
@@ -22,56 +23,68 @@ use std::hash::Hash;
 /// closed: if the limit is closed `true
 /// lower: `true` for the lower limit, `false` for the upper limit
 /// value: limit value, in the case of Limitless, it indicates that there is no limit.
-#[derive(Debug, Clone, Hash, Eq, Ord)]
-pub struct IntervalLimit<T: Display + Clone + Hash + Ord> {
+#[derive(Debug, Clone, Eq)]
+pub struct IntervalLimit<T: Clone + Ord> {
   closed: bool,
   lower: bool,
   value: LimitValue<T>,
 }
 
-impl<T: Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> PartialEq for IntervalLimit<T> {
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Hash> Hash for IntervalLimit<T> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.closed.hash(state);
+    self.lower.hash(state);
+    self.value.hash(state);
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialEq for IntervalLimit<T> {
   fn eq(&self, other: &Self) -> bool {
-    self.partial_cmp(other) == Some(Ordering::Equal)
+    self.cmp(other) == Ordering::Equal
   }
 }
 
-impl<T: Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> PartialOrd
-  for IntervalLimit<T>
-{
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> PartialOrd for IntervalLimit<T> {
   fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Ord for IntervalLimit<T> {
+  fn cmp(&self, other: &Self) -> Ordering {
     if self.value.is_limitless() && other.value.is_limitless() {
       if self.lower == other.lower {
-        Some(Ordering::Equal)
+        Ordering::Equal
       } else {
-        self.lower_to_ordering(Some(Ordering::Less), Some(Ordering::Greater))
+        self.lower_to_ordering(Ordering::Less, Ordering::Greater)
       }
     } else if self.value.is_limitless() {
-      self.lower_to_ordering(Some(Ordering::Less), Some(Ordering::Greater))
+      self.lower_to_ordering(Ordering::Less, Ordering::Greater)
     } else if other.value.is_limitless() {
-      other.lower_to_ordering(Some(Ordering::Greater), Some(Ordering::Less))
+      other.lower_to_ordering(Ordering::Greater, Ordering::Less)
     } else if self.value == other.value {
       if self.lower && other.lower {
         if self.closed ^ other.closed {
-          self.closed_to_ordering(Some(Ordering::Less), Some(Ordering::Greater))
+          self.closed_to_ordering(Ordering::Less, Ordering::Greater)
         } else {
-          Some(Ordering::Equal)
+          Ordering::Equal
         }
       } else if !self.lower && !other.lower {
         if self.closed ^ other.closed {
-          self.closed_to_ordering(Some(Ordering::Greater), Some(Ordering::Less))
+          self.closed_to_ordering(Ordering::Greater, Ordering::Less)
         } else {
-          Some(Ordering::Equal)
+          Ordering::Equal
         }
       } else {
-        self.lower_to_ordering(Some(Ordering::Less), Some(Ordering::Greater))
+        self.lower_to_ordering(Ordering::Less, Ordering::Greater)
       }
     } else {
-      self.value.partial_cmp(&other.value)
+      self.value.cmp(&other.value)
     }
   }
 }
 
-impl<T: Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> IntervalLimit<T> {
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalLimit<T> {
   /// Verify whether this limit is closed or not.
   ///
   /// - return: if it is closed, `true`, otherwise `false`.
@@ -121,6 +134,42 @@ impl<T: Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> IntervalLimi
     &self.value
   }
 
+  /// Convert this limit into its limit value.
+  ///
+  /// - return: limit value
+  pub fn into_value(self) -> LimitValue<T> {
+    self.value
+  }
+
+  /// Get the limit value, skipping the `LimitValue` layer.
+  ///
+  /// - return: the limit value, or `None` for an infinite limit
+  pub fn finite_value(&self) -> Option<&T> {
+    self.value.as_value().ok()
+  }
+
+  /// Get this limit as a `std::ops::Bound`.
+  ///
+  /// - return: `Included` for a closed limit, `Excluded` for an open finite limit, `Unbounded` for an infinite limit
+  pub fn as_bound(&self) -> Bound<&T> {
+    match &self.value {
+      LimitValue::Limit(value) if self.closed => Bound::Included(value),
+      LimitValue::Limit(value) => Bound::Excluded(value),
+      LimitValue::Limitless => Bound::Unbounded,
+    }
+  }
+
+  /// Convert this limit into a `std::ops::Bound`.
+  ///
+  /// - return: `Included` for a closed limit, `Excluded` for an open finite limit, `Unbounded` for an infinite limit
+  pub fn into_bound(self) -> Bound<T> {
+    match self.value {
+      LimitValue::Limit(value) if self.closed => Bound::Included(value),
+      LimitValue::Limit(value) => Bound::Excluded(value),
+      LimitValue::Limitless => Bound::Unbounded,
+    }
+  }
+
   /// Generate a limit
   ///
   /// - params
@@ -156,6 +205,34 @@ impl<T: Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> IntervalLimi
     Self::new(closed, false, value)
   }
 
+  /// Return the lesser of the given limits under the ordering of limits.
+  ///
+  /// - params
+  ///     - a: a limit
+  ///     - b: a limit
+  /// - return: the lesser limit, `a` if they are equal
+  pub fn min<'a>(a: &'a Self, b: &'a Self) -> &'a Self {
+    if b < a {
+      b
+    } else {
+      a
+    }
+  }
+
+  /// Return the greater of the given limits under the ordering of limits.
+  ///
+  /// - params
+  ///     - a: a limit
+  ///     - b: a limit
+  /// - return: the greater limit, `b` if they are equal
+  pub fn max<'a>(a: &'a Self, b: &'a Self) -> &'a Self {
+    if b < a {
+      a
+    } else {
+      b
+    }
+  }
+
   fn lower_to_ordering<A>(&self, t: A, f: A) -> A {
     if self.lower {
       t
@@ -173,7 +250,7 @@ impl<T: Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> IntervalLimi
   }
 }
 
-impl<T: Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Display for IntervalLimit<T> {
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display> Display for IntervalLimit<T> {
   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     write!(
       f,
@@ -198,26 +198,27 @@ fn test02_compare_to() {
 
 #[test]
 fn test03_sort() {
-  let mut list: Vec<IntervalLimit<i32>> = vec![];
-  list.push(IntervalLimit::upper(false, LimitValue::Limitless));
-  list.push(IntervalLimit::upper(true, LimitValue::Limitless));
-  list.push(IntervalLimit::lower(false, LimitValue::Limitless));
-  list.push(IntervalLimit::lower(true, LimitValue::Limitless));
-  list.push(IntervalLimit::lower(true, LimitValue::Limit(1)));
-  list.push(IntervalLimit::lower(false, LimitValue::Limit(1)));
-  list.push(IntervalLimit::lower(true, LimitValue::Limit(5)));
-  list.push(IntervalLimit::lower(false, LimitValue::Limit(5)));
-  list.push(IntervalLimit::upper(true, LimitValue::Limit(1)));
-  list.push(IntervalLimit::upper(false, LimitValue::Limit(1)));
-  list.push(IntervalLimit::upper(true, LimitValue::Limit(5)));
-  list.push(IntervalLimit::upper(false, LimitValue::Limit(5)));
+  let mut list: Vec<IntervalLimit<i32>> = vec![
+    IntervalLimit::upper(false, LimitValue::Limitless),
+    IntervalLimit::upper(true, LimitValue::Limitless),
+    IntervalLimit::lower(false, LimitValue::Limitless),
+    IntervalLimit::lower(true, LimitValue::Limitless),
+    IntervalLimit::lower(true, LimitValue::Limit(1)),
+    IntervalLimit::lower(false, LimitValue::Limit(1)),
+    IntervalLimit::lower(true, LimitValue::Limit(5)),
+    IntervalLimit::lower(false, LimitValue::Limit(5)),
+    IntervalLimit::upper(true, LimitValue::Limit(1)),
+    IntervalLimit::upper(false, LimitValue::Limit(1)),
+    IntervalLimit::upper(true, LimitValue::Limit(5)),
+    IntervalLimit::upper(false, LimitValue::Limit(5)),
+  ];
 
   let mut rng = rand::thread_rng();
   list.shuffle(&mut rng);
   list.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
   assert_eq!(
-    list.get(0).unwrap(),
+    list.first().unwrap(),
     &IntervalLimit::lower(false, LimitValue::Limitless)
   );
   assert_eq!(
@@ -267,3 +268,42 @@ fn test03_sort() {
     &IntervalLimit::upper(false, LimitValue::Limitless)
   );
 }
+
+#[test]
+fn test04_into_value() {
+  assert_eq!(
+    IntervalLimit::lower(true, LimitValue::Limit(10)).into_value(),
+    LimitValue::Limit(10)
+  );
+  assert_eq!(
+    IntervalLimit::<i32>::upper(true, LimitValue::Limitless).into_value(),
+    LimitValue::Limitless
+  );
+}
+
+#[test]
+fn test05_as_bound() {
+  use std::ops::Bound;
+  let closed = IntervalLimit::lower(true, LimitValue::Limit(10));
+  assert_eq!(closed.as_bound(), Bound::Included(&10));
+  assert_eq!(closed.finite_value(), Some(&10));
+  assert_eq!(
+    IntervalLimit::upper(false, LimitValue::Limit(10)).as_bound(),
+    Bound::Excluded(&10)
+  );
+  let infinite = IntervalLimit::<i32>::upper(true, LimitValue::Limitless);
+  assert_eq!(infinite.as_bound(), Bound::Unbounded);
+  assert_eq!(infinite.finite_value(), None);
+}
+
+#[test]
+fn test06_min_max() {
+  let closed = IntervalLimit::lower(true, LimitValue::Limit(10));
+  let open = IntervalLimit::lower(false, LimitValue::Limit(10));
+  let infinite = IntervalLimit::<i32>::lower(false, LimitValue::Limitless);
+  assert_eq!(IntervalLimit::min(&open, &closed), &closed);
+  assert_eq!(IntervalLimit::max(&open, &closed), &open);
+  assert_eq!(IntervalLimit::min(&closed, &infinite), &infinite);
+  let upper = IntervalLimit::upper(false, LimitValue::Limit(10));
+  assert_eq!(IntervalLimit::max(&upper, &open), &upper);
+}
@@ -0,0 +1,287 @@
+use std::fmt::Debug;
+use std::ops::Sub;
+
+use num_traits::ToPrimitive;
+
+use crate::invariants;
+use crate::{Error, Interval, IntervalSeq, LimitValue};
+
+/// A structure that maps disjoint intervals to values.
+///
+/// Entries are kept sorted by their lower limits and never overlap each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalMap<T: Clone + Eq + Ord + PartialEq + PartialOrd, V> {
+  /// entries sorted by the lower limits of their intervals
+  entries: Vec<(Interval<T>, V)>,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd, V> Default for IntervalMap<T, V> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd, V> IntervalMap<T, V> {
+  /// Generate an empty map.
+  ///
+  /// - return: `IntervalMap`
+  pub fn new() -> Self {
+    Self { entries: vec![] }
+  }
+
+  /// Return whether this map is empty.
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  /// Gets the number of entries in this map.
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  /// Get the value of the entry whose interval includes the specified value `value`.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: the value of the entry, or `None` if no entry includes `value`
+  pub fn get(&self, value: &LimitValue<T>) -> Option<&V> {
+    self.get_entry(value).map(|(_, v)| v)
+  }
+
+  /// Get the entry whose interval includes the specified value `value`.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: the entry, or `None` if no entry includes `value`
+  pub fn get_entry(&self, value: &LimitValue<T>) -> Option<(&Interval<T>, &V)> {
+    let index = self.entries.partition_point(|(e, _)| e.is_below(value));
+    self
+      .entries
+      .get(index)
+      .filter(|(e, _)| e.includes(value))
+      .map(|(e, v)| (e, v))
+  }
+
+  /// Get the entry whose interval has the specified lower limit `value`, in O(log n).
+  ///
+  /// - params
+  ///     - value: a lower limit
+  /// - return: the entry, or `None` if no entry starts at `value`
+  pub fn find_by_lower(&self, value: &LimitValue<T>) -> Option<(&Interval<T>, &V)> {
+    let index = self.partition_point(|e, _| e.as_lower_limit() < value);
+    self
+      .entries
+      .get(index)
+      .filter(|(e, _)| e.as_lower_limit() == value)
+      .map(|(e, v)| (e, v))
+  }
+
+  /// Return the index of the partition point of the entries, like `slice::partition_point`.
+  ///
+  /// The entries are sorted by the lower limits, so the predicate is expected to be monotone in them.
+  ///
+  /// - params
+  ///     - pred: a predicate that is `true` for the entries before the partition point
+  /// - return: the index of the first entry for which `pred` is `false`
+  pub fn partition_point(&self, mut pred: impl FnMut(&Interval<T>, &V) -> bool) -> usize {
+    self.entries.partition_point(|(e, v)| pred(e, v))
+  }
+
+  /// Gets an iterator of the entries of this map, in ascending order.
+  pub fn iter(&self) -> impl Iterator<Item = (&Interval<T>, &V)> {
+    self.entries.iter().map(|(e, v)| (e, v))
+  }
+
+  /// Insert an entry only if its interval does not overlap any existing entry.
+  ///
+  /// An empty interval is ignored.
+  ///
+  /// - params
+  ///     - interval: an interval
+  ///     - value: a value
+  /// - return: `Err(Error::OverlapError)` if the interval overlaps an existing entry
+  pub fn try_insert(&mut self, interval: Interval<T>, value: V) -> Result<(), Error> {
+    if self.entries.iter().any(|(e, _)| e.intersects(&interval)) {
+      Err(Error::OverlapError)
+    } else {
+      self.push_entry(interval, value);
+      Ok(())
+    }
+  }
+
+  fn push_entry(&mut self, interval: Interval<T>, value: V) {
+    if !interval.is_empty() {
+      let index = self
+        .entries
+        .partition_point(|(e, _)| e.lower < interval.lower);
+      self.entries.insert(index, (interval, value));
+    }
+    self.check_invariants();
+  }
+
+  fn check_invariants(&self) {
+    invariants::check_sorted_disjoint("IntervalMap", self.entries.iter().map(|(e, _)| e));
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd, V: Clone> IntervalMap<T, V> {
+  /// Insert an entry, overwriting the overlapped parts of the existing entries.
+  ///
+  /// The existing entries are truncated or split so that the new entry wins where they overlap.
+  /// An empty interval is ignored.
+  ///
+  /// - params
+  ///     - interval: an interval
+  ///     - value: a value
+  pub fn insert(&mut self, interval: Interval<T>, value: V) {
+    if interval.is_empty() {
+      return;
+    }
+    let mut entries = Vec::with_capacity(self.entries.len() + 2);
+    for (e, v) in self.entries.drain(..) {
+      if e.intersects(&interval) {
+        for remainder in interval.complement_relative_to(&e) {
+          if !remainder.is_empty() {
+            entries.push((remainder, v.clone()));
+          }
+        }
+      } else {
+        entries.push((e, v));
+      }
+    }
+    self.entries = entries;
+    self.push_entry(interval, value);
+  }
+
+  /// Insert an entry, combining its value with the values of the existing entries where they overlap.
+  ///
+  /// Each overlapped part gets `combine(existing, value)`, the rest of the new interval gets `value`,
+  /// and the rest of the existing entries keep their values.
+  /// For example, `|a, b| a + b` counts concurrent allocations and `|a, b| a.max(b).clone()` keeps the maximum.
+  /// An empty interval is ignored.
+  ///
+  /// - params
+  ///     - interval: an interval
+  ///     - value: a value
+  ///     - combine: a function of the existing value and the new value
+  pub fn insert_with(
+    &mut self,
+    interval: Interval<T>,
+    value: V,
+    mut combine: impl FnMut(&V, &V) -> V,
+  ) {
+    if interval.is_empty() {
+      return;
+    }
+    let mut entries = Vec::with_capacity(self.entries.len() + 2);
+    let mut overlapped = vec![];
+    for (e, v) in self.entries.drain(..) {
+      if e.intersects(&interval) {
+        for remainder in interval.complement_relative_to(&e) {
+          if !remainder.is_empty() {
+            entries.push((remainder, v.clone()));
+          }
+        }
+        let common = e.intersect(&interval);
+        entries.push((common, combine(&v, &value)));
+        overlapped.push(e);
+      } else {
+        entries.push((e, v));
+      }
+    }
+    for rest in IntervalSeq::new(overlapped).uncovered_parts_of(&interval) {
+      entries.push((rest, value.clone()));
+    }
+    entries.sort_by(|(a, _), (b, _)| a.lower.cmp(&b.lower));
+    self.entries = entries;
+    self.check_invariants();
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd, V: ToPrimitive> IntervalMap<T, V> {
+  /// Return the sum of the values weighted by the lengths of their entries within the given interval `query`.
+  ///
+  /// - params
+  ///     - query: an interval to be aggregated over
+  /// - return: the weighted sum, or `None` if an entry overlaps an unbounded part of `query`
+  pub fn weighted_sum<D>(&self, query: &Interval<T>) -> Option<f64>
+  where
+    T: Sub<Output = D>,
+    D: ToPrimitive,
+  {
+    self
+      .overlap_lengths(query)
+      .map(|(length, value)| Some(length? * value.to_f64()?))
+      .sum()
+  }
+
+  /// Return the average of the values weighted by the lengths of their entries within the given interval `query`,
+  /// e.g. the time-weighted average price over a billing period.
+  ///
+  /// The parts of `query` that are not covered by any entry do not contribute to the average.
+  ///
+  /// - params
+  ///     - query: an interval to be aggregated over
+  /// - return: the weighted average, or `None` if an entry overlaps an unbounded part of `query`
+  ///   or the covered part of `query` has zero length
+  pub fn weighted_average<D>(&self, query: &Interval<T>) -> Option<f64>
+  where
+    T: Sub<Output = D>,
+    D: ToPrimitive,
+  {
+    let covered = self
+      .overlap_lengths(query)
+      .map(|(length, _)| length)
+      .sum::<Option<f64>>()?;
+    if covered <= 0.0 {
+      return None;
+    }
+    Some(self.weighted_sum(query)? / covered)
+  }
+
+  /// Gets an iterator of the lengths of the entries within `query`, as `f64`, paired with their values.
+  fn overlap_lengths<'a, D>(
+    &'a self,
+    query: &'a Interval<T>,
+  ) -> impl Iterator<Item = (Option<f64>, &'a V)>
+  where
+    T: Sub<Output = D>,
+    D: ToPrimitive,
+  {
+    self
+      .entries
+      .iter()
+      .filter(move |(e, _)| e.intersects(query))
+      .map(move |(e, v)| (e.intersect(query).length().and_then(|d: D| d.to_f64()), v))
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd, V: PartialOrd> IntervalMap<T, V> {
+  /// Return the least value of the entries that intersect the given interval `query`.
+  ///
+  /// - params
+  ///     - query: an interval to be aggregated over
+  /// - return: the least value, or `None` if no entry intersects `query`
+  pub fn min_over(&self, query: &Interval<T>) -> Option<&V> {
+    self
+      .entries
+      .iter()
+      .filter(|(e, _)| e.intersects(query))
+      .map(|(_, v)| v)
+      .reduce(|a, b| if b < a { b } else { a })
+  }
+
+  /// Return the greatest value of the entries that intersect the given interval `query`.
+  ///
+  /// - params
+  ///     - query: an interval to be aggregated over
+  /// - return: the greatest value, or `None` if no entry intersects `query`
+  pub fn max_over(&self, query: &Interval<T>) -> Option<&V> {
+    self
+      .entries
+      .iter()
+      .filter(|(e, _)| e.intersects(query))
+      .map(|(_, v)| v)
+      .reduce(|a, b| if b > a { b } else { a })
+  }
+}
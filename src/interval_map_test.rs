@@ -0,0 +1,158 @@
+use crate::{Error, Interval, IntervalMap, LimitValue};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_insert_overwrites() {
+  let mut map = IntervalMap::new();
+  map.insert(closed(0, 100), "base");
+  map.insert(closed(10, 20), "discount");
+  map.insert(closed(90, 120), "premium");
+  assert_eq!(
+    map.iter().collect::<Vec<_>>(),
+    vec![
+      (
+        &Interval::over(LimitValue::Limit(0), true, LimitValue::Limit(10), false),
+        &"base"
+      ),
+      (&closed(10, 20), &"discount"),
+      (
+        &Interval::open(LimitValue::Limit(20), LimitValue::Limit(90)),
+        &"base"
+      ),
+      (&closed(90, 120), &"premium"),
+    ]
+  );
+  assert_eq!(map.get(&LimitValue::Limit(20)), Some(&"discount"));
+  assert_eq!(map.get(&LimitValue::Limit(21)), Some(&"base"));
+  assert_eq!(map.get(&LimitValue::Limit(121)), None);
+
+  map.insert(closed(0, 200), "flat");
+  assert_eq!(map.len(), 1);
+  assert_eq!(map.get(&LimitValue::Limit(150)), Some(&"flat"));
+}
+
+#[test]
+fn test02_try_insert() {
+  let mut map = IntervalMap::new();
+  assert!(map.try_insert(closed(1, 5), 1).is_ok());
+  assert!(map
+    .try_insert(
+      Interval::over(LimitValue::Limit(5), false, LimitValue::Limit(8), true),
+      2
+    )
+    .is_ok());
+  assert!(matches!(
+    map.try_insert(closed(8, 10), 3),
+    Err(Error::OverlapError)
+  ));
+  assert_eq!(map.len(), 2);
+  assert_eq!(map.get(&LimitValue::Limit(5)), Some(&1));
+  assert_eq!(map.get(&LimitValue::Limit(6)), Some(&2));
+  assert_eq!(map.get(&LimitValue::Limit(9)), None);
+}
+
+#[test]
+fn test03_find_by_lower() {
+  let mut map = IntervalMap::new();
+  map.insert(closed(0, 9), "a");
+  map.insert(closed(10, 19), "b");
+  map.insert(closed(30, 39), "c");
+  assert_eq!(
+    map.find_by_lower(&LimitValue::Limit(10)),
+    Some((&closed(10, 19), &"b"))
+  );
+  assert_eq!(map.find_by_lower(&LimitValue::Limit(11)), None);
+  assert_eq!(
+    map.partition_point(|e, _| e.as_upper_limit() < &LimitValue::Limit(25)),
+    2
+  );
+}
+
+#[test]
+fn test04_aggregations() {
+  let mut prices = IntervalMap::new();
+  prices.insert(
+    Interval::over(LimitValue::Limit(0), true, LimitValue::Limit(10), false),
+    100,
+  );
+  prices.insert(
+    Interval::over(LimitValue::Limit(10), true, LimitValue::Limit(20), false),
+    200,
+  );
+  prices.insert(
+    Interval::over(LimitValue::Limit(30), true, LimitValue::Limit(40), false),
+    50,
+  );
+  let period = Interval::over(LimitValue::Limit(5), true, LimitValue::Limit(20), false);
+  assert_eq!(
+    prices.weighted_sum(&period),
+    Some(5.0 * 100.0 + 10.0 * 200.0)
+  );
+  assert_eq!(prices.weighted_average(&period), Some(2500.0 / 15.0));
+  assert_eq!(prices.min_over(&period), Some(&100));
+  assert_eq!(prices.max_over(&period), Some(&200));
+
+  let uncovered = Interval::open(LimitValue::Limit(20), LimitValue::Limit(30));
+  assert_eq!(prices.weighted_sum(&uncovered), Some(0.0));
+  assert_eq!(prices.weighted_average(&uncovered), None);
+  assert_eq!(prices.min_over(&uncovered), None);
+  assert_eq!(
+    prices.weighted_sum(&Interval::and_more(LimitValue::Limit(35))),
+    Some(5.0 * 50.0)
+  );
+  assert_eq!(
+    prices.max_over(&Interval::open(
+      LimitValue::Limitless,
+      LimitValue::Limitless
+    )),
+    Some(&200)
+  );
+}
+
+#[test]
+fn test05_insert_with() {
+  let closed = |lower, upper| Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper));
+  let mut allocations = IntervalMap::new();
+  allocations.insert_with(closed(0, 10), 1, |a, b| a + b);
+  allocations.insert_with(closed(5, 20), 2, |a, b| a + b);
+  allocations.insert_with(closed(30, 40), 1, |a, b| a + b);
+  allocations.insert_with(
+    Interval::open(LimitValue::Limitless, LimitValue::Limitless),
+    0,
+    |a, b| a + b,
+  );
+  assert_eq!(
+    allocations
+      .iter()
+      .map(|(e, v)| (e.clone(), *v))
+      .collect::<Vec<_>>(),
+    vec![
+      (Interval::under(LimitValue::Limit(0)), 0),
+      (
+        Interval::over(LimitValue::Limit(0), true, LimitValue::Limit(5), false),
+        1
+      ),
+      (closed(5, 10), 3),
+      (
+        Interval::over(LimitValue::Limit(10), false, LimitValue::Limit(20), true),
+        2
+      ),
+      (
+        Interval::open(LimitValue::Limit(20), LimitValue::Limit(30)),
+        0
+      ),
+      (closed(30, 40), 1),
+      (Interval::more_than(LimitValue::Limit(40)), 0),
+    ]
+  );
+  assert_eq!(allocations.get(&LimitValue::Limit(7)), Some(&3));
+
+  let mut peaks = IntervalMap::new();
+  peaks.insert_with(closed(0, 10), 5, |a: &i32, b| *a.max(b));
+  peaks.insert_with(closed(5, 15), 3, |a: &i32, b| *a.max(b));
+  assert_eq!(peaks.get(&LimitValue::Limit(7)), Some(&5));
+  assert_eq!(peaks.get(&LimitValue::Limit(12)), Some(&3));
+}
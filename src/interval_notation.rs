@@ -0,0 +1,270 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use crate::{Error, Interval, IntervalSeq, LimitValue};
+
+/// The bracket convention used to write intervals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalNotation {
+  /// open limits are written with parentheses, e.g. `(1, 10]`
+  Standard,
+  /// open limits are written with reversed brackets, e.g. `]1, 10]`
+  European,
+}
+
+/// A configurable formatter of intervals in plain mathematical notation, e.g. `[1, 10)`.
+///
+/// The default configuration writes the standard notation with `-inf`, `+inf` and `, `, which `FromStr` parses back,
+/// except for the empty interval `{}`, which `Interval::parse_with_empty_at` parses back.
+/// The parser also accepts the `∞` symbols and the `; ` separator, but not other custom symbols or separators.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalFormatter {
+  notation: IntervalNotation,
+  lower_infinity: String,
+  upper_infinity: String,
+  separator: String,
+}
+
+impl Default for IntervalFormatter {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl IntervalFormatter {
+  /// Generate a formatter with the standard notation, `-inf`, `+inf` and `, `.
+  ///
+  /// - return: `IntervalFormatter`
+  pub fn new() -> Self {
+    Self {
+      notation: IntervalNotation::Standard,
+      lower_infinity: "-inf".to_string(),
+      upper_infinity: "+inf".to_string(),
+      separator: ", ".to_string(),
+    }
+  }
+
+  /// Set the bracket convention.
+  ///
+  /// - params
+  ///     - value: the bracket convention
+  /// - return: `IntervalFormatter`
+  pub fn notation(mut self, value: IntervalNotation) -> Self {
+    self.notation = value;
+    self
+  }
+
+  /// Set the symbols written for Limitless limits, e.g. `-∞` and `∞`.
+  ///
+  /// - params
+  ///     - lower: the symbol for a Limitless lower limit
+  ///     - upper: the symbol for a Limitless upper limit
+  /// - return: `IntervalFormatter`
+  pub fn infinity(mut self, lower: &str, upper: &str) -> Self {
+    self.lower_infinity = lower.to_string();
+    self.upper_infinity = upper.to_string();
+    self
+  }
+
+  /// Set the separator written between the limits, e.g. `; ` for the European notation.
+  ///
+  /// - params
+  ///     - value: the separator
+  /// - return: `IntervalFormatter`
+  pub fn separator(mut self, value: &str) -> Self {
+    self.separator = value.to_string();
+    self
+  }
+
+  /// Format the given interval.
+  ///
+  /// An empty interval is written as `{}` and a single-element interval as `{value}`.
+  ///
+  /// - params
+  ///     - interval: an interval
+  /// - return: the formatted string
+  pub fn format<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display>(
+    &self,
+    interval: &Interval<T>,
+  ) -> String {
+    if interval.is_empty() {
+      return "{}".to_string();
+    }
+    if interval.is_single_element() {
+      return format!("{{{}}}", interval.as_lower_limit().as_value().unwrap());
+    }
+    let (lower_open, upper_open) = match self.notation {
+      IntervalNotation::Standard => ('(', ')'),
+      IntervalNotation::European => (']', '['),
+    };
+    let lower = match interval.as_lower_limit() {
+      LimitValue::Limit(value) => value.to_string(),
+      LimitValue::Limitless => self.lower_infinity.clone(),
+    };
+    let upper = match interval.as_upper_limit() {
+      LimitValue::Limit(value) => value.to_string(),
+      LimitValue::Limitless => self.upper_infinity.clone(),
+    };
+    format!(
+      "{}{}{}{}{}",
+      if interval.includes_lower_limit() {
+        '['
+      } else {
+        lower_open
+      },
+      lower,
+      self.separator,
+      upper,
+      if interval.includes_upper_limit() {
+        ']'
+      } else {
+        upper_open
+      }
+    )
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display> Interval<T> {
+  /// Format this interval with plain values in the given notation.
+  ///
+  /// Limitless limits are written as `-inf` and `+inf`, an empty interval as `{}`
+  /// and a single-element interval as `{value}`. Use `IntervalFormatter` for other symbols and separators.
+  ///
+  /// - params
+  ///     - notation: the bracket convention
+  /// - return: the formatted string
+  pub fn format_with(&self, notation: IntervalNotation) -> String {
+    IntervalFormatter::new().notation(notation).format(self)
+  }
+
+  /// Format this interval as a LaTeX math expression, e.g. `\left[1, 10\right)` or `\left(-\infty, 5\right]`.
+  ///
+  /// An empty interval is written as `\emptyset` and a single-element interval as `\{value\}`.
+  ///
+  /// - return: the LaTeX string
+  pub fn to_latex(&self) -> String {
+    if self.is_empty() {
+      return "\\emptyset".to_string();
+    }
+    if self.is_single_element() {
+      return format!("\\{{{}\\}}", self.as_lower_limit().as_value().unwrap());
+    }
+    let lower = match self.as_lower_limit() {
+      LimitValue::Limit(value) => value.to_string(),
+      LimitValue::Limitless => "-\\infty".to_string(),
+    };
+    let upper = match self.as_upper_limit() {
+      LimitValue::Limit(value) => value.to_string(),
+      LimitValue::Limitless => "\\infty".to_string(),
+    };
+    format!(
+      "\\left{}{}, {}\\right{}",
+      if self.includes_lower_limit() {
+        '['
+      } else {
+        '('
+      },
+      lower,
+      upper,
+      if self.includes_upper_limit() {
+        ']'
+      } else {
+        ')'
+      }
+    )
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display> IntervalSeq<T> {
+  /// Format the union of this interval sequence as a LaTeX math expression,
+  /// e.g. `\left[1, 3\right] \cup \left(5, 7\right)`.
+  ///
+  /// Overlapping or adjacent element intervals are merged, and an empty union is written as `\emptyset`.
+  ///
+  /// - return: the LaTeX string
+  pub fn to_latex(&self) -> String {
+    let intervals = self.coalesced_intervals();
+    if intervals.is_empty() {
+      "\\emptyset".to_string()
+    } else {
+      intervals
+        .iter()
+        .map(|e| e.to_latex())
+        .collect::<Vec<String>>()
+        .join(" \\cup ")
+    }
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + FromStr> Interval<T> {
+  /// Parse an interval like `FromStr`, but parse `{}` as an empty interval at `pivot`.
+  ///
+  /// An empty interval has no location of its own, so the caller chooses it.
+  ///
+  /// - params
+  ///     - s: an interval written in the standard or the European notation
+  ///     - pivot: the value of both limits of the empty interval
+  /// - return: an interval, or `Err(Error::Parse)` if `s` is not a valid interval
+  pub fn parse_with_empty_at(s: &str, pivot: T) -> Result<Self, Error> {
+    Self::parse_notation(s, || Ok(Interval::empty_at(pivot)))
+  }
+
+  fn parse_notation(s: &str, empty: impl FnOnce() -> Result<Self, Error>) -> Result<Self, Error> {
+    let s = s.trim();
+    let error = || Error::Parse(format!("invalid interval: {}", s));
+    if let Some(inner) = s.strip_prefix('{') {
+      let inner = inner.strip_suffix('}').ok_or_else(error)?.trim();
+      return if inner.is_empty() {
+        empty()
+      } else {
+        let value = inner.parse::<T>().map_err(|_| error())?;
+        Ok(Interval::single(value))
+      };
+    }
+    let mut chars = s.chars();
+    let lower_closed = match chars.next() {
+      Some('[') => true,
+      Some('(') | Some(']') => false,
+      _ => return Err(error()),
+    };
+    let upper_closed = match chars.next_back() {
+      Some(']') => true,
+      Some(')') | Some('[') => false,
+      _ => return Err(error()),
+    };
+    let inner = chars.as_str();
+    let separator = if inner.contains(';') { ';' } else { ',' };
+    let (lower, upper) = inner.split_once(separator).ok_or_else(error)?;
+    let lower = match lower.trim() {
+      "-inf" | "-∞" => LimitValue::Limitless,
+      value => LimitValue::Limit(value.parse::<T>().map_err(|_| error())?),
+    };
+    let upper = match upper.trim() {
+      "inf" | "+inf" | "∞" | "+∞" => LimitValue::Limitless,
+      value => LimitValue::Limit(value.parse::<T>().map_err(|_| error())?),
+    };
+    Interval::try_over(lower, lower_closed, upper, upper_closed).map_err(|_| error())
+  }
+}
+
+impl<T> FromStr for Interval<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + FromStr,
+{
+  type Err = Error;
+
+  /// Parse an interval written in the standard or the European notation.
+  ///
+  /// Accepts strings like `[1, 10)`, `]1, 10[`, `(-inf, 5]` and `{3}`.
+  /// The limits may be separated by `;` instead of `,`, e.g. `]1,5; 2[`.
+  /// `{}` is rejected because an empty interval has no location; use `parse_with_empty_at` to parse it.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::parse_notation(s, || {
+      Err(Error::Parse(format!(
+        "empty interval without a location: {}",
+        s.trim()
+      )))
+    })
+  }
+}
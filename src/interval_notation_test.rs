@@ -0,0 +1,171 @@
+use rust_decimal::Decimal;
+
+use crate::{Error, Interval, IntervalFormatter, IntervalNotation, IntervalSeq, LimitValue};
+
+#[test]
+fn test01_parse_standard() {
+  assert_eq!(
+    "[1, 10)".parse::<Interval<i32>>().unwrap(),
+    Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(10), false)
+  );
+  assert_eq!(
+    "(-inf, 5]".parse::<Interval<i32>>().unwrap(),
+    Interval::up_to(LimitValue::Limit(5))
+  );
+  assert_eq!(
+    " (3,+inf) ".parse::<Interval<i32>>().unwrap(),
+    Interval::more_than(LimitValue::Limit(3))
+  );
+  assert_eq!("{3}".parse::<Interval<i32>>().unwrap(), Interval::single(3));
+}
+
+#[test]
+fn test02_parse_european() {
+  assert_eq!(
+    "]1, 10[".parse::<Interval<i32>>().unwrap(),
+    Interval::open(LimitValue::Limit(1), LimitValue::Limit(10))
+  );
+  assert_eq!(
+    "]-∞, 5]".parse::<Interval<i32>>().unwrap(),
+    Interval::up_to(LimitValue::Limit(5))
+  );
+  assert_eq!(
+    "[1.5; 2.5[".parse::<Interval<Decimal>>().unwrap(),
+    Interval::over(
+      LimitValue::Limit(Decimal::new(15, 1)),
+      true,
+      LimitValue::Limit(Decimal::new(25, 1)),
+      false
+    )
+  );
+}
+
+#[test]
+fn test03_parse_errors() {
+  for s in [
+    "",
+    "1, 10",
+    "[1 10]",
+    "[a, 10]",
+    "[10, 1]",
+    "[+inf, 1]",
+    "{3",
+    "{}",
+  ] {
+    assert!(
+      matches!(s.parse::<Interval<i32>>(), Err(Error::Parse(_))),
+      "{}",
+      s
+    );
+  }
+}
+
+#[test]
+fn test04_format_with() {
+  let interval = Interval::over(LimitValue::Limit(1), false, LimitValue::Limit(10), true);
+  assert_eq!(interval.format_with(IntervalNotation::Standard), "(1, 10]");
+  assert_eq!(interval.format_with(IntervalNotation::European), "]1, 10]");
+  let interval = Interval::under(LimitValue::Limit(5));
+  assert_eq!(
+    interval.format_with(IntervalNotation::Standard),
+    "(-inf, 5)"
+  );
+  assert_eq!(
+    interval.format_with(IntervalNotation::European),
+    "]-inf, 5["
+  );
+  assert_eq!(
+    Interval::single(3).format_with(IntervalNotation::European),
+    "{3}"
+  );
+  for notation in [IntervalNotation::Standard, IntervalNotation::European] {
+    assert_eq!(
+      interval
+        .format_with(notation)
+        .parse::<Interval<i32>>()
+        .unwrap(),
+      interval
+    );
+  }
+}
+
+#[test]
+fn test05_to_latex() {
+  assert_eq!(
+    Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(10), false).to_latex(),
+    "\\left[1, 10\\right)"
+  );
+  assert_eq!(
+    Interval::up_to(LimitValue::Limit(5)).to_latex(),
+    "\\left(-\\infty, 5\\right]"
+  );
+  assert_eq!(Interval::single(3).to_latex(), "\\{3\\}");
+  assert_eq!(
+    Interval::open(LimitValue::Limit(3), LimitValue::Limit(3)).to_latex(),
+    "\\emptyset"
+  );
+  let seq = IntervalSeq::new(vec![
+    Interval::open(LimitValue::Limit(5), LimitValue::Limit(7)),
+    Interval::closed(LimitValue::Limit(1), LimitValue::Limit(2)),
+    Interval::closed(LimitValue::Limit(2), LimitValue::Limit(3)),
+  ]);
+  assert_eq!(
+    seq.to_latex(),
+    "\\left[1, 3\\right] \\cup \\left(5, 7\\right)"
+  );
+  assert_eq!(IntervalSeq::<i32>::empty().to_latex(), "\\emptyset");
+}
+
+#[test]
+fn test06_interval_formatter() {
+  let half_open = Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(10), false);
+  let up_to = Interval::up_to(LimitValue::Limit(5));
+  let formatter = IntervalFormatter::new();
+  assert_eq!(formatter.format(&half_open), "[1, 10)");
+  assert_eq!(formatter.format(&up_to), "(-inf, 5]");
+
+  let unicode = IntervalFormatter::new().infinity("-∞", "∞");
+  assert_eq!(unicode.format(&up_to), "(-∞, 5]");
+  let european = IntervalFormatter::new()
+    .notation(IntervalNotation::European)
+    .separator("; ");
+  assert_eq!(european.format(&half_open), "[1; 10[");
+  assert_eq!(
+    IntervalFormatter::new()
+      .separator(" .. ")
+      .format(&half_open),
+    "[1 .. 10)"
+  );
+  assert_eq!(unicode.format(&Interval::<i32>::empty()), "{}");
+  assert_eq!(unicode.format(&Interval::single(3)), "{3}");
+
+  for formatter in [formatter, unicode, european] {
+    for interval in [
+      half_open.clone(),
+      up_to.clone(),
+      Interval::empty(),
+      Interval::single(3),
+    ] {
+      assert_eq!(
+        Interval::parse_with_empty_at(&formatter.format(&interval), 0).unwrap(),
+        interval
+      );
+    }
+  }
+}
+
+#[test]
+fn test07_parse_with_empty_at() {
+  assert_eq!(
+    Interval::parse_with_empty_at(" { } ", 5).unwrap(),
+    Interval::empty_at(5)
+  );
+  assert_eq!(
+    Interval::parse_with_empty_at("[1, 10)", 5).unwrap(),
+    Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(10), false)
+  );
+  assert!(matches!(
+    Interval::<i32>::parse_with_empty_at("{3", 5),
+    Err(Error::Parse(_))
+  ));
+}
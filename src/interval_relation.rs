@@ -0,0 +1,163 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+
+use crate::{Interval, IntervalSeq, OverlapMatrix};
+
+/// The thirteen relations of Allen's interval algebra.
+///
+/// Each variant describes the position of an interval relative to another interval.
+/// Touching limits are judged by the open/closed state, so `[1, 3)` meets `[3, 5]` while `[1, 3]` overlaps it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IntervalRelation {
+  /// entirely below the other, with a gap
+  Before,
+  /// entirely below the other, without a gap
+  Meets,
+  /// starts below the other and ends inside it
+  Overlaps,
+  /// starts with the other and ends inside it
+  Starts,
+  /// strictly inside the other
+  During,
+  /// starts inside the other and ends with it
+  Finishes,
+  /// same limits as the other
+  Equals,
+  /// starts below the other and ends with it
+  FinishedBy,
+  /// strictly encloses the other
+  Contains,
+  /// starts with the other and ends above it
+  StartedBy,
+  /// starts inside the other and ends above it
+  OverlappedBy,
+  /// entirely above the other, without a gap
+  MetBy,
+  /// entirely above the other, with a gap
+  After,
+}
+
+impl IntervalRelation {
+  /// Gets the relation seen from the other interval.
+  pub fn inverse(&self) -> Self {
+    match self {
+      IntervalRelation::Before => IntervalRelation::After,
+      IntervalRelation::Meets => IntervalRelation::MetBy,
+      IntervalRelation::Overlaps => IntervalRelation::OverlappedBy,
+      IntervalRelation::Starts => IntervalRelation::StartedBy,
+      IntervalRelation::During => IntervalRelation::Contains,
+      IntervalRelation::Finishes => IntervalRelation::FinishedBy,
+      IntervalRelation::Equals => IntervalRelation::Equals,
+      IntervalRelation::FinishedBy => IntervalRelation::Finishes,
+      IntervalRelation::Contains => IntervalRelation::During,
+      IntervalRelation::StartedBy => IntervalRelation::Starts,
+      IntervalRelation::OverlappedBy => IntervalRelation::Overlaps,
+      IntervalRelation::MetBy => IntervalRelation::Meets,
+      IntervalRelation::After => IntervalRelation::Before,
+    }
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Interval<T> {
+  /// Classify the position of this interval relative to the given interval `other`.
+  ///
+  /// An empty interval has no position in Allen's interval algebra, so it is not related to any interval.
+  ///
+  /// - params
+  ///     - other: an interval to be compared
+  /// - return: `IntervalRelation`, or `None` if either interval is empty
+  pub fn relation_to(&self, other: &Interval<T>) -> Option<IntervalRelation> {
+    if self.is_empty() || other.is_empty() {
+      return None;
+    }
+    if !self.intersects(other) {
+      let below = self.lower < other.lower;
+      return Some(match (below, self.abuts(other)) {
+        (true, true) => IntervalRelation::Meets,
+        (true, false) => IntervalRelation::Before,
+        (false, true) => IntervalRelation::MetBy,
+        (false, false) => IntervalRelation::After,
+      });
+    }
+    Some(
+      match (self.lower.cmp(&other.lower), self.upper.cmp(&other.upper)) {
+        (Ordering::Equal, Ordering::Equal) => IntervalRelation::Equals,
+        (Ordering::Equal, Ordering::Less) => IntervalRelation::Starts,
+        (Ordering::Equal, Ordering::Greater) => IntervalRelation::StartedBy,
+        (Ordering::Greater, Ordering::Equal) => IntervalRelation::Finishes,
+        (Ordering::Less, Ordering::Equal) => IntervalRelation::FinishedBy,
+        (Ordering::Greater, Ordering::Less) => IntervalRelation::During,
+        (Ordering::Less, Ordering::Greater) => IntervalRelation::Contains,
+        (Ordering::Less, Ordering::Less) => IntervalRelation::Overlaps,
+        (Ordering::Greater, Ordering::Greater) => IntervalRelation::OverlappedBy,
+      },
+    )
+  }
+}
+
+/// A classification of every pair of element intervals of two interval sequences by `IntervalRelation`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationReport {
+  /// relations of the pairs, rows for the left and columns for the right interval sequence
+  relations: OverlapMatrix<Option<IntervalRelation>>,
+  /// the number of pairs for each relation
+  counts: BTreeMap<IntervalRelation, usize>,
+}
+
+impl RelationReport {
+  /// Gets the relations of the pairs, which are `None` for the pairs with an empty interval.
+  ///
+  /// The rows follow the positions of the left interval sequence and the columns those of the right one.
+  pub fn as_relations(&self) -> &OverlapMatrix<Option<IntervalRelation>> {
+    &self.relations
+  }
+
+  /// Gets the number of pairs for each relation that occurs, not counting the pairs with an empty interval.
+  pub fn as_counts(&self) -> &BTreeMap<IntervalRelation, usize> {
+    &self.counts
+  }
+
+  /// Gets the number of pairs in the given relation.
+  ///
+  /// - params
+  ///     - relation: a relation
+  /// - return: the number of pairs
+  pub fn count(&self, relation: IntervalRelation) -> usize {
+    self.counts.get(&relation).copied().unwrap_or(0)
+  }
+}
+
+impl Display for RelationReport {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let counts = self
+      .counts
+      .iter()
+      .map(|(relation, count)| format!("{:?}: {}", relation, count))
+      .collect::<Vec<String>>();
+    write!(f, "{}", counts.join(", "))
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalSeq<T> {
+  /// Classify each element interval of this interval sequence against each element interval of `other`.
+  ///
+  /// The rows follow the positions of this interval sequence and the columns those of `other`, as returned by `get`.
+  ///
+  /// - params
+  ///     - other: an interval sequence
+  /// - return: `RelationReport`
+  pub fn relation_report(&self, other: &Self) -> RelationReport {
+    let (left, right) = (self.as_slice(), other.as_slice());
+    let mut counts = BTreeMap::new();
+    let relations = OverlapMatrix::from_fn(left.len(), right.len(), |row, column| {
+      let relation = left[row].relation_to(&right[column]);
+      if let Some(relation) = relation {
+        *counts.entry(relation).or_insert(0) += 1;
+      }
+      relation
+    });
+    RelationReport { relations, counts }
+  }
+}
@@ -0,0 +1,93 @@
+use crate::{Interval, IntervalRelation, IntervalSeq, LimitValue};
+
+fn over(lower: i32, lower_included: bool, upper: i32, upper_included: bool) -> Interval<i32> {
+  Interval::over(
+    LimitValue::Limit(lower),
+    lower_included,
+    LimitValue::Limit(upper),
+    upper_included,
+  )
+}
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  over(lower, true, upper, true)
+}
+
+#[test]
+fn test01_relation_to() {
+  let base = closed(10, 20);
+  let cases = vec![
+    (closed(0, 5), IntervalRelation::Before),
+    (over(0, true, 10, false), IntervalRelation::Meets),
+    (closed(0, 10), IntervalRelation::Overlaps),
+    (closed(10, 15), IntervalRelation::Starts),
+    (closed(12, 15), IntervalRelation::During),
+    (over(10, false, 20, true), IntervalRelation::Finishes),
+    (closed(10, 20), IntervalRelation::Equals),
+    (closed(0, 20), IntervalRelation::FinishedBy),
+    (closed(0, 30), IntervalRelation::Contains),
+    (closed(10, 30), IntervalRelation::StartedBy),
+    (closed(15, 30), IntervalRelation::OverlappedBy),
+    (over(20, false, 30, true), IntervalRelation::MetBy),
+    (closed(25, 30), IntervalRelation::After),
+  ];
+  for (interval, relation) in cases {
+    assert_eq!(interval.relation_to(&base), Some(relation), "{}", interval);
+    assert_eq!(
+      base.relation_to(&interval),
+      Some(relation.inverse()),
+      "{}",
+      interval
+    );
+  }
+  assert_eq!(
+    Interval::under(LimitValue::Limit(15)).relation_to(&base),
+    Some(IntervalRelation::Overlaps)
+  );
+  assert_eq!(
+    Interval::<i32>::open(LimitValue::Limitless, LimitValue::Limitless).relation_to(
+      &Interval::open(LimitValue::Limitless, LimitValue::Limitless)
+    ),
+    Some(IntervalRelation::Equals)
+  );
+}
+
+#[test]
+fn test02_relation_report() {
+  let imported = IntervalSeq::new(vec![closed(0, 5), closed(12, 15), closed(30, 40)]);
+  let schedule = IntervalSeq::new(vec![closed(10, 20), closed(35, 50)]);
+  let report = imported.relation_report(&schedule);
+  assert_eq!(report.as_relations().rows(), 3);
+  assert_eq!(
+    report.as_relations().row(2),
+    Some(
+      &[
+        Some(IntervalRelation::After),
+        Some(IntervalRelation::Overlaps)
+      ][..]
+    )
+  );
+  assert_eq!(report.count(IntervalRelation::Before), 3);
+  assert_eq!(report.count(IntervalRelation::During), 1);
+  assert_eq!(report.count(IntervalRelation::Equals), 0);
+  assert_eq!(
+    report.to_string(),
+    "Before: 3, Overlaps: 1, During: 1, After: 1"
+  );
+}
+
+#[test]
+fn test03_relation_with_empty() {
+  let base = closed(10, 20);
+  let inside = over(15, false, 15, false);
+  assert_eq!(inside.relation_to(&base), None);
+  assert_eq!(base.relation_to(&inside), None);
+  assert_eq!(over(30, false, 30, false).relation_to(&base), None);
+  assert_eq!(inside.relation_to(&inside), None);
+
+  let imported = IntervalSeq::new(vec![closed(0, 5), inside]);
+  let schedule = IntervalSeq::new(vec![base]);
+  let report = imported.relation_report(&schedule);
+  assert_eq!(report.as_relations().row(1), Some(&[None][..]));
+  assert_eq!(report.to_string(), "Before: 1");
+}
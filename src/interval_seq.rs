@@ -1,8 +1,17 @@
-use std::cmp::Ordering;
-use std::fmt::{Debug, Display};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Debug;
 use std::hash::Hash;
+use std::iter::{FusedIterator, Peekable};
+use std::ops::{RangeBounds, Sub};
 
-use crate::{Interval, IntervalLimit, to_ordering};
+use num_traits::{FromPrimitive, Num, ToPrimitive, Zero};
+
+use crate::invariants;
+use crate::{
+  Error, Interval, IntervalLimit, IntervalSeqBuilder, IntervalSeqCursor, LimitValue, OverlapMatrix,
+  SortSpec,
+};
 
 #[derive(Clone)]
 pub enum Ordered {
@@ -17,100 +26,199 @@ pub enum Ordered {
 }
 
 impl Ordered {
-  fn lower_factor(&self) -> i8 {
+  fn inverse_lower(&self) -> bool {
     match self {
-      Ordered::UpperLower { inverse_lower, .. } => {
-        if *inverse_lower {
-          -1
-        } else {
-          1
-        }
-      }
-      Ordered::LowerUpper { inverse_lower, .. } => {
-        if *inverse_lower {
-          -1
-        } else {
-          1
-        }
-      }
+      Ordered::UpperLower { inverse_lower, .. } => *inverse_lower,
+      Ordered::LowerUpper { inverse_lower, .. } => *inverse_lower,
     }
   }
-  fn upper_factor(&self) -> i8 {
+
+  fn inverse_upper(&self) -> bool {
     match self {
-      Ordered::UpperLower { inverse_upper, .. } => {
-        if *inverse_upper {
-          -1
-        } else {
-          1
-        }
-      }
-      Ordered::LowerUpper { inverse_upper, .. } => {
-        if *inverse_upper {
-          -1
-        } else {
-          1
-        }
-      }
+      Ordered::UpperLower { inverse_upper, .. } => *inverse_upper,
+      Ordered::LowerUpper { inverse_upper, .. } => *inverse_upper,
+    }
+  }
+
+  fn directed(ordering: Ordering, inverse: bool) -> Ordering {
+    if inverse {
+      ordering.reverse()
+    } else {
+      ordering
     }
   }
 
   pub fn compare<T>(&self, e1: &Interval<T>, e2: &Interval<T>) -> Ordering
   where
-    T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd,
+    T: Clone + Eq + Ord + PartialEq + PartialOrd,
   {
-    match self {
-      Ordered::UpperLower { .. } => {
-        if e1.is_empty() && e2.is_empty() {
-          Ordering::Equal
-        } else if e1.is_empty() {
-          Ordering::Less
-        } else if e2.is_empty() {
-          Ordering::Greater
-        } else {
-          let upper_comparance = e1.upper.partial_cmp(&e2.upper).unwrap();
-          let lower_comparance = e1.lower.partial_cmp(&e2.lower).unwrap();
-          if upper_comparance != Ordering::Equal {
-            to_ordering(upper_comparance as i8 * self.upper_factor())
-          } else {
-            to_ordering(lower_comparance as i8 * self.lower_factor())
-          }
-        }
-      }
-      Ordered::LowerUpper { .. } => {
-        if e1.is_empty() && e2.is_empty() {
-          Ordering::Equal
-        } else if e1.is_empty() {
-          Ordering::Greater
-        } else if e2.is_empty() {
-          Ordering::Less
-        } else {
-          let upper_comparance = e1.upper.partial_cmp(&e2.upper).unwrap();
-          let lower_comparance = e1.lower.partial_cmp(&e2.lower).unwrap();
-          if upper_comparance != Ordering::Equal {
-            to_ordering(upper_comparance as i8 + self.lower_factor())
-          } else {
-            to_ordering(lower_comparance as i8 * self.upper_factor())
-          }
+    let empties_first = matches!(self, Ordered::UpperLower { .. });
+    match (e1.is_empty(), e2.is_empty()) {
+      (true, true) => Ordering::Equal,
+      (true, false) => Self::directed(Ordering::Less, !empties_first),
+      (false, true) => Self::directed(Ordering::Greater, !empties_first),
+      (false, false) => {
+        let lower = Self::directed(e1.lower.cmp(&e2.lower), self.inverse_lower());
+        let upper = Self::directed(e1.upper.cmp(&e2.upper), self.inverse_upper());
+        match self {
+          Ordered::UpperLower { .. } => upper.then(lower),
+          Ordered::LowerUpper { .. } => lower.then(upper),
         }
       }
     }
   }
 }
 
+/// A segment of a timeline, which is either covered or not covered by an interval sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimelineSegment<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// an interval covered by the interval sequence
+  Covered(Interval<T>),
+  /// an interval not covered by the interval sequence
+  Uncovered(Interval<T>),
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> TimelineSegment<T> {
+  /// Get the interval of this segment.
+  pub fn as_interval(&self) -> &Interval<T> {
+    match self {
+      TimelineSegment::Covered(interval) => interval,
+      TimelineSegment::Uncovered(interval) => interval,
+    }
+  }
+
+  /// Verify whether this segment is covered by the interval sequence.
+  pub fn is_covered(&self) -> bool {
+    matches!(self, TimelineSegment::Covered(_))
+  }
+}
+
+/// A group of element intervals that overlap each other transitively.
+pub struct IntervalCluster<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  hull: Interval<T>,
+  members: IntervalSeq<T>,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalCluster<T> {
+  /// Get the smallest interval that encompasses all the members of this cluster.
+  pub fn as_hull(&self) -> &Interval<T> {
+    &self.hull
+  }
+
+  /// Get the members of this cluster.
+  pub fn as_members(&self) -> &IntervalSeq<T> {
+    &self.members
+  }
+}
+
+/// A policy on empty intervals in an interval sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmptyPolicy {
+  /// empty intervals are stored by `append` and kept in the results of `gap`, `intersections` and `clip`
+  KeepEmpties,
+  /// empty intervals are dropped by `append` and from the results of `gap`, `intersections` and `clip`
+  DropEmpties,
+}
+
 /// A structure that represents an interval sequence (a sequence of multiple Intervals).
-pub struct IntervalSeq<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> {
+///
+/// The positional methods (`get`, `as_slice`, `partition_point`, `remove`, `pop`, `truncate` and `drain`)
+/// refer to the storage order, which is the order of appending until `iter` or `iter_rev` sorts the elements in place.
+pub struct IntervalSeq<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
   /// interval sequence
   intervals: Vec<Interval<T>>,
   /// ordered
   ordered: Ordered,
+  /// the order that takes precedence over `ordered` if set
+  sort_spec: Option<SortSpec<T>>,
+  /// the policy on empty intervals; if not set, `append` stores them and the operations drop them
+  empty_policy: Option<EmptyPolicy>,
 }
 
-impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> IntervalSeq<T> {
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalSeq<T> {
   /// Add an interval element to this interval sequence.
   ///
   /// - value: an interval
   pub fn append(&mut self, value: &Interval<T>) {
-    self.intervals.push(value.clone());
+    if self.stores(value) {
+      self.intervals.push(value.clone());
+    }
+    self.check_invariants();
+  }
+
+  /// Add all the given interval elements to this interval sequence, taking ownership of them.
+  ///
+  /// - params
+  ///     - values: intervals
+  pub fn append_all(&mut self, values: impl IntoIterator<Item = Interval<T>>) {
+    let values = values
+      .into_iter()
+      .filter(|e| self.stores(e))
+      .collect::<Vec<_>>();
+    self.intervals.extend(values);
+    self.check_invariants();
+  }
+
+  /// Concatenate this interval sequence and the given interval sequence `other`.
+  ///
+  /// The ordering of this interval sequence is kept.
+  ///
+  /// - params
+  ///     - other: an interval sequence
+  /// - return: concatenated interval sequence
+  pub fn concat(mut self, other: IntervalSeq<T>) -> Self {
+    self.append_all(other.intervals);
+    self
+  }
+
+  /// Add an interval element to this interval sequence, merging it with the members that overlap or are adjacent to it.
+  ///
+  /// An empty interval is dropped. If this interval sequence is coalesced, it stays coalesced,
+  /// so covered values can be accumulated without periodic `coalesce` passes.
+  ///
+  /// The members are not kept sorted, so each call scans all of them: appending takes O(n) for n members,
+  /// and building a sequence of n intervals this way takes O(n^2) in the worst case.
+  /// For bulk loads, `append_all` followed by a single `coalesce` takes O(n log n).
+  ///
+  /// - params
+  ///     - value: an interval
+  pub fn append_merged(&mut self, value: &Interval<T>) {
+    if value.is_empty() {
+      return;
+    }
+    let mut merged = value.clone();
+    self.intervals.retain(|e| {
+      if e.intersects(&merged) || e.abuts(&merged) {
+        merged = merged.span(e);
+        false
+      } else {
+        true
+      }
+    });
+    self.append(&merged);
+  }
+
+  /// Add an interval element to this interval sequence, rejecting an empty interval.
+  ///
+  /// - params
+  ///     - value: an interval
+  /// - return: `Err(Error::EmptyIntervalError)` if the interval is empty, in which case it is not added
+  pub fn try_append(&mut self, value: &Interval<T>) -> Result<(), Error> {
+    if value.is_empty() {
+      Err(Error::EmptyIntervalError)
+    } else {
+      self.append(value);
+      Ok(())
+    }
+  }
+
+  /// Add an interval element to this interval sequence, silently dropping an empty interval.
+  ///
+  /// - params
+  ///     - value: an interval
+  /// - return: `true` if the interval was added, `false` if it was empty
+  pub fn append_non_empty(&mut self, value: &Interval<T>) -> bool {
+    self.try_append(value).is_ok()
   }
 
   /// Return whether the interval sequence are empty.
@@ -128,53 +236,173 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     Self::new(intervals)
   }
 
+  /// Generate a builder of interval sequences with validation policies.
+  ///
+  /// - return: `IntervalSeqBuilder`
+  pub fn builder() -> IntervalSeqBuilder<T> {
+    IntervalSeqBuilder::new()
+  }
+
   pub fn new(values: impl IntoIterator<Item = Interval<T>>) -> Self {
-    let mut intervals: Vec<Interval<T>> = vec![];
-    values.into_iter().for_each(|e| {
-      intervals.push(e);
-    });
-    Self {
+    Self::from_vec(values.into_iter().collect())
+  }
+
+  /// Generate empty interval sequence that can hold at least `capacity` intervals without reallocating.
+  ///
+  /// - params
+  ///     - capacity: the number of intervals to allocate room for
+  /// - return: `IntervalSeq`
+  pub fn with_capacity(capacity: usize) -> Self {
+    Self::from_vec(Vec::with_capacity(capacity))
+  }
+
+  /// Generate an interval sequence that takes ownership of the given buffer.
+  ///
+  /// No allocation is performed, so a buffer obtained from `into_vec` can be reused by the next computation.
+  /// `IntervalSeqArena` manages such buffers for bulk computations.
+  ///
+  /// - params
+  ///     - intervals: a buffer of intervals
+  /// - return: `IntervalSeq`
+  pub fn from_vec(intervals: Vec<Interval<T>>) -> Self {
+    let result = Self {
       intervals,
       ordered: Ordered::UpperLower {
         inverse_lower: true,
         inverse_upper: false,
       },
+      sort_spec: None,
+      empty_policy: None,
+    };
+    result.check_invariants();
+    result
+  }
+
+  /// Return the buffer of this interval sequence, keeping its allocation.
+  ///
+  /// - return: the element intervals in their current storage order
+  pub fn into_vec(self) -> Vec<Interval<T>> {
+    self.intervals
+  }
+
+  /// Gets the number of intervals this interval sequence can hold without reallocating.
+  pub fn capacity(&self) -> usize {
+    self.intervals.capacity()
+  }
+
+  /// Reserve room for at least `additional` more intervals.
+  ///
+  /// - params
+  ///     - additional: the number of intervals to allocate room for
+  pub fn reserve(&mut self, additional: usize) {
+    self.intervals.reserve(additional);
+  }
+
+  /// Merge many interval sequences into one interval sequence sorted by the lower limits.
+  ///
+  /// A heap-based k-way merge is performed, so merging N intervals from k sequences takes O(N log k)
+  /// when each sequence is already sorted by the lower limits.
+  ///
+  /// - params
+  ///     - seqs: interval sequences to be merged
+  /// - return: merged interval sequence
+  pub fn merge_all(seqs: impl IntoIterator<Item = IntervalSeq<T>>) -> Self {
+    Self::new(Self::k_way_merge(seqs, false))
+  }
+
+  /// Merge many interval sequences into one interval sequence, coalescing overlapping or adjacent intervals.
+  ///
+  /// Empty element intervals are dropped.
+  ///
+  /// - params
+  ///     - seqs: interval sequences to be merged
+  /// - return: merged and coalesced interval sequence
+  pub fn merge_all_coalesced(seqs: impl IntoIterator<Item = IntervalSeq<T>>) -> Self {
+    Self::new(Self::k_way_merge(seqs, true))
+  }
+
+  fn k_way_merge(
+    seqs: impl IntoIterator<Item = IntervalSeq<T>>,
+    coalesce: bool,
+  ) -> Vec<Interval<T>> {
+    let mut sources = seqs
+      .into_iter()
+      .map(|seq| {
+        let mut intervals = seq.intervals;
+        intervals.sort_by(|a, b| a.lower.cmp(&b.lower));
+        intervals.into_iter().peekable()
+      })
+      .collect::<Vec<Peekable<std::vec::IntoIter<Interval<T>>>>>();
+    let mut heap = BinaryHeap::with_capacity(sources.len());
+    for (index, source) in sources.iter_mut().enumerate() {
+      if let Some(interval) = source.peek() {
+        heap.push(Reverse((interval.lower.clone(), index)));
+      }
     }
+    let mut values: Vec<Interval<T>> = vec![];
+    while let Some(Reverse((_, index))) = heap.pop() {
+      let interval = sources[index].next().unwrap();
+      if let Some(next) = sources[index].peek() {
+        heap.push(Reverse((next.lower.clone(), index)));
+      }
+      if !coalesce {
+        values.push(interval);
+      } else if !interval.is_empty() {
+        match values.last_mut() {
+          Some(last) if last.intersects(&interval) || last.abuts(&interval) => {
+            *last = last.span(&interval);
+          }
+          _ => values.push(interval),
+        }
+      }
+    }
+    values
+  }
+
+  /// Return the distinct limits of the non-empty element intervals in ascending order.
+  ///
+  /// Each limit keeps whether it is a lower or an upper limit and whether it is closed,
+  /// so a value may appear more than once, e.g. as the closed upper limit of `[1, 3]` and the open lower limit of `(3, 5]`.
+  ///
+  /// - return: sorted and deduplicated limits
+  pub fn boundaries(&self) -> Vec<IntervalLimit<T>> {
+    let mut limits = self
+      .intervals
+      .iter()
+      .filter(|e| !e.is_empty())
+      .flat_map(|e| vec![e.lower.clone(), e.upper.clone()])
+      .collect::<Vec<IntervalLimit<T>>>();
+    limits.sort();
+    limits.dedup();
+    limits
+  }
+
+  /// Return the distinct finite limit values of the non-empty element intervals in ascending order.
+  ///
+  /// These values split the extent into the elementary intervals of this interval sequence.
+  ///
+  /// - return: sorted and deduplicated values
+  pub fn boundary_values(&self) -> Vec<T> {
+    let mut values = self
+      .boundaries()
+      .into_iter()
+      .filter_map(|e| match e.into_value() {
+        LimitValue::Limit(value) => Some(value),
+        LimitValue::Limitless => None,
+      })
+      .collect::<Vec<T>>();
+    values.dedup();
+    values
   }
 
   /// Return the smallest interval that encompasses all the element intervals.
   ///
+  /// Unlike `Interval::enclose_all`, the limits of empty element intervals are taken into account as well.
+  ///
   /// - return: the smallest interval that encompasses all the elemental intervals.
   /// - panic: if none of the elements are present
   pub fn extent(&self) -> Interval<T> {
-    if self.intervals.is_empty() {
-      panic!("self.interval is empty!")
-    }
-    let first = self.intervals.get(0).unwrap();
-    if self.intervals.len() == 1 {
-      first.clone()
-    } else {
-      let mut lowers = self
-        .intervals
-        .iter()
-        .map(|e| e.lower.clone())
-        .collect::<Vec<IntervalLimit<T>>>();
-      lowers.sort_by(|a, b| a.partial_cmp(&b).unwrap());
-      let lower = lowers.get(0).unwrap();
-      let mut uppers = self
-        .intervals
-        .iter()
-        .map(|e| e.upper.clone())
-        .collect::<Vec<IntervalLimit<T>>>();
-      uppers.sort_by(|a, b| b.partial_cmp(&a).unwrap());
-      let upper = uppers.get(0).unwrap();
-      first.new_of_same_type(
-        lower.as_value().clone(),
-        lower.is_closed(),
-        upper.as_value().clone(),
-        upper.is_closed(),
-      )
-    }
+    Interval::enclose_limits(&self.intervals).expect("self.interval is empty!")
   }
 
   /// In the sorted intervals, return the intervals that are between adjacent intervals as the interval sequence.
@@ -186,19 +414,18 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
   /// - return: gap interval sequence
   pub fn gap(&self) -> Self {
     if self.intervals.len() < 2 {
-      let values: Vec<Interval<T>> = vec![];
-      Self::new(values)
+      self.derive(vec![])
     } else {
       let mut values: Vec<Interval<T>> = vec![];
       for i in 1usize..self.intervals.len() {
         let left = &self.intervals[i - 1];
         let right = &self.intervals[i];
         let gap = left.gap(right);
-        if !gap.is_empty() {
+        if self.yields(&gap) {
           values.push(gap);
         }
       }
-      Self::new(values)
+      self.derive(values)
     }
   }
 
@@ -211,36 +438,639 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
   /// - return: common interval sequence
   pub fn intersections(&self) -> Self {
     if self.intervals.len() < 2 {
-      let values: Vec<Interval<T>> = vec![];
-      Self::new(values)
+      self.derive(vec![])
     } else {
       let mut values: Vec<Interval<T>> = vec![];
       for i in 1usize..self.intervals.len() {
         let left = &self.intervals[i - 1];
         let right = &self.intervals[i];
         let gap = left.intersect(right);
-        if !gap.is_empty() {
+        if self.yields(&gap) {
           values.push(gap);
         }
       }
-      Self::new(values)
+      self.derive(values)
+    }
+  }
+
+  /// Return the interval sequence whose elements are clipped to the given interval `bounds`.
+  ///
+  /// Each element is replaced by its common part with `bounds`, and elements that do not intersect `bounds` are dropped.
+  ///
+  /// - params
+  ///     - bounds: a bounding interval
+  /// - return: clipped interval sequence
+  pub fn clip(&self, bounds: &Interval<T>) -> Self {
+    let values = self
+      .intervals
+      .iter()
+      .map(|e| e.intersect(bounds))
+      .filter(|e| self.yields(e))
+      .collect::<Vec<Interval<T>>>();
+    self.derive(values)
+  }
+
+  /// Return the interval sequence in which overlapping or adjacent element intervals are merged.
+  ///
+  /// Empty element intervals are dropped, and the result is sorted by the lower limits.
+  ///
+  /// - return: coalesced interval sequence
+  pub fn coalesce(&self) -> Self {
+    Self::new(self.coalesced_intervals())
+  }
+
+  /// Return the interval sequence in which element intervals are merged when they overlap, are adjacent,
+  /// or are separated by a gap shorter than `tolerance`.
+  ///
+  /// Empty element intervals are dropped, and the result is sorted by the lower limits.
+  ///
+  /// - params
+  ///     - tolerance: the length below which a gap is bridged
+  /// - return: coalesced interval sequence
+  pub fn coalesce_with_tolerance<D>(&self, tolerance: D) -> Self
+  where
+    T: Sub<Output = D>,
+    D: PartialOrd,
+  {
+    Self::new(self.coalesced_intervals_by(|last, next| {
+      last
+        .gap(next)
+        .length()
+        .is_some_and(|length: D| length < tolerance)
+    }))
+  }
+
+  /// Verify whether this interval sequence and the given interval sequence `other` cover the same set of values.
+  ///
+  /// Unlike a structural comparison, this is independent of the order of the element intervals
+  /// and of how the covered values are split into element intervals.
+  ///
+  /// - params
+  ///     - other: an interval sequence to be compared
+  /// - return: `true` if both cover the same values, `false` otherwise
+  pub fn set_eq(&self, other: &Self) -> bool {
+    self.coalesced_intervals() == other.coalesced_intervals()
+  }
+
+  /// Return the segments of the given interval `bounds` in ascending order,
+  /// alternating between the parts covered by this interval sequence and the parts not covered.
+  ///
+  /// Overlapping or adjacent element intervals are merged into a single covered segment.
+  ///
+  /// - params
+  ///     - bounds: a bounding interval
+  /// - return: an iterator of timeline segments
+  pub fn timeline(&self, bounds: &Interval<T>) -> impl Iterator<Item = TimelineSegment<T>> {
+    let mut segments = self
+      .clip(bounds)
+      .coalesced_intervals()
+      .into_iter()
+      .map(TimelineSegment::Covered)
+      .chain(
+        self
+          .uncovered_parts_of(bounds)
+          .into_iter()
+          .map(TimelineSegment::Uncovered),
+      )
+      .collect::<Vec<TimelineSegment<T>>>();
+    segments.sort_by(|a, b| a.as_interval().lower.cmp(&b.as_interval().lower));
+    segments.into_iter()
+  }
+
+  /// Return the groups of element intervals that overlap each other transitively.
+  ///
+  /// Intervals that are only adjacent (without a common part) belong to different groups, and empty element intervals are dropped.
+  /// The groups are sorted by the lower limits of their hulls.
+  ///
+  /// - return: clusters of overlapping intervals
+  pub fn clusters(&self) -> Vec<IntervalCluster<T>> {
+    let mut sorted = self
+      .intervals
+      .iter()
+      .filter(|e| !e.is_empty())
+      .cloned()
+      .collect::<Vec<Interval<T>>>();
+    sorted.sort_by(|a, b| a.lower.cmp(&b.lower));
+    let mut clusters: Vec<IntervalCluster<T>> = vec![];
+    for interval in sorted {
+      match clusters.last_mut() {
+        Some(cluster) if cluster.hull.intersects(&interval) => {
+          cluster.hull = cluster.hull.span(&interval);
+          cluster.members.append(&interval);
+        }
+        _ => clusters.push(IntervalCluster {
+          hull: interval.clone(),
+          members: Self::new(vec![interval]),
+        }),
+      }
+    }
+    clusters
+  }
+
+  /// Return an undirected graph whose nodes are the element intervals and whose edges connect overlapping intervals.
+  ///
+  /// The node indices follow the order of the element intervals in this interval sequence.
+  /// Adjacent intervals without a common part are not connected.
+  ///
+  /// - return: overlap graph
+  #[cfg(feature = "petgraph")]
+  pub fn overlap_graph(&self) -> petgraph::graph::UnGraph<Interval<T>, ()> {
+    let mut graph = petgraph::graph::UnGraph::with_capacity(self.intervals.len(), 0);
+    let nodes = self
+      .intervals
+      .iter()
+      .map(|e| graph.add_node(e.clone()))
+      .collect::<Vec<petgraph::graph::NodeIndex>>();
+    let mut sorted = (0..self.intervals.len()).collect::<Vec<usize>>();
+    sorted.sort_by(|a, b| self.intervals[*a].lower.cmp(&self.intervals[*b].lower));
+    for (i, a) in sorted.iter().enumerate() {
+      let left = &self.intervals[*a];
+      for b in sorted[i + 1..].iter() {
+        let right = &self.intervals[*b];
+        if left.is_below(right.as_lower_limit()) {
+          break;
+        }
+        if left.intersects(right) {
+          graph.add_edge(nodes[*a], nodes[*b], ());
+        }
+      }
+    }
+    graph
+  }
+
+  /// Return the fraction of the given interval `target` that is covered by this interval sequence.
+  ///
+  /// Overlapping element intervals are counted only once.
+  ///
+  /// - params
+  ///     - target: an interval to be measured
+  /// - return: the covered fraction in `0.0..=1.0`, or `None` if `target` is unbounded or has zero length
+  pub fn coverage_of<D>(&self, target: &Interval<T>) -> Option<f64>
+  where
+    T: Sub<Output = D>,
+    D: ToPrimitive,
+  {
+    let total = target.length()?.to_f64()?;
+    if target.is_empty() || total <= 0.0 {
+      return None;
+    }
+    let uncovered = self
+      .uncovered_parts_of(target)
+      .iter()
+      .map(|e| e.length().and_then(|d: D| d.to_f64()))
+      .sum::<Option<f64>>()?;
+    Some(((total - uncovered) / total).clamp(0.0, 1.0))
+  }
+
+  /// Return whether each element interval of this interval sequence intersects each element interval of `other`.
+  ///
+  /// The rows follow the positions of this interval sequence and the columns those of `other`, as returned by `get`.
+  ///
+  /// - params
+  ///     - other: an interval sequence
+  /// - return: the matrix of `true` for the intersecting pairs
+  pub fn overlap_matrix(&self, other: &Self) -> OverlapMatrix<bool> {
+    OverlapMatrix::from_fn(self.len(), other.len(), |row, column| {
+      self.intervals[row].intersects(&other.intervals[column])
+    })
+  }
+
+  /// Return the length of the common part of each element interval of this interval sequence
+  /// and each element interval of `other`.
+  ///
+  /// The rows follow the positions of this interval sequence and the columns those of `other`, as returned by `get`.
+  ///
+  /// - params
+  ///     - other: an interval sequence
+  /// - return: the matrix of the lengths, where a disjoint pair is zero and an unbounded common part is `None`
+  pub fn overlap_length_matrix<D>(&self, other: &Self) -> OverlapMatrix<Option<D>>
+  where
+    T: Sub<Output = D>,
+    D: Zero,
+  {
+    OverlapMatrix::from_fn(self.len(), other.len(), |row, column| {
+      let common = self.intervals[row].intersect(&other.intervals[column]);
+      if common.is_empty() {
+        Some(D::zero())
+      } else {
+        common.length()
+      }
+    })
+  }
+
+  pub(crate) fn coalesced_intervals(&self) -> Vec<Interval<T>> {
+    self.coalesced_intervals_by(|_, _| false)
+  }
+
+  /// Merge overlapping or adjacent element intervals, and also the pairs for which `bridges` returns `true`.
+  fn coalesced_intervals_by(
+    &self,
+    bridges: impl Fn(&Interval<T>, &Interval<T>) -> bool,
+  ) -> Vec<Interval<T>> {
+    let mut sorted = self
+      .intervals
+      .iter()
+      .filter(|e| !e.is_empty())
+      .cloned()
+      .collect::<Vec<Interval<T>>>();
+    sorted.sort_by(|a, b| a.lower.cmp(&b.lower));
+    let mut values: Vec<Interval<T>> = vec![];
+    for interval in sorted {
+      match values.last_mut() {
+        Some(last)
+          if last.intersects(&interval) || last.abuts(&interval) || bridges(last, &interval) =>
+        {
+          *last = last.span(&interval);
+        }
+        _ => values.push(interval),
+      }
     }
+    values
+  }
+
+  /// Split the values covered by this interval sequence into `n` chunks of approximately equal total length.
+  ///
+  /// The coverage is coalesced first, and intervals are split at the chunk boundaries as needed.
+  /// A split point is included in the upper chunk. With integer values, the boundaries are rounded down.
+  ///
+  /// - params
+  ///     - n: the number of chunks
+  /// - return: `n` interval sequences in ascending order, or `None` if the coverage is unbounded
+  /// - panic
+  ///     - if `n` is zero
+  pub fn chunks_by_length(&self, n: usize) -> Option<Vec<IntervalSeq<T>>>
+  where
+    T: Num + FromPrimitive,
+  {
+    assert!(n > 0, "the number of chunks must be positive");
+    let coverage = self.coalesced_intervals();
+    let lengths = coverage
+      .iter()
+      .map(|e| e.length())
+      .collect::<Option<Vec<T>>>()?;
+    let total = lengths.iter().cloned().fold(T::zero(), |a, b| a + b);
+    let boundary = |k: usize| scale(&total, k, n);
+    let mut chunks: Vec<Vec<Interval<T>>> = (0..n).map(|_| vec![]).collect();
+    let mut next = 1;
+    let mut offset = T::zero();
+    for (interval, length) in coverage.into_iter().zip(lengths) {
+      let end = offset.clone() + length;
+      let mut rest = interval;
+      let mut rest_offset = offset;
+      loop {
+        while next < n && boundary(next)? <= rest_offset {
+          next += 1;
+        }
+        let split_offset = match boundary(next) {
+          Some(b) if next < n && b < end => b,
+          _ => {
+            chunks[next - 1].push(rest);
+            break;
+          }
+        };
+        let split = rest.lower_value()?.clone() + (split_offset.clone() - rest_offset);
+        chunks[next - 1].push(Interval::over(
+          rest.as_lower_limit().clone(),
+          rest.includes_lower_limit(),
+          LimitValue::Limit(split.clone()),
+          false,
+        ));
+        rest = Interval::over(
+          LimitValue::Limit(split),
+          true,
+          rest.as_upper_limit().clone(),
+          rest.includes_upper_limit(),
+        );
+        rest_offset = split_offset;
+      }
+      offset = end;
+    }
+    Some(chunks.into_iter().map(IntervalSeq::new).collect())
+  }
+
+  /// Return `n` values spread proportionally across the values covered by this interval sequence, skipping the gaps.
+  ///
+  /// The covered length is divided into `n` slices of equal length, and the midpoint of each slice is returned
+  /// in ascending order. With integer values, the midpoints are rounded down.
+  /// A midpoint that falls on an open limit is not covered, e.g. the first midpoint `0` of `(0, 10)` when `n` is 10,
+  /// and then `None` is returned rather than fewer than `n` values.
+  ///
+  /// - params
+  ///     - n: the number of values
+  /// - return: `n` covered values, which are none if nothing is covered,
+  ///   or `None` if the coverage is unbounded or a midpoint is not covered
+  pub fn sample_points(&self, n: usize) -> Option<Vec<T>>
+  where
+    T: Num + FromPrimitive,
+  {
+    let coverage = self.coalesced_intervals();
+    let lengths = coverage
+      .iter()
+      .map(|e| e.length())
+      .collect::<Option<Vec<T>>>()?;
+    let total = lengths.iter().cloned().fold(T::zero(), |a, b| a + b);
+    let mut points = Vec::with_capacity(n);
+    let mut pieces = coverage.iter().zip(lengths);
+    let mut piece = pieces.next();
+    let mut offset = T::zero();
+    for k in 0..n {
+      let target = scale(&total, 2 * k + 1, 2 * n)?;
+      while let Some((interval, length)) = &piece {
+        if target < offset.clone() + length.clone() || pieces.len() == 0 {
+          let point = interval.lower_value()?.clone() + (target.clone() - offset.clone());
+          if !interval.includes(&LimitValue::Limit(point.clone())) {
+            return None;
+          }
+          points.push(point);
+          break;
+        }
+        offset = offset + length.clone();
+        piece = pieces.next();
+      }
+    }
+    Some(points)
+  }
+
+  /// Split the element intervals into interval sequences by the key computed by `f`,
+  /// e.g. time windows by day or by resource id.
+  ///
+  /// Each interval sequence inherits the empty policy of this interval sequence.
+  ///
+  /// - params
+  ///     - f: a function that computes the key of an interval
+  /// - return: the interval sequences by key
+  pub fn group_by<K: Eq + Hash>(
+    &self,
+    f: impl Fn(&Interval<T>) -> K,
+  ) -> HashMap<K, IntervalSeq<T>> {
+    let mut groups: HashMap<K, Vec<Interval<T>>> = HashMap::new();
+    for interval in &self.intervals {
+      groups
+        .entry(f(interval))
+        .or_default()
+        .push(interval.clone());
+    }
+    groups
+      .into_iter()
+      .map(|(key, values)| (key, self.derive(values)))
+      .collect()
+  }
+
+  /// Assign the non-empty element intervals to the minimum number of lanes, so that the intervals in a lane do not intersect.
+  ///
+  /// The number of lanes is the minimum number of resources (rooms, machines, tracks) needed to serve all the intervals.
+  ///
+  /// - return: lanes of intervals sorted by their lower limits
+  pub fn lanes(&self) -> Vec<Vec<Interval<T>>> {
+    let mut sorted = self
+      .intervals
+      .iter()
+      .filter(|e| !e.is_empty())
+      .collect::<Vec<&Interval<T>>>();
+    sorted.sort_by(|a, b| a.lower.cmp(&b.lower));
+    let mut lanes: Vec<Vec<Interval<T>>> = vec![];
+    let mut ends: BinaryHeap<Reverse<(IntervalLimit<T>, usize)>> = BinaryHeap::new();
+    for interval in sorted {
+      let lane = match ends.peek() {
+        Some(Reverse((_, lane))) if !lanes[*lane].last().unwrap().intersects(interval) => {
+          let lane = *lane;
+          ends.pop();
+          lane
+        }
+        _ => {
+          lanes.push(vec![]);
+          lanes.len() - 1
+        }
+      };
+      lanes[lane].push(interval.clone());
+      ends.push(Reverse((interval.upper.clone(), lane)));
+    }
+    lanes
+  }
+
+  /// Return the parts of the given interval `target` that are not covered by any element interval.
+  ///
+  /// - params
+  ///     - target: an interval to be examined
+  /// - return: the uncovered parts of `target`, in ascending order
+  pub(crate) fn uncovered_parts_of(&self, target: &Interval<T>) -> Vec<Interval<T>> {
+    let mut remainder: Vec<Interval<T>> = vec![];
+    if !target.is_empty() {
+      remainder.push(target.clone());
+    }
+    for interval in self.intervals.iter().filter(|e| !e.is_empty()) {
+      remainder = remainder
+        .iter()
+        .flat_map(|e| interval.complement_relative_to(e))
+        .filter(|e| !e.is_empty())
+        .collect();
+    }
+    remainder
   }
 
   /// Gets an iterator of this interval sequence.
-  pub fn iter(&mut self) -> impl Iterator<Item = &Interval<T>> {
-    let mut l = self.intervals.clone();
-    l.sort_by(|a, b| self.ordered.compare(a, b));
-    self.intervals = l;
+  ///
+  /// The iterator knows its exact length and can also be traversed from the back.
+  pub fn iter(
+    &mut self,
+  ) -> impl DoubleEndedIterator<Item = &Interval<T>> + ExactSizeIterator + FusedIterator {
+    self.sort();
     self.intervals.iter()
   }
 
-  /// Gets an into iterator of this interval sequence.
-  pub fn into_iter(mut self) -> impl IntoIterator<Item = Interval<T>> {
-    let mut l = self.intervals.clone();
-    l.sort_by(|a, b| self.ordered.compare(a, b));
-    self.intervals = l;
-    self.intervals.into_iter()
+  /// Gets an iterator of this interval sequence in descending order.
+  ///
+  /// Like `iter`, the elements are sorted in place and then walked from the back,
+  /// instead of being copied into a reversed temporary `Vec`.
+  pub fn iter_rev(
+    &mut self,
+  ) -> impl DoubleEndedIterator<Item = &Interval<T>> + ExactSizeIterator + FusedIterator {
+    self.sort();
+    self.intervals.iter().rev()
+  }
+
+  /// Return the index of the partition point in the storage order of this interval sequence, like `slice::partition_point`.
+  ///
+  /// The interval sequence is assumed to be partitioned by the predicate in its storage order.
+  ///
+  /// - params
+  ///     - pred: a predicate that is `true` for the elements before the partition point
+  /// - return: the index of the first element for which `pred` is `false`
+  pub fn partition_point(&self, pred: impl FnMut(&Interval<T>) -> bool) -> usize {
+    self.intervals.partition_point(pred)
+  }
+
+  /// Get the first interval element that has the specified lower limit `value`, in O(log n).
+  ///
+  /// The interval sequence is assumed to be sorted by the lower limits in its storage order,
+  /// as in the order of `Ordered::LowerUpper` or the results of `coalesce`.
+  ///
+  /// - params
+  ///     - value: a lower limit
+  /// - return: the interval, or `None` if no interval starts at `value`
+  pub fn find_by_lower(&self, value: &LimitValue<T>) -> Option<&Interval<T>> {
+    let index = self.partition_point(|e| e.as_lower_limit() < value);
+    self
+      .intervals
+      .get(index)
+      .filter(|e| e.as_lower_limit() == value)
+  }
+
+  /// Remove the interval element at the given position in the storage order of this interval sequence.
+  ///
+  /// - params
+  ///     - index: a position
+  /// - return: the removed interval
+  /// - panic
+  ///     - if `index` is out of bounds
+  pub fn remove(&mut self, index: usize) -> Interval<T> {
+    let removed = self.intervals.remove(index);
+    self.check_invariants();
+    removed
+  }
+
+  /// Remove the first interval element equal to the given interval `value`.
+  ///
+  /// - params
+  ///     - value: an interval
+  /// - return: `true` if an element was removed, `false` otherwise
+  pub fn remove_interval(&mut self, value: &Interval<T>) -> bool {
+    match self.intervals.iter().position(|e| e == value) {
+      Some(index) => {
+        self.remove(index);
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Remove the last interval element in the storage order of this interval sequence.
+  ///
+  /// - return: the removed interval, or `None` if this interval sequence is empty
+  pub fn pop(&mut self) -> Option<Interval<T>> {
+    let removed = self.intervals.pop();
+    self.check_invariants();
+    removed
+  }
+
+  /// Remove all the interval elements, keeping the allocated capacity.
+  pub fn clear(&mut self) {
+    self.intervals.clear();
+    self.check_invariants();
+  }
+
+  /// Keep the first `len` interval elements in the storage order of this interval sequence and remove the rest.
+  ///
+  /// - params
+  ///     - len: the number of elements to keep
+  pub fn truncate(&mut self, len: usize) {
+    self.intervals.truncate(len);
+    self.check_invariants();
+  }
+
+  /// Remove the interval elements in the given range of positions in the storage order of this interval sequence.
+  ///
+  /// The elements are removed before this method returns, so that the invariants can be checked.
+  ///
+  /// - params
+  ///     - range: a range of positions
+  /// - return: an iterator of the removed intervals
+  /// - panic
+  ///     - if `range` is out of bounds
+  pub fn drain(&mut self, range: impl RangeBounds<usize>) -> std::vec::IntoIter<Interval<T>> {
+    let removed = self.intervals.drain(range).collect::<Vec<_>>();
+    self.check_invariants();
+    removed.into_iter()
+  }
+
+  /// Set the order of this interval sequence, which is used instead of the default order by the upper limits.
+  ///
+  /// - params
+  ///     - spec: a sort specification
+  /// - return: `IntervalSeq`
+  pub fn with_sort_spec(mut self, spec: SortSpec<T>) -> Self {
+    self.set_sort_spec(spec);
+    self
+  }
+
+  /// Set the order of this interval sequence, which is used instead of the default order by the upper limits.
+  ///
+  /// - params
+  ///     - spec: a sort specification
+  pub fn set_sort_spec(&mut self, spec: SortSpec<T>) {
+    self.sort_spec = Some(spec);
+  }
+
+  /// Set the policy on empty intervals of this interval sequence.
+  ///
+  /// With `EmptyPolicy::DropEmpties`, the empty intervals already stored are removed.
+  /// The interval sequences returned by `gap`, `intersections` and `clip` inherit the policy.
+  ///
+  /// - params
+  ///     - policy: a policy on empty intervals
+  /// - return: `IntervalSeq`
+  pub fn with_empty_policy(mut self, policy: EmptyPolicy) -> Self {
+    self.set_empty_policy(policy);
+    self
+  }
+
+  /// Set the policy on empty intervals of this interval sequence.
+  ///
+  /// With `EmptyPolicy::DropEmpties`, the empty intervals already stored are removed.
+  ///
+  /// - params
+  ///     - policy: a policy on empty intervals
+  pub fn set_empty_policy(&mut self, policy: EmptyPolicy) {
+    self.empty_policy = Some(policy);
+    if policy == EmptyPolicy::DropEmpties {
+      self.intervals.retain(|e| !e.is_empty());
+    }
+  }
+
+  /// Gets the policy on empty intervals of this interval sequence, if set.
+  pub fn empty_policy(&self) -> Option<EmptyPolicy> {
+    self.empty_policy
+  }
+
+  /// Return whether `append` stores the given interval.
+  fn stores(&self, value: &Interval<T>) -> bool {
+    !value.is_empty() || self.empty_policy != Some(EmptyPolicy::DropEmpties)
+  }
+
+  /// Return whether an operation yields the given resulting interval.
+  fn yields(&self, value: &Interval<T>) -> bool {
+    !value.is_empty() || self.empty_policy == Some(EmptyPolicy::KeepEmpties)
+  }
+
+  /// Generate an interval sequence of the results of an operation, inheriting the policy on empty intervals.
+  fn derive(&self, values: Vec<Interval<T>>) -> Self {
+    let mut result = Self::from_vec(values);
+    result.empty_policy = self.empty_policy;
+    result
+  }
+
+  fn compare(&self, a: &Interval<T>, b: &Interval<T>) -> Ordering {
+    match &self.sort_spec {
+      Some(spec) => spec.compare(a, b),
+      None => self.ordered.compare(a, b),
+    }
+  }
+
+  fn sort(&mut self) {
+    let mut intervals = std::mem::take(&mut self.intervals);
+    intervals.sort_by(|a, b| self.compare(a, b));
+    self.intervals = intervals;
+    invariants::check("IntervalSeq", "members are sorted in the order", || {
+      self
+        .intervals
+        .windows(2)
+        .all(|w| self.compare(&w[0], &w[1]) != Ordering::Greater)
+    });
+  }
+
+  /// Gets a cursor that walks this interval sequence in ascending order of the upper limits.
+  pub fn cursor(&self) -> IntervalSeqCursor<'_, T> {
+    IntervalSeqCursor::new(self)
   }
 
   /// Gets the len of this interval sequence.
@@ -248,8 +1078,53 @@ impl<T: Debug + Display + Clone + Hash + Eq + Ord + PartialEq + PartialOrd> Inte
     self.intervals.len()
   }
 
-  /// Gets the interval in this interval sequence by index
+  /// Gets the interval in this interval sequence by index in the storage order
   pub fn get(&self, idx: usize) -> Option<&Interval<T>> {
     self.intervals.get(idx)
   }
+
+  /// Gets the element intervals of this interval sequence as a slice.
+  ///
+  /// Unlike `iter`, the elements are not sorted, so they are in their current storage order.
+  pub fn as_slice(&self) -> &[Interval<T>] {
+    &self.intervals
+  }
+
+  fn check_invariants(&self) {
+    invariants::check_well_formed("IntervalSeq", &self.intervals);
+  }
+}
+
+/// The default interval sequence is the empty one.
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Default for IntervalSeq<T> {
+  fn default() -> Self {
+    Self::empty()
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntoIterator for IntervalSeq<T> {
+  type Item = Interval<T>;
+  type IntoIter = std::vec::IntoIter<Interval<T>>;
+
+  /// Gets an into iterator of this interval sequence.
+  fn into_iter(mut self) -> Self::IntoIter {
+    self.sort();
+    self.intervals.into_iter()
+  }
+}
+
+/// Compute `total * numerator / denominator` for `numerator <= denominator` without overflowing.
+///
+/// The division is done first so that the intermediate values do not exceed `total`,
+/// and for integers the remainder of the division is scaled separately to keep the precision.
+fn scale<T: Clone + Num + FromPrimitive>(
+  total: &T,
+  numerator: usize,
+  denominator: usize,
+) -> Option<T> {
+  let numerator = T::from_usize(numerator)?;
+  let denominator = T::from_usize(denominator)?;
+  let quotient = total.clone() / denominator.clone();
+  let remainder = total.clone() - quotient.clone() * denominator.clone();
+  Some(quotient * numerator.clone() + remainder * numerator / denominator)
 }
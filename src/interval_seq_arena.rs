@@ -0,0 +1,88 @@
+use crate::{Interval, IntervalSeq};
+
+/// An arena of interval sequence buffers, which lets short-lived bulk computations reuse their storage
+/// instead of going back to the global allocator for each interval sequence.
+///
+/// `alloc` hands out an empty interval sequence backed by a released buffer if there is one,
+/// and `release` takes the buffer of an interval sequence back for the next `alloc`.
+/// The buffers themselves come from the global allocator, and the results of operations
+/// such as `coalesce` or `gap` allocate their own buffers, which can be released to the arena as well.
+#[derive(Debug, Clone)]
+pub struct IntervalSeqArena<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// released buffers, all of which are empty
+  buffers: Vec<Vec<Interval<T>>>,
+  /// the capacity of the buffers allocated when no released buffer is left
+  capacity: usize,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Default for IntervalSeqArena<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalSeqArena<T> {
+  /// Generate an arena without buffers.
+  ///
+  /// - return: `IntervalSeqArena`
+  pub fn new() -> Self {
+    Self {
+      buffers: vec![],
+      capacity: 0,
+    }
+  }
+
+  /// Generate an arena that allocates `count` buffers of `capacity` intervals up front.
+  ///
+  /// The buffers allocated later, when all of these are in use, have the same capacity.
+  ///
+  /// - params
+  ///     - count: the number of buffers
+  ///     - capacity: the number of intervals each buffer can hold without reallocating
+  /// - return: `IntervalSeqArena`
+  pub fn with_buffers(count: usize, capacity: usize) -> Self {
+    Self {
+      buffers: (0..count).map(|_| Vec::with_capacity(capacity)).collect(),
+      capacity,
+    }
+  }
+
+  /// Gets the number of released buffers that `alloc` can hand out without allocating.
+  pub fn available(&self) -> usize {
+    self.buffers.len()
+  }
+
+  /// Generate an empty interval sequence backed by a buffer of this arena.
+  ///
+  /// A released buffer is reused if there is one, and a new buffer is allocated otherwise.
+  ///
+  /// - return: an empty `IntervalSeq`
+  pub fn alloc(&mut self) -> IntervalSeq<T> {
+    let buffer = self
+      .buffers
+      .pop()
+      .unwrap_or_else(|| Vec::with_capacity(self.capacity));
+    IntervalSeq::from_vec(buffer)
+  }
+
+  /// Generate an interval sequence of the given intervals, backed by a buffer of this arena.
+  ///
+  /// - params
+  ///     - values: intervals
+  /// - return: `IntervalSeq`
+  pub fn alloc_from(&mut self, values: impl IntoIterator<Item = Interval<T>>) -> IntervalSeq<T> {
+    let mut buffer = self.alloc().into_vec();
+    buffer.extend(values);
+    IntervalSeq::from_vec(buffer)
+  }
+
+  /// Take the buffer of the given interval sequence back, dropping its intervals but keeping its allocation.
+  ///
+  /// - params
+  ///     - seq: an interval sequence that is no longer needed
+  pub fn release(&mut self, seq: IntervalSeq<T>) {
+    let mut buffer = seq.into_vec();
+    buffer.clear();
+    self.buffers.push(buffer);
+  }
+}
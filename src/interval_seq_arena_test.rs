@@ -0,0 +1,40 @@
+use crate::{Interval, IntervalSeqArena, LimitValue};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_alloc_and_release() {
+  let mut arena = IntervalSeqArena::with_buffers(1, 16);
+  assert_eq!(arena.available(), 1);
+  let mut seq = arena.alloc();
+  assert!(seq.is_empty());
+  assert_eq!(seq.capacity(), 16);
+  assert_eq!(arena.available(), 0);
+  seq.append(&closed(1, 3));
+  let buffer = seq.as_slice().as_ptr();
+  arena.release(seq);
+  assert_eq!(arena.available(), 1);
+
+  let seq = arena.alloc_from(vec![closed(5, 8), closed(10, 12)]);
+  assert_eq!(seq.as_slice(), &[closed(5, 8), closed(10, 12)]);
+  assert_eq!(seq.as_slice().as_ptr(), buffer);
+  let other = arena.alloc();
+  assert_eq!(other.capacity(), 16);
+  arena.release(seq);
+  arena.release(other);
+  assert_eq!(arena.available(), 2);
+}
+
+#[test]
+fn test02_release_results() {
+  let mut arena = IntervalSeqArena::new();
+  let seq = arena.alloc_from(vec![closed(1, 5), closed(3, 8)]);
+  let coalesced = seq.coalesce();
+  assert_eq!(coalesced.as_slice(), &[closed(1, 8)]);
+  arena.release(seq);
+  arena.release(coalesced);
+  assert_eq!(arena.available(), 2);
+  assert!(arena.alloc().is_empty());
+}
@@ -0,0 +1,125 @@
+use std::fmt::Debug;
+
+use crate::{Error, Interval, IntervalSeq};
+
+/// A builder of `IntervalSeq` that validates each interval against the configured policies.
+///
+/// All policies are disabled by default, in which case the builder behaves like `IntervalSeq::append`.
+#[derive(Debug, Clone)]
+pub struct IntervalSeqBuilder<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  intervals: Vec<Interval<T>>,
+  reject_overlapping: bool,
+  reject_empty: bool,
+  coalesce: bool,
+  require_sorted: bool,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Default for IntervalSeqBuilder<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalSeqBuilder<T> {
+  /// Generate a builder with all the policies disabled.
+  ///
+  /// - return: `IntervalSeqBuilder`
+  pub fn new() -> Self {
+    Self {
+      intervals: vec![],
+      reject_overlapping: false,
+      reject_empty: false,
+      coalesce: false,
+      require_sorted: false,
+    }
+  }
+
+  /// Set whether an interval overlapping an already appended interval is rejected.
+  ///
+  /// - params
+  ///     - value: `true` to reject overlapping intervals
+  /// - return: `IntervalSeqBuilder`
+  pub fn reject_overlapping(mut self, value: bool) -> Self {
+    self.reject_overlapping = value;
+    self
+  }
+
+  /// Set whether an empty interval is rejected.
+  ///
+  /// - params
+  ///     - value: `true` to reject empty intervals
+  /// - return: `IntervalSeqBuilder`
+  pub fn reject_empty(mut self, value: bool) -> Self {
+    self.reject_empty = value;
+    self
+  }
+
+  /// Set whether the built interval sequence is coalesced.
+  ///
+  /// - params
+  ///     - value: `true` to merge overlapping or adjacent intervals on build
+  /// - return: `IntervalSeqBuilder`
+  pub fn coalesce(mut self, value: bool) -> Self {
+    self.coalesce = value;
+    self
+  }
+
+  /// Set whether the intervals must be appended in ascending order of their lower limits.
+  ///
+  /// - params
+  ///     - value: `true` to reject an interval whose lower limit is below the preceding one
+  /// - return: `IntervalSeqBuilder`
+  pub fn require_sorted(mut self, value: bool) -> Self {
+    self.require_sorted = value;
+    self
+  }
+
+  /// Add an interval after validating it against the policies.
+  ///
+  /// A rejected interval is not added, and the builder can still be used.
+  ///
+  /// - params
+  ///     - value: an interval
+  /// - return: `Err(Error::EmptyIntervalError)`, `Err(Error::UnsortedError)` or `Err(Error::OverlapError)`
+  ///   if the interval violates the corresponding policy
+  pub fn append(&mut self, value: &Interval<T>) -> Result<(), Error> {
+    if self.reject_empty && value.is_empty() {
+      return Err(Error::EmptyIntervalError);
+    }
+    if self.require_sorted && self.intervals.last().is_some_and(|e| e.lower > value.lower) {
+      return Err(Error::UnsortedError);
+    }
+    if self.reject_overlapping && self.intervals.iter().any(|e| e.intersects(value)) {
+      return Err(Error::OverlapError);
+    }
+    self.intervals.push(value.clone());
+    Ok(())
+  }
+
+  /// Add all the given intervals, stopping at the first rejected one.
+  ///
+  /// - params
+  ///     - values: intervals
+  /// - return: the error of the first rejected interval
+  pub fn append_all<'a>(
+    &mut self,
+    values: impl IntoIterator<Item = &'a Interval<T>>,
+  ) -> Result<(), Error>
+  where
+    T: 'a,
+  {
+    values.into_iter().try_for_each(|e| self.append(e))
+  }
+
+  /// Generate the interval sequence from the appended intervals.
+  ///
+  /// - return: `IntervalSeq`
+  pub fn build(self) -> IntervalSeq<T> {
+    let result = IntervalSeq::from_vec(self.intervals);
+    if self.coalesce {
+      result.coalesce()
+    } else {
+      result
+    }
+  }
+}
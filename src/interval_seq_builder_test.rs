@@ -0,0 +1,67 @@
+use crate::{Error, Interval, IntervalSeq, LimitValue};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+fn empty() -> Interval<i32> {
+  Interval::open(LimitValue::Limit(1), LimitValue::Limit(1))
+}
+
+#[test]
+fn test01_no_policies() {
+  let mut builder = IntervalSeq::builder();
+  assert!(builder
+    .append_all(&[closed(5, 10), closed(0, 7), empty()])
+    .is_ok());
+  assert_eq!(builder.build().len(), 3);
+}
+
+#[test]
+fn test02_reject_overlapping() {
+  let mut builder = IntervalSeq::builder().reject_overlapping(true);
+  assert!(builder.append(&closed(0, 5)).is_ok());
+  assert!(matches!(
+    builder.append(&closed(5, 10)),
+    Err(Error::OverlapError)
+  ));
+  assert!(builder.append(&closed(6, 10)).is_ok());
+  assert_eq!(builder.build().len(), 2);
+}
+
+#[test]
+fn test03_reject_empty() {
+  let mut builder = IntervalSeq::builder().reject_empty(true);
+  assert!(matches!(
+    builder.append(&empty()),
+    Err(Error::EmptyIntervalError)
+  ));
+  assert!(builder.build().is_empty());
+}
+
+#[test]
+fn test04_require_sorted() {
+  let mut builder = IntervalSeq::builder().require_sorted(true);
+  assert!(matches!(
+    builder.append_all(&[closed(0, 5), closed(3, 4), closed(1, 2), closed(8, 9)]),
+    Err(Error::UnsortedError)
+  ));
+  let mut seq = builder.build();
+  assert_eq!(
+    seq.iter().collect::<Vec<_>>(),
+    vec![&closed(3, 4), &closed(0, 5)]
+  );
+}
+
+#[test]
+fn test05_coalesce() {
+  let mut builder = IntervalSeq::builder().coalesce(true);
+  assert!(builder
+    .append_all(&[closed(5, 10), closed(0, 7), closed(20, 30)])
+    .is_ok());
+  let mut seq = builder.build();
+  assert_eq!(
+    seq.iter().collect::<Vec<_>>(),
+    vec![&closed(0, 10), &closed(20, 30)]
+  );
+}
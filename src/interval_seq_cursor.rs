@@ -0,0 +1,71 @@
+use crate::{Interval, IntervalSeq, LimitValue};
+
+/// A cursor that walks the intervals of an interval sequence in ascending order of their upper limits.
+///
+/// Unlike an iterator, a cursor can look at the gap before the next interval and jump to a value with `seek`,
+/// so incremental consumers do not need to restart the iteration on every query.
+pub struct IntervalSeqCursor<'a, T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// intervals sorted by their upper limits, then by their lower limits
+  intervals: Vec<&'a Interval<T>>,
+  /// the index of the next interval
+  position: usize,
+}
+
+impl<'a, T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalSeqCursor<'a, T> {
+  pub(crate) fn new(seq: &'a IntervalSeq<T>) -> Self {
+    let mut intervals = seq.as_slice().iter().collect::<Vec<&'a Interval<T>>>();
+    intervals.sort_by(|a, b| a.upper.cmp(&b.upper).then_with(|| a.lower.cmp(&b.lower)));
+    Self {
+      intervals,
+      position: 0,
+    }
+  }
+
+  /// Gets the index of the next interval.
+  pub fn position(&self) -> usize {
+    self.position
+  }
+
+  /// Get the next interval without advancing this cursor.
+  ///
+  /// - return: the next interval, or `None` if the cursor reached the end
+  pub fn peek(&self) -> Option<&'a Interval<T>> {
+    self.intervals.get(self.position).copied()
+  }
+
+  /// Get the next interval and advance this cursor.
+  ///
+  /// - return: the next interval, or `None` if the cursor reached the end
+  pub fn next_interval(&mut self) -> Option<&'a Interval<T>> {
+    let next = self.peek();
+    if next.is_some() {
+      self.position += 1;
+    }
+    next
+  }
+
+  /// Get the gap between the previous interval and the next interval, without advancing this cursor.
+  ///
+  /// - return: the gap, or `None` if there is no previous or next interval, or if they intersect or abut
+  pub fn peek_gap(&self) -> Option<Interval<T>> {
+    let previous = self.intervals.get(self.position.checked_sub(1)?)?;
+    let next = self.peek()?;
+    let gap = previous.gap(next);
+    if gap.is_empty() {
+      None
+    } else {
+      Some(gap)
+    }
+  }
+
+  /// Move this cursor so that the next interval is the first interval that is not below the specified value `value`,
+  /// i.e. the first interval that includes `value` or lies above it.
+  ///
+  /// - params
+  ///     - value: a value to seek
+  /// - return: the next interval, or `None` if all the intervals are below `value`
+  pub fn seek(&mut self, value: &LimitValue<T>) -> Option<&'a Interval<T>> {
+    self.position = self.intervals.partition_point(|e| e.is_below(value));
+    self.peek()
+  }
+}
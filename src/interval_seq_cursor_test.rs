@@ -0,0 +1,39 @@
+use crate::{Interval, IntervalSeq, LimitValue};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_next_interval() {
+  let interval_sequence = IntervalSeq::new(vec![closed(20, 25), closed(1, 5), closed(10, 12)]);
+  let mut cursor = interval_sequence.cursor();
+  assert_eq!(cursor.peek_gap(), None);
+  assert_eq!(cursor.next_interval(), Some(&closed(1, 5)));
+  assert_eq!(
+    cursor.peek_gap(),
+    Some(Interval::open(LimitValue::Limit(5), LimitValue::Limit(10)))
+  );
+  assert_eq!(cursor.peek(), Some(&closed(10, 12)));
+  assert_eq!(cursor.next_interval(), Some(&closed(10, 12)));
+  assert_eq!(cursor.next_interval(), Some(&closed(20, 25)));
+  assert_eq!(cursor.peek_gap(), None);
+  assert_eq!(cursor.next_interval(), None);
+  assert_eq!(cursor.position(), 3);
+}
+
+#[test]
+fn test02_seek() {
+  let interval_sequence = IntervalSeq::new(vec![closed(20, 25), closed(1, 5), closed(10, 12)]);
+  let mut cursor = interval_sequence.cursor();
+  assert_eq!(cursor.seek(&LimitValue::Limit(11)), Some(&closed(10, 12)));
+  assert_eq!(cursor.position(), 1);
+  assert_eq!(cursor.seek(&LimitValue::Limit(13)), Some(&closed(20, 25)));
+  assert_eq!(
+    cursor.peek_gap(),
+    Some(Interval::open(LimitValue::Limit(12), LimitValue::Limit(20)))
+  );
+  assert_eq!(cursor.seek(&LimitValue::Limit(0)), Some(&closed(1, 5)));
+  assert_eq!(cursor.seek(&LimitValue::Limit(26)), None);
+  assert_eq!(cursor.next_interval(), None);
+}
@@ -1,7 +1,9 @@
+#![allow(non_upper_case_globals)]
+
 use once_cell::sync::Lazy;
 
-use crate::{Interval, LimitValue};
-use crate::interval_seq::IntervalSeq;
+use crate::{EmptyPolicy, Error, Interval, IntervalLimit, LimitValue, SortSpec};
+use crate::interval_seq::{IntervalSeq, Ordered, TimelineSegment};
 
 static c5_10c: Lazy<Interval<i32>> =
   Lazy::new(|| Interval::closed(LimitValue::Limit(5), LimitValue::Limit(10)));
@@ -140,3 +142,847 @@ fn test06_extent() {
   let interval_sequence3 = IntervalSeq::new(values);
   assert_eq!(interval_sequence3.extent(), *all);
 }
+
+#[test]
+fn test07_coverage_of() {
+  let c0_100o = Interval::over(LimitValue::Limit(0), true, LimitValue::Limit(100), false);
+  let mut interval_sequence = IntervalSeq::empty();
+  assert_eq!(interval_sequence.coverage_of(&c0_100o), Some(0.0));
+
+  interval_sequence.append(&Interval::closed(
+    LimitValue::Limit(10),
+    LimitValue::Limit(30),
+  ));
+  interval_sequence.append(&Interval::closed(
+    LimitValue::Limit(20),
+    LimitValue::Limit(40),
+  ));
+  interval_sequence.append(&Interval::closed(
+    LimitValue::Limit(90),
+    LimitValue::Limit(120),
+  ));
+  assert_eq!(interval_sequence.coverage_of(&c0_100o), Some(0.4));
+
+  interval_sequence.append(&all);
+  assert_eq!(interval_sequence.coverage_of(&c0_100o), Some(1.0));
+
+  assert_eq!(interval_sequence.coverage_of(&_o18), None);
+  assert_eq!(interval_sequence.coverage_of(&c20_20c), None);
+}
+
+#[test]
+fn test08_clip() {
+  let mut interval_sequence = IntervalSeq::empty();
+  interval_sequence.append(&c5_10c);
+  interval_sequence.append(&o10_12c);
+  interval_sequence.append(&c20_25c);
+  interval_sequence.append(&o30_35o);
+  interval_sequence.append(&_o18);
+  let c11_22c = Interval::closed(LimitValue::Limit(11), LimitValue::Limit(22));
+  let mut clipped = interval_sequence.clip(&c11_22c);
+  assert_eq!(clipped.len(), 3);
+  let mut iter = clipped.iter();
+  assert_eq!(
+    iter.next().unwrap(),
+    &Interval::closed(LimitValue::Limit(11), LimitValue::Limit(12))
+  );
+  assert_eq!(
+    iter.next().unwrap(),
+    &Interval::over(LimitValue::Limit(11), true, LimitValue::Limit(18), false)
+  );
+  assert_eq!(
+    iter.next().unwrap(),
+    &Interval::closed(LimitValue::Limit(20), LimitValue::Limit(22))
+  );
+  assert!(iter.next().is_none());
+}
+
+#[test]
+fn test09_coalesce() {
+  let mut interval_sequence = IntervalSeq::empty();
+  interval_sequence.append(&c20_25c);
+  interval_sequence.append(&o10_12c);
+  interval_sequence.append(&c5_10c);
+  interval_sequence.append(&o11_20c);
+  interval_sequence.append(&o30_35o);
+  interval_sequence.append(&Interval::open(LimitValue::Limit(1), LimitValue::Limit(1)));
+  let coalesced = interval_sequence.coalesce();
+  assert_eq!(coalesced.len(), 2);
+  assert_eq!(
+    coalesced.get(0).unwrap(),
+    &Interval::closed(LimitValue::Limit(5), LimitValue::Limit(25))
+  );
+  assert_eq!(coalesced.get(1).unwrap(), &*o30_35o);
+}
+
+#[test]
+fn test10_set_eq() {
+  let interval_sequence1 = IntervalSeq::new(vec![c5_10c.clone(), o10_12c.clone()]);
+  let interval_sequence2 = IntervalSeq::new(vec![
+    Interval::closed(LimitValue::Limit(8), LimitValue::Limit(12)),
+    Interval::over(LimitValue::Limit(5), true, LimitValue::Limit(8), false),
+  ]);
+  assert!(interval_sequence1.set_eq(&interval_sequence2));
+  assert!(interval_sequence2.set_eq(&interval_sequence1));
+
+  let interval_sequence3 = IntervalSeq::new(vec![c5_10c.clone(), o11_12c.clone()]);
+  assert!(!interval_sequence1.set_eq(&interval_sequence3));
+
+  let interval_sequence4 = IntervalSeq::new(vec![
+    Interval::over(LimitValue::Limit(5), true, LimitValue::Limit(10), false),
+    o10_12c.clone(),
+  ]);
+  assert!(!interval_sequence1.set_eq(&interval_sequence4));
+  assert!(
+    IntervalSeq::empty().set_eq(&IntervalSeq::new(vec![Interval::open(
+      LimitValue::Limit(1),
+      LimitValue::Limit(1)
+    )]))
+  );
+}
+
+#[test]
+fn test11_timeline() {
+  let mut interval_sequence = IntervalSeq::empty();
+  interval_sequence.append(&o10_12c);
+  interval_sequence.append(&c5_10c);
+  interval_sequence.append(&c20_25c);
+  let c0_22c = Interval::closed(LimitValue::Limit(0), LimitValue::Limit(22));
+  let timeline = interval_sequence
+    .timeline(&c0_22c)
+    .collect::<Vec<TimelineSegment<i32>>>();
+  assert_eq!(
+    timeline,
+    vec![
+      TimelineSegment::Uncovered(Interval::over(
+        LimitValue::Limit(0),
+        true,
+        LimitValue::Limit(5),
+        false
+      )),
+      TimelineSegment::Covered(Interval::closed(
+        LimitValue::Limit(5),
+        LimitValue::Limit(12)
+      )),
+      TimelineSegment::Uncovered(o12_20o.clone()),
+      TimelineSegment::Covered(Interval::closed(
+        LimitValue::Limit(20),
+        LimitValue::Limit(22)
+      )),
+    ]
+  );
+  assert!(interval_sequence
+    .timeline(&o30_35o)
+    .eq(vec![TimelineSegment::Uncovered(o30_35o.clone())]));
+}
+
+#[test]
+fn test12_clusters() {
+  let mut interval_sequence = IntervalSeq::empty();
+  interval_sequence.append(&o25_30c);
+  interval_sequence.append(&c5_10c);
+  interval_sequence.append(&o11_20c);
+  interval_sequence.append(&c20_25c);
+  interval_sequence.append(&o10_12c);
+  let clusters = interval_sequence.clusters();
+  assert_eq!(clusters.len(), 3);
+  assert_eq!(clusters[0].as_hull(), &*c5_10c);
+  assert_eq!(clusters[0].as_members().len(), 1);
+  assert_eq!(
+    clusters[1].as_hull(),
+    &Interval::over(LimitValue::Limit(10), false, LimitValue::Limit(25), true)
+  );
+  assert_eq!(clusters[1].as_members().len(), 3);
+  assert_eq!(clusters[2].as_hull(), &*o25_30c);
+  assert!(IntervalSeq::<i32>::empty().clusters().is_empty());
+}
+
+#[cfg(feature = "petgraph")]
+#[test]
+fn test13_overlap_graph() {
+  let interval_sequence = IntervalSeq::new(vec![
+    c5_10c.clone(),
+    o10_12c.clone(),
+    o11_20c.clone(),
+    c20_25c.clone(),
+    _o18.clone(),
+  ]);
+  let graph = interval_sequence.overlap_graph();
+  assert_eq!(graph.node_count(), 5);
+  assert_eq!(graph.edge_count(), 5);
+  let connected = |a: usize, b: usize| {
+    graph
+      .find_edge(
+        petgraph::graph::NodeIndex::new(a),
+        petgraph::graph::NodeIndex::new(b),
+      )
+      .is_some()
+  };
+  assert!(!connected(0, 1));
+  assert!(connected(1, 2));
+  assert!(connected(2, 3));
+  assert!(connected(4, 0));
+  assert!(connected(4, 1));
+  assert!(connected(4, 2));
+  assert!(!connected(4, 3));
+}
+
+#[test]
+fn test14_merge_all() {
+  let interval_sequences = || {
+    vec![
+      IntervalSeq::new(vec![c5_10c.clone(), c20_25c.clone()]),
+      IntervalSeq::new(vec![o11_20c.clone(), o30_35o.clone()]),
+      IntervalSeq::empty(),
+      IntervalSeq::new(vec![o10_12c.clone()]),
+    ]
+  };
+
+  let merged = IntervalSeq::merge_all(interval_sequences());
+  assert_eq!(merged.len(), 5);
+  assert_eq!(merged.get(0).unwrap(), &*c5_10c);
+  assert_eq!(merged.get(1).unwrap(), &*o10_12c);
+  assert_eq!(merged.get(2).unwrap(), &*o11_20c);
+  assert_eq!(merged.get(3).unwrap(), &*c20_25c);
+  assert_eq!(merged.get(4).unwrap(), &*o30_35o);
+
+  let merged = IntervalSeq::merge_all_coalesced(interval_sequences());
+  assert_eq!(merged.len(), 2);
+  assert_eq!(
+    merged.get(0).unwrap(),
+    &Interval::closed(LimitValue::Limit(5), LimitValue::Limit(25))
+  );
+  assert_eq!(merged.get(1).unwrap(), &*o30_35o);
+}
+
+#[test]
+fn test15_reuse_buffer() {
+  let mut interval_sequence = IntervalSeq::with_capacity(8);
+  assert!(interval_sequence.capacity() >= 8);
+  interval_sequence.append(&c5_10c);
+  interval_sequence.append(&c20_25c);
+  let mut buffer = interval_sequence.into_vec();
+  let ptr = buffer.as_ptr();
+  assert_eq!(buffer, vec![c5_10c.clone(), c20_25c.clone()]);
+
+  buffer.clear();
+  let mut interval_sequence = IntervalSeq::from_vec(buffer);
+  assert!(interval_sequence.is_empty());
+  interval_sequence.append(&o10_12c);
+  interval_sequence.reserve(4);
+  assert!(interval_sequence.capacity() >= 5);
+  let buffer = interval_sequence.into_vec();
+  assert_eq!(buffer, vec![o10_12c.clone()]);
+  assert_eq!(buffer.as_ptr(), ptr);
+}
+
+#[test]
+fn test16_iterate_backwards() {
+  let mut interval_sequence =
+    IntervalSeq::new(vec![c20_25c.clone(), c5_10c.clone(), o10_12c.clone()]);
+  let iter = interval_sequence.iter();
+  assert_eq!(iter.len(), 3);
+  let reversed = iter.rev().collect::<Vec<&Interval<i32>>>();
+  assert_eq!(reversed, vec![&*c20_25c, &*o10_12c, &*c5_10c]);
+
+  {
+    let mut iter = interval_sequence.iter();
+    assert_eq!(iter.next_back(), Some(&*c20_25c));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.rposition(|e| *e == *c5_10c), Some(0));
+  }
+
+  let mut into_iter = interval_sequence.into_iter();
+  assert_eq!(into_iter.len(), 3);
+  assert_eq!(into_iter.next_back(), Some(c20_25c.clone()));
+}
+
+#[test]
+fn test17_lanes() {
+  let interval_sequence = IntervalSeq::new(vec![
+    c5_10c.clone(),
+    o10_12c.clone(),
+    o11_20c.clone(),
+    c20_25c.clone(),
+    _o18.clone(),
+  ]);
+  assert_eq!(
+    interval_sequence.lanes(),
+    vec![
+      vec![_o18.clone()],
+      vec![c5_10c.clone(), o10_12c.clone(), c20_25c.clone()],
+      vec![o11_20c.clone()]
+    ]
+  );
+  assert!(IntervalSeq::<i32>::empty().lanes().is_empty());
+}
+
+#[test]
+fn test18_try_append() {
+  let really_empty = Interval::open(LimitValue::Limit(20), LimitValue::Limit(20));
+  let mut interval_sequence = IntervalSeq::empty();
+  assert!(interval_sequence.try_append(&c5_10c).is_ok());
+  assert!(matches!(
+    interval_sequence.try_append(&really_empty),
+    Err(Error::EmptyIntervalError)
+  ));
+  assert!(interval_sequence.append_non_empty(&c20_25c));
+  assert!(!interval_sequence.append_non_empty(&really_empty));
+  assert_eq!(interval_sequence.len(), 2);
+}
+
+#[test]
+fn test19_append_all_and_concat() {
+  let mut interval_sequence = IntervalSeq::new(vec![c20_25c.clone()]);
+  interval_sequence.append_all(vec![o10_12c.clone(), c5_10c.clone()]);
+  assert_eq!(interval_sequence.len(), 3);
+
+  let mut concatenated =
+    interval_sequence.concat(IntervalSeq::new(vec![o30_35o.clone(), o11_20c.clone()]));
+  assert_eq!(
+    concatenated.iter().collect::<Vec<_>>(),
+    vec![&*c5_10c, &*o10_12c, &*o11_20c, &*c20_25c, &*o30_35o]
+  );
+}
+
+#[test]
+fn test20_remove_and_drain() {
+  let mut interval_sequence = IntervalSeq::new(vec![
+    c20_25c.clone(),
+    c5_10c.clone(),
+    o30_35o.clone(),
+    o10_12c.clone(),
+    o11_20c.clone(),
+  ]);
+  assert_eq!(
+    interval_sequence.get(0).cloned(),
+    Some(interval_sequence.remove(0))
+  );
+  assert!(interval_sequence.remove_interval(&o11_20c));
+  assert!(!interval_sequence.remove_interval(&o11_20c));
+  assert_eq!(interval_sequence.pop(), Some(o10_12c.clone()));
+  assert_eq!(interval_sequence.len(), 2);
+
+  interval_sequence.append_all(vec![c20_25c.clone(), o10_12c.clone()]);
+  assert_eq!(
+    interval_sequence.drain(1..3).collect::<Vec<_>>(),
+    vec![o30_35o.clone(), c20_25c.clone()]
+  );
+  interval_sequence.truncate(1);
+  assert_eq!(interval_sequence.iter().collect::<Vec<_>>(), vec![&*c5_10c]);
+  interval_sequence.clear();
+  assert!(interval_sequence.is_empty());
+  assert_eq!(interval_sequence.pop(), None);
+}
+
+#[test]
+fn test21_iter_rev() {
+  let mut interval_sequence =
+    IntervalSeq::new(vec![c5_10c.clone(), o30_35o.clone(), c20_25c.clone()]);
+  assert_eq!(
+    interval_sequence.iter_rev().collect::<Vec<_>>(),
+    vec![&*o30_35o, &*c20_25c, &*c5_10c]
+  );
+  assert_eq!(interval_sequence.iter_rev().len(), 3);
+}
+
+#[test]
+fn test22_partition_point() {
+  let interval_sequence = IntervalSeq::new(vec![c5_10c.clone(), c20_25c.clone(), o30_35o.clone()]);
+  assert_eq!(
+    interval_sequence.partition_point(|e| e.as_upper_limit() <= &LimitValue::Limit(25)),
+    2
+  );
+  assert_eq!(interval_sequence.partition_point(|_| false), 0);
+}
+
+#[test]
+fn test23_coalesce_with_tolerance() {
+  let interval_sequence = IntervalSeq::new(vec![
+    c5_10c.clone(),
+    o11_20c.clone(),
+    o25_30c.clone(),
+    o30_35o.clone(),
+  ]);
+  let mut coalesced = interval_sequence.coalesce_with_tolerance(2);
+  assert_eq!(
+    coalesced.iter().collect::<Vec<_>>(),
+    vec![
+      &Interval::closed(LimitValue::Limit(5), LimitValue::Limit(20)),
+      &Interval::over(LimitValue::Limit(25), false, LimitValue::Limit(35), false),
+    ]
+  );
+  let mut coalesced = interval_sequence.coalesce_with_tolerance(6);
+  assert_eq!(
+    coalesced.iter().collect::<Vec<_>>(),
+    vec![&Interval::over(
+      LimitValue::Limit(5),
+      true,
+      LimitValue::Limit(35),
+      false
+    )]
+  );
+  assert_eq!(interval_sequence.coalesce_with_tolerance(0).len(), 3);
+}
+
+#[test]
+fn test24_overlap_matrix() {
+  let requests = IntervalSeq::new(vec![c5_10c.clone(), o11_20c.clone()]);
+  let resources = IntervalSeq::new(vec![o10_12c.clone(), o30_35o.clone(), _o18.clone()]);
+
+  let matrix = requests.overlap_matrix(&resources);
+  assert_eq!((matrix.rows(), matrix.columns()), (2, 3));
+  assert_eq!(matrix.row(0), Some(&[false, false, true][..]));
+  assert_eq!(matrix.row(1), Some(&[true, false, true][..]));
+  assert_eq!(matrix.get(2, 0), None);
+
+  let lengths = requests.overlap_length_matrix(&resources);
+  assert_eq!(lengths.row(0), Some(&[Some(0), Some(0), Some(5)][..]));
+  assert_eq!(lengths.row(1), Some(&[Some(1), Some(0), Some(7)][..]));
+
+  let unbounded = IntervalSeq::new(vec![all.clone()]).overlap_length_matrix(&resources);
+  assert_eq!(unbounded.get(0, 2), Some(&None));
+}
+
+#[test]
+fn test25_sort_spec() {
+  let c5_20c = Interval::closed(LimitValue::Limit(5), LimitValue::Limit(20));
+  let mut interval_sequence = IntervalSeq::new(vec![
+    o11_20c.clone(),
+    c5_10c.clone(),
+    o10_12c.clone(),
+    c5_20c.clone(),
+    _o18.clone(),
+  ])
+  .with_sort_spec(SortSpec::new().by_lower().by_length().descending());
+  assert_eq!(
+    interval_sequence.iter().collect::<Vec<_>>(),
+    vec![&*_o18, &c5_20c, &*c5_10c, &*o10_12c, &*o11_20c]
+  );
+
+  interval_sequence.set_sort_spec(
+    SortSpec::new()
+      .by_key(|e| e.has_lower_limit())
+      .then(SortSpec::new().by_upper().descending()),
+  );
+  assert_eq!(
+    interval_sequence.into_iter().collect::<Vec<_>>(),
+    vec![
+      _o18.clone(),
+      c5_20c,
+      o11_20c.clone(),
+      o10_12c.clone(),
+      c5_10c.clone()
+    ]
+  );
+}
+
+#[test]
+fn test26_boundaries() {
+  let interval_sequence = IntervalSeq::new(vec![
+    o10_12c.clone(),
+    c5_10c.clone(),
+    o12_20o.clone(),
+    _o18.clone(),
+    Interval::open(LimitValue::Limit(40), LimitValue::Limit(40)),
+  ]);
+  assert_eq!(
+    interval_sequence.boundaries(),
+    vec![
+      IntervalLimit::lower(false, LimitValue::Limitless),
+      IntervalLimit::lower(true, LimitValue::Limit(5)),
+      IntervalLimit::lower(false, LimitValue::Limit(10)),
+      IntervalLimit::upper(true, LimitValue::Limit(10)),
+      IntervalLimit::lower(false, LimitValue::Limit(12)),
+      IntervalLimit::upper(true, LimitValue::Limit(12)),
+      IntervalLimit::upper(false, LimitValue::Limit(18)),
+      IntervalLimit::upper(false, LimitValue::Limit(20)),
+    ]
+  );
+  assert_eq!(interval_sequence.boundary_values(), vec![5, 10, 12, 18, 20]);
+}
+
+#[test]
+fn test27_default() {
+  #[derive(Default)]
+  struct Config {
+    allowed: IntervalSeq<i32>,
+    window: Interval<i32>,
+  }
+  let config = Config::default();
+  assert!(config.allowed.is_empty());
+  assert_eq!(
+    config.window,
+    Interval::open(LimitValue::Limitless, LimitValue::Limitless)
+  );
+}
+
+#[test]
+fn test28_append_merged() {
+  let mut seq = IntervalSeq::empty();
+  seq.append_merged(&c5_10c);
+  seq.append_merged(&o30_35o);
+  seq.append_merged(&o12_20o);
+  assert_eq!(seq.len(), 3);
+  seq.append_merged(&o10_12c);
+  seq.append_merged(&Interval::open(LimitValue::Limit(0), LimitValue::Limit(0)));
+  assert_eq!(
+    seq.iter().cloned().collect::<Vec<_>>(),
+    vec![
+      Interval::over(LimitValue::Limit(5), true, LimitValue::Limit(20), false),
+      o30_35o.clone(),
+    ]
+  );
+  seq.append_merged(&c20_25c);
+  seq.append_merged(&o25_30c);
+  assert_eq!(
+    seq.iter().cloned().collect::<Vec<_>>(),
+    vec![Interval::over(
+      LimitValue::Limit(5),
+      true,
+      LimitValue::Limit(35),
+      false
+    )]
+  );
+}
+
+#[test]
+fn test29_empty_policy() {
+  let o0_0o = Interval::open(LimitValue::Limit(0), LimitValue::Limit(0));
+  let mut legacy = IntervalSeq::new(vec![c5_10c.clone(), o10_12c.clone(), o30_35o.clone()]);
+  legacy.append(&o0_0o);
+  assert_eq!(legacy.len(), 4);
+  assert_eq!(legacy.empty_policy(), None);
+
+  let mut dropping = IntervalSeq::new(vec![c5_10c.clone(), o0_0o.clone(), o10_12c.clone()])
+    .with_empty_policy(EmptyPolicy::DropEmpties);
+  assert_eq!(dropping.len(), 2);
+  dropping.append(&o0_0o);
+  dropping.append_all(vec![o0_0o.clone(), o30_35o.clone()]);
+  assert_eq!(dropping.len(), 3);
+  assert_eq!(dropping.gap().len(), 1);
+  assert_eq!(
+    dropping.gap().empty_policy(),
+    Some(EmptyPolicy::DropEmpties)
+  );
+
+  let keeping = IntervalSeq::new(vec![c5_10c.clone(), o10_12c.clone(), o30_35o.clone()])
+    .with_empty_policy(EmptyPolicy::KeepEmpties);
+  let mut gap = keeping.gap();
+  assert_eq!(gap.len(), 2);
+  assert_eq!(gap.iter().filter(|e| e.is_empty()).count(), 1);
+  assert_eq!(keeping.intersections().len(), 2);
+  assert_eq!(keeping.clip(&c5_10c).len(), 3);
+  assert_eq!(legacy.clip(&c5_10c).len(), 1);
+}
+
+#[test]
+fn test30_ordered_compare() {
+  use std::cmp::Ordering;
+  let o0_0o = Interval::open(LimitValue::Limit(0), LimitValue::Limit(0));
+  let upper_lower = Ordered::UpperLower {
+    inverse_lower: true,
+    inverse_upper: false,
+  };
+  assert_eq!(upper_lower.compare(&c5_10c, &o10_12c), Ordering::Less);
+  assert_eq!(upper_lower.compare(&c5_10c, &*_o18), Ordering::Less);
+  assert_eq!(upper_lower.compare(&o11_20c, &c20_20c), Ordering::Greater);
+  assert_eq!(upper_lower.compare(&o0_0o, &c5_10c), Ordering::Less);
+  let lower_upper = Ordered::LowerUpper {
+    inverse_lower: false,
+    inverse_upper: true,
+  };
+  assert_eq!(lower_upper.compare(&c5_10c, &o10_12c), Ordering::Less);
+  assert_eq!(lower_upper.compare(&o10_12c, &o11_20c), Ordering::Less);
+  assert_eq!(lower_upper.compare(&o11_20c, &o11_12c), Ordering::Less);
+  assert_eq!(lower_upper.compare(&o0_0o, &c5_10c), Ordering::Greater);
+  assert_eq!(lower_upper.compare(&o0_0o, &o0_0o), Ordering::Equal);
+}
+
+#[test]
+fn test32_chunks_by_length() {
+  let closed = |lower, upper| Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper));
+  let half_open = |lower, upper| {
+    Interval::over(
+      LimitValue::Limit(lower),
+      true,
+      LimitValue::Limit(upper),
+      false,
+    )
+  };
+  let seq = IntervalSeq::new(vec![closed(0, 10), closed(5, 20), closed(30, 40)]);
+  let mut chunks = seq.chunks_by_length(3).unwrap();
+  assert_eq!(chunks.len(), 3);
+  let actual = chunks
+    .iter_mut()
+    .map(|e| e.iter().cloned().collect::<Vec<_>>())
+    .collect::<Vec<_>>();
+  assert_eq!(
+    actual,
+    vec![
+      vec![half_open(0, 10)],
+      vec![closed(10, 20)],
+      vec![closed(30, 40)],
+    ]
+  );
+
+  let mut chunks = IntervalSeq::new(vec![closed(0, 10)])
+    .chunks_by_length(1)
+    .unwrap();
+  assert_eq!(
+    chunks[0].iter().cloned().collect::<Vec<_>>(),
+    vec![closed(0, 10)]
+  );
+  assert!(
+    IntervalSeq::new(vec![Interval::and_more(LimitValue::Limit(0))])
+      .chunks_by_length(2)
+      .is_none()
+  );
+}
+
+#[test]
+fn test33_sample_points() {
+  let closed = |lower, upper| Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper));
+  let seq = IntervalSeq::new(vec![closed(0, 10), closed(100, 110)]);
+  assert_eq!(seq.sample_points(4), Some(vec![2, 7, 102, 107]));
+  assert_eq!(seq.sample_points(1), Some(vec![100]));
+  assert_eq!(seq.sample_points(0), Some(vec![]));
+  assert_eq!(IntervalSeq::<i32>::empty().sample_points(3), Some(vec![]));
+  assert_eq!(
+    IntervalSeq::new(vec![Interval::and_more(LimitValue::Limit(0))]).sample_points(2),
+    None
+  );
+  let open = IntervalSeq::new(vec![Interval::open(
+    LimitValue::Limit(0),
+    LimitValue::Limit(10),
+  )]);
+  assert_eq!(open.sample_points(1), Some(vec![5]));
+  assert_eq!(open.sample_points(5).map(|e| e.len()), Some(5));
+  assert_eq!(open.sample_points(10), None);
+
+  let near_max = IntervalSeq::new(vec![Interval::closed(
+    LimitValue::Limit(0i64),
+    LimitValue::Limit(i64::MAX),
+  )]);
+  let points = near_max.sample_points(3).unwrap();
+  assert_eq!(points.len(), 3);
+  assert_eq!(
+    points,
+    (0..3)
+      .map(|k| (i64::MAX as i128 * (2 * k + 1) / 6) as i64)
+      .collect::<Vec<_>>()
+  );
+}
+
+#[test]
+fn test34_group_by() {
+  let closed = |lower, upper| Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper));
+  let seq = IntervalSeq::new(vec![
+    closed(1, 3),
+    closed(12, 15),
+    closed(5, 8),
+    closed(10, 11),
+  ]);
+  let mut groups = seq.group_by(|e| *e.lower_value().unwrap() / 10);
+  assert_eq!(groups.len(), 2);
+  let mut values = |key| {
+    groups
+      .get_mut(&key)
+      .unwrap()
+      .iter()
+      .cloned()
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(values(0), vec![closed(1, 3), closed(5, 8)]);
+  assert_eq!(values(1), vec![closed(10, 11), closed(12, 15)]);
+
+  let seq = IntervalSeq::<i32>::empty().with_empty_policy(EmptyPolicy::DropEmpties);
+  assert!(seq.group_by(|_| ()).is_empty());
+  let seq = IntervalSeq::new(vec![closed(1, 3)]).with_empty_policy(EmptyPolicy::DropEmpties);
+  assert_eq!(
+    seq.group_by(|_| ())[&()].empty_policy(),
+    Some(EmptyPolicy::DropEmpties)
+  );
+}
+
+#[test]
+fn test39_as_slice() {
+  let mut interval_sequence = IntervalSeq::new(vec![c20_25c.clone(), c5_10c.clone()]);
+  assert_eq!(
+    interval_sequence.as_slice(),
+    &[c20_25c.clone(), c5_10c.clone()]
+  );
+  assert_eq!(interval_sequence.iter().len(), 2);
+  assert_eq!(
+    interval_sequence.as_slice(),
+    &[c5_10c.clone(), c20_25c.clone()]
+  );
+  assert!(IntervalSeq::<i32>::empty().as_slice().is_empty());
+}
+
+#[test]
+fn test40_extent_with_empty() {
+  let o30_30o = Interval::open(LimitValue::Limit(30), LimitValue::Limit(30));
+  let interval_sequence = IntervalSeq::new(vec![c5_10c.clone(), o30_30o.clone()]);
+  assert_eq!(
+    interval_sequence.extent(),
+    Interval::over(LimitValue::Limit(5), true, LimitValue::Limit(30), false)
+  );
+  let interval_sequence = IntervalSeq::new(vec![o30_30o.clone()]);
+  assert_eq!(interval_sequence.extent(), o30_30o);
+}
+
+#[test]
+fn test41_chunks_by_length_near_max() {
+  let seq = IntervalSeq::new(vec![Interval::closed(
+    LimitValue::Limit(0i64),
+    LimitValue::Limit(i64::MAX),
+  )]);
+  let mut chunks = seq.chunks_by_length(4).unwrap();
+  let boundary = |k: i128| (i64::MAX as i128 * k / 4) as i64;
+  let actual = chunks
+    .iter_mut()
+    .map(|e| e.iter().cloned().collect::<Vec<_>>())
+    .collect::<Vec<_>>();
+  let half_open = |lower, upper| {
+    Interval::over(
+      LimitValue::Limit(lower),
+      true,
+      LimitValue::Limit(upper),
+      false,
+    )
+  };
+  assert_eq!(
+    actual,
+    vec![
+      vec![half_open(0, boundary(1))],
+      vec![half_open(boundary(1), boundary(2))],
+      vec![half_open(boundary(2), boundary(3))],
+      vec![Interval::closed(
+        LimitValue::Limit(boundary(3)),
+        LimitValue::Limit(i64::MAX)
+      )],
+    ]
+  );
+}
+
+#[test]
+fn test42_find_by_lower() {
+  let lower_upper = Ordered::LowerUpper {
+    inverse_lower: false,
+    inverse_upper: false,
+  };
+  let mut values = vec![
+    c20_25c.clone(),
+    _o18.clone(),
+    o10_12c.clone(),
+    c5_10c.clone(),
+    o11_20c.clone(),
+    o12_20o.clone(),
+  ];
+  values.sort_by(|a, b| lower_upper.compare(a, b));
+  let interval_sequence = IntervalSeq::new(values);
+  assert_eq!(
+    interval_sequence.find_by_lower(&LimitValue::Limitless),
+    Some(&*_o18)
+  );
+  assert_eq!(
+    interval_sequence.find_by_lower(&LimitValue::Limit(10)),
+    Some(&*o10_12c)
+  );
+  assert_eq!(
+    interval_sequence.find_by_lower(&LimitValue::Limit(20)),
+    Some(&*c20_25c)
+  );
+  assert_eq!(
+    interval_sequence.find_by_lower(&LimitValue::Limit(13)),
+    None
+  );
+  assert_eq!(
+    interval_sequence.find_by_lower(&LimitValue::Limit(30)),
+    None
+  );
+  assert_eq!(
+    IntervalSeq::<i32>::empty().find_by_lower(&LimitValue::Limit(0)),
+    None
+  );
+}
+
+#[test]
+fn test43_ordered_ties() {
+  use std::cmp::Ordering;
+  let closed = |lower, upper| Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper));
+  let ordered = |lower_upper: bool, inverse_lower: bool, inverse_upper: bool| {
+    if lower_upper {
+      Ordered::LowerUpper {
+        inverse_lower,
+        inverse_upper,
+      }
+    } else {
+      Ordered::UpperLower {
+        inverse_lower,
+        inverse_upper,
+      }
+    }
+  };
+
+  // equal lower limits and different upper limits
+  let (short, long) = (closed(5, 8), closed(5, 10));
+  assert_eq!(
+    ordered(true, false, false).compare(&short, &long),
+    Ordering::Less
+  );
+  assert_eq!(
+    ordered(true, false, true).compare(&short, &long),
+    Ordering::Greater
+  );
+  assert_eq!(
+    ordered(false, false, false).compare(&short, &long),
+    Ordering::Less
+  );
+  assert_eq!(
+    ordered(false, false, true).compare(&short, &long),
+    Ordering::Greater
+  );
+
+  // different lower limits and equal upper limits
+  let (early, late) = (closed(3, 10), closed(5, 10));
+  assert_eq!(
+    ordered(true, false, false).compare(&early, &late),
+    Ordering::Less
+  );
+  assert_eq!(
+    ordered(true, true, false).compare(&early, &late),
+    Ordering::Greater
+  );
+  assert_eq!(
+    ordered(false, false, false).compare(&early, &late),
+    Ordering::Less
+  );
+  assert_eq!(
+    ordered(false, true, false).compare(&early, &late),
+    Ordering::Greater
+  );
+
+  // the first key decides when both limits differ
+  let (wide, narrow) = (closed(1, 20), closed(5, 10));
+  assert_eq!(
+    ordered(true, false, false).compare(&wide, &narrow),
+    Ordering::Less
+  );
+  assert_eq!(
+    ordered(false, false, false).compare(&wide, &narrow),
+    Ordering::Greater
+  );
+
+  let lower_upper = ordered(true, false, false);
+  let mut values = vec![
+    long.clone(),
+    wide.clone(),
+    late.clone(),
+    short.clone(),
+    early.clone(),
+  ];
+  values.sort_by(|a, b| lower_upper.compare(a, b));
+  assert_eq!(values, vec![wide, early, short, long, late]);
+}
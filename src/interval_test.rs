@@ -1,6 +1,10 @@
+#![allow(non_upper_case_globals)]
+
 use once_cell::sync::Lazy;
 
-use crate::{Interval, LimitValue};
+use std::ops::Bound;
+
+use crate::{Error, Interval, IntervalLimit, IntervalSeq, LimitValue};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
 
@@ -219,6 +223,11 @@ fn test12_intersection() {
   assert_eq!(c5_10c.intersect(&c5_10c), *c5_10c);
   assert_eq!(c1_10c.intersect(&o10_12c), *empty);
   assert_eq!(o10_12c.intersect(&c1_10c), *empty);
+  assert_eq!(
+    o9_.intersect(&Interval::and_more(LimitValue::Limit(3))),
+    *o9_
+  );
+  assert!(_2o.intersect(&o9_).is_empty());
 }
 
 #[test]
@@ -440,3 +449,769 @@ fn test32_relative_complement_overlap_left_open() {
   assert_eq!(complement.len(), 1);
   assert_eq!(complement[0], o5_7c);
 }
+
+#[test]
+fn test33_length() {
+  assert_eq!(c1_10c.length(), Some(9));
+  assert_eq!(o10_12c.length(), Some(2));
+  assert_eq!(c1_1c.length(), Some(0));
+  assert_eq!(_2o.length::<i32>(), None);
+  assert_eq!(all.length::<i32>(), None);
+}
+
+#[test]
+fn test34_equals_to_std_ranges() {
+  assert_eq!(*c1_10c, 1..=10);
+  assert_eq!(1..=10, *c1_10c);
+  assert_ne!(*c1_10c, 1..10);
+  assert_eq!(
+    Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(10), false),
+    1..10
+  );
+  assert_eq!(*c1_1c, 1..=1);
+  assert_eq!(*empty, 3..3);
+  assert_ne!(*c1_1c, 1..1);
+  let (start, end) = (5, 4);
+  assert_eq!(*empty, start..=end);
+  assert_eq!(Interval::and_more(LimitValue::Limit(9)), 9..);
+  assert_ne!(*o9_, ..);
+  assert_ne!(*o9_, 9..);
+  assert_eq!(*_2o, ..2);
+  assert_eq!(Interval::up_to(LimitValue::Limit(2)), ..=2);
+  assert_eq!(*all, ..);
+  assert_eq!(.., *all);
+}
+
+#[test]
+fn test35_into_parts() {
+  assert_eq!(
+    o10_12c.clone().into_parts(),
+    (LimitValue::Limit(10), false, LimitValue::Limit(12), true)
+  );
+  assert_eq!(
+    _2o.clone().into_parts(),
+    (LimitValue::Limitless, false, LimitValue::Limit(2), false)
+  );
+  let (lower, upper) = o10_12c.clone().into_limits();
+  assert_eq!(lower, IntervalLimit::lower(false, LimitValue::Limit(10)));
+  assert_eq!(upper, IntervalLimit::upper(true, LimitValue::Limit(12)));
+  assert_eq!(Interval::new(lower, upper), *o10_12c);
+}
+
+#[test]
+fn test36_limit_values() {
+  assert_eq!(o10_12c.lower_value(), Some(&10));
+  assert_eq!(o10_12c.upper_value(), Some(&12));
+  assert_eq!(_2o.lower_value(), None);
+  assert_eq!(_2o.upper_value(), Some(&2));
+  assert_eq!(o9_.upper_value(), None);
+}
+
+#[test]
+fn test37_center_and_radius() {
+  let interval = Interval::from_center(Decimal::new(100, 1), Decimal::new(5, 1));
+  assert_eq!(
+    interval,
+    Interval::closed(
+      LimitValue::Limit(Decimal::new(95, 1)),
+      LimitValue::Limit(Decimal::new(105, 1))
+    )
+  );
+  assert_eq!(
+    interval.center_and_radius(),
+    Some((Decimal::new(100, 1), Decimal::new(5, 1)))
+  );
+  assert_eq!(Interval::from_center(3, 0), Interval::single(3));
+  assert_eq!(c4_6c.center_and_radius(), Some((5, 1)));
+  assert_eq!(o9_.center_and_radius(), None);
+}
+
+#[test]
+#[should_panic]
+fn test38_from_center_with_negative_radius() {
+  Interval::from_center(3, -1);
+}
+
+#[test]
+fn test39_tolerance() {
+  assert_eq!(
+    Interval::tolerance(10, 2),
+    Interval::closed(LimitValue::Limit(8), LimitValue::Limit(12))
+  );
+  assert_eq!(
+    Interval::relative_tolerance(200, 5),
+    Interval::closed(LimitValue::Limit(190), LimitValue::Limit(210))
+  );
+  assert_eq!(
+    Interval::relative_tolerance(-200, 5),
+    Interval::closed(LimitValue::Limit(-210), LimitValue::Limit(-190))
+  );
+  assert_eq!(
+    Interval::relative_tolerance(Decimal::new(33, 0), Decimal::new(1, 1)),
+    Interval::closed(
+      LimitValue::Limit(Decimal::new(32967, 3)),
+      LimitValue::Limit(Decimal::new(33033, 3))
+    )
+  );
+  assert_eq!(
+    Interval::relative_tolerance(30_000_000, 100),
+    Interval::closed(LimitValue::Limit(0), LimitValue::Limit(60_000_000))
+  );
+  assert_eq!(
+    Interval::relative_tolerance(-250, 5),
+    Interval::closed(LimitValue::Limit(-262), LimitValue::Limit(-238))
+  );
+  assert_eq!(
+    Interval::relative_tolerance(i32::MAX, 0),
+    Interval::closed(LimitValue::Limit(i32::MAX), LimitValue::Limit(i32::MAX))
+  );
+}
+
+#[test]
+fn test40_checked_shift_and_expand() {
+  assert_eq!(
+    c5_10c.checked_shift(-5),
+    Some(Interval::closed(LimitValue::Limit(0), LimitValue::Limit(5)))
+  );
+  assert_eq!(
+    o9_.checked_shift(1),
+    Some(Interval::over(
+      LimitValue::Limit(10),
+      false,
+      LimitValue::Limitless,
+      true
+    ))
+  );
+  assert_eq!(c5_10c.checked_shift(i32::MAX), None);
+  assert_eq!(
+    Interval::closed(LimitValue::Limit(1u8), LimitValue::Limit(250u8)).checked_shift(5),
+    Some(Interval::closed(
+      LimitValue::Limit(6u8),
+      LimitValue::Limit(255u8)
+    ))
+  );
+  assert_eq!(
+    c5_10c.checked_expand(2),
+    Some(Interval::closed(
+      LimitValue::Limit(3),
+      LimitValue::Limit(12)
+    ))
+  );
+  assert_eq!(
+    c5_10c.checked_expand(-2),
+    Some(Interval::closed(LimitValue::Limit(7), LimitValue::Limit(8)))
+  );
+  assert_eq!(c5_10c.checked_expand(-3), None);
+  assert_eq!(
+    Interval::closed(LimitValue::Limit(1u8), LimitValue::Limit(3u8)).checked_expand(2),
+    None
+  );
+  assert_eq!(all.checked_expand(i32::MAX), Some(all.clone()));
+}
+
+#[test]
+fn test41_saturating_shift_and_expand() {
+  let near_max = Interval::closed(
+    LimitValue::Limit(i32::MAX - 10),
+    LimitValue::Limit(i32::MAX - 5),
+  );
+  assert_eq!(
+    near_max.saturating_shift(8),
+    Interval::closed(LimitValue::Limit(i32::MAX - 2), LimitValue::Limit(i32::MAX))
+  );
+  assert_eq!(
+    c5_10c.saturating_shift(i32::MIN + 7),
+    Interval::closed(
+      LimitValue::Limit(i32::MIN + 12),
+      LimitValue::Limit(i32::MIN + 17)
+    )
+  );
+  assert_eq!(
+    Interval::under(LimitValue::Limit(i32::MIN + 1)).saturating_shift(-5),
+    Interval::under(LimitValue::Limit(i32::MIN))
+  );
+  assert_eq!(
+    Interval::closed(LimitValue::Limit(2u8), LimitValue::Limit(250u8)).saturating_expand(10),
+    Interval::closed(LimitValue::Limit(0u8), LimitValue::Limit(255u8))
+  );
+  assert_eq!(
+    near_max.expand_or_limitless(10),
+    Interval::closed(LimitValue::Limit(i32::MAX - 20), LimitValue::Limitless)
+  );
+  assert_eq!(
+    c5_10c.expand_or_limitless(1),
+    Interval::closed(LimitValue::Limit(4), LimitValue::Limit(11))
+  );
+}
+
+#[test]
+fn test42_expand_by_negative_margin() {
+  assert_eq!(
+    c5_10c.saturating_expand(-2),
+    Interval::closed(LimitValue::Limit(7), LimitValue::Limit(8))
+  );
+  assert_eq!(c5_10c.saturating_expand(-3), Interval::empty_at(8));
+  assert_eq!(
+    Interval::closed(LimitValue::Limit(i32::MAX - 1), LimitValue::Limit(i32::MAX))
+      .saturating_expand(i32::MIN),
+    Interval::empty_at(i32::MAX)
+  );
+  assert_eq!(
+    c5_10c.expand_or_limitless(-2),
+    Interval::closed(LimitValue::Limit(7), LimitValue::Limit(8))
+  );
+  assert_eq!(c5_10c.expand_or_limitless(-3), Interval::empty_at(8));
+  assert_eq!(
+    Interval::closed(LimitValue::Limit(i32::MAX - 1), LimitValue::Limit(i32::MAX))
+      .expand_or_limitless(i32::MIN),
+    Interval::empty_at(i32::MIN + i32::MAX)
+  );
+  assert_eq!(
+    Interval::and_more(LimitValue::Limit(5)).expand_or_limitless(-3),
+    Interval::and_more(LimitValue::Limit(8))
+  );
+}
+
+#[test]
+fn test43_from_bounds() {
+  assert_eq!(
+    Interval::from_bounds(Bound::Included(1), Bound::Included(10)).unwrap(),
+    *c1_10c
+  );
+  assert_eq!(
+    Interval::from_bounds(Bound::Excluded(10), Bound::Included(12)).unwrap(),
+    *o10_12c
+  );
+  assert_eq!(
+    Interval::from_bounds(Bound::Unbounded, Bound::Excluded(2)).unwrap(),
+    *_2o
+  );
+  assert_eq!(
+    Interval::from_bounds(Bound::Excluded(9), Bound::Unbounded).unwrap(),
+    *o9_
+  );
+  assert_eq!(
+    Interval::<i32>::from_bounds(Bound::Unbounded, Bound::Unbounded).unwrap(),
+    *all
+  );
+  assert_eq!(
+    Interval::from_bounds(Bound::Excluded(1), Bound::Excluded(1)).unwrap(),
+    *empty
+  );
+  assert!(matches!(
+    Interval::from_bounds(Bound::Included(2), Bound::Included(1)),
+    Err(Error::UpperBelowLower)
+  ));
+}
+
+#[test]
+fn test44_covers_limitless() {
+  assert!(!_2o.covers(&all));
+  assert!(!o9_.covers(&all));
+  assert!(all.covers(&_2o));
+  assert!(all.covers(&o9_));
+  assert!(all.covers(&c5_10c));
+  assert!(!c5_10c.covers(&o9_));
+  assert!(o9_.covers(&o9_));
+}
+
+#[test]
+fn test45_single_element() {
+  assert_eq!(c1_1c.as_single_element(), Some(&1));
+  assert_eq!(c1_1o.as_single_element(), Some(&1));
+  assert_eq!(o1_1o.as_single_element(), None);
+  assert_eq!(c5_10c.as_single_element(), None);
+  assert_eq!(all.as_single_element(), None);
+  assert_eq!(c1_1c.clone().into_single_element(), Some(1));
+  assert_eq!(c4_6c.clone().into_single_element(), None);
+}
+
+#[test]
+fn test46_negate() {
+  assert_eq!(
+    c5_15c.negate(),
+    Interval::closed(LimitValue::Limit(-15), LimitValue::Limit(-5))
+  );
+  assert_eq!(
+    o10_12c.negate(),
+    Interval::over(LimitValue::Limit(-12), true, LimitValue::Limit(-10), false)
+  );
+  assert_eq!(
+    _2o.negate(),
+    Interval::over(LimitValue::Limit(-2), false, LimitValue::Limitless, false)
+  );
+  assert_eq!(all.negate(), *all);
+  assert!(o1_1o.negate().is_empty());
+  assert_eq!(o10_12c.negate().negate(), *o10_12c);
+}
+
+#[test]
+fn test47_scale() {
+  assert_eq!(
+    o10_12c.scale(3),
+    Interval::over(LimitValue::Limit(30), false, LimitValue::Limit(36), true)
+  );
+  assert_eq!(
+    o10_12c.scale(-1),
+    Interval::over(LimitValue::Limit(-12), true, LimitValue::Limit(-10), false)
+  );
+  assert_eq!(
+    o9_.scale(2),
+    Interval::over(LimitValue::Limit(18), false, LimitValue::Limitless, false)
+  );
+  assert_eq!(
+    o9_.scale(-2),
+    Interval::over(LimitValue::Limitless, false, LimitValue::Limit(-18), false)
+  );
+  assert_eq!(
+    c4_6c.scale_about(5, 3),
+    Interval::closed(LimitValue::Limit(2), LimitValue::Limit(8))
+  );
+  assert_eq!(
+    o10_12c.scale_about(10, -2),
+    Interval::over(LimitValue::Limit(6), true, LimitValue::Limit(10), false)
+  );
+}
+
+#[test]
+fn test48_pad() {
+  assert_eq!(
+    c5_10c.pad(2, 5),
+    Interval::closed(LimitValue::Limit(3), LimitValue::Limit(15))
+  );
+  assert_eq!(
+    o10_12c.pad(0, -1),
+    Interval::over(LimitValue::Limit(10), false, LimitValue::Limit(11), true)
+  );
+  assert_eq!(_2o.pad(5, 1), Interval::under(LimitValue::Limit(3)));
+  let collapsed = c4_6c.pad(-2, -1);
+  assert!(collapsed.is_empty());
+  assert_eq!(collapsed.as_lower_limit(), &LimitValue::Limit(6));
+}
+
+#[test]
+fn test49_default() {
+  let interval = Interval::<i32>::default();
+  assert!(!interval.has_lower_limit());
+  assert!(!interval.has_upper_limit());
+  assert!(interval.includes(&LimitValue::Limit(i32::MIN)));
+  assert!(interval.includes(&LimitValue::Limit(i32::MAX)));
+  assert!(!interval.is_empty());
+}
+
+#[test]
+fn test50_single() {
+  let interval = Interval::single(3);
+  assert!(interval.is_single_element());
+  assert_eq!(
+    interval,
+    Interval::closed(LimitValue::Limit(3), LimitValue::Limit(3))
+  );
+  assert!(interval.includes(&LimitValue::Limit(3)));
+}
+
+#[test]
+fn test51_intersect_all() {
+  assert_eq!(
+    Interval::intersect_all(vec![&*c1_10c, &*c5_15c, &*c4_6c]),
+    Interval::closed(LimitValue::Limit(5), LimitValue::Limit(6))
+  );
+  assert_eq!(Interval::intersect_all(vec![&*c5_10c]), *c5_10c);
+  assert!(Interval::intersect_all(vec![&*c4_6c, &*c12_16c, &*c1_10c]).is_empty());
+  assert_eq!(Interval::intersect_all(Vec::<&Interval<i32>>::new()), *all);
+}
+
+#[test]
+fn test52_enclose_all() {
+  assert_eq!(
+    Interval::enclose_all(vec![&*c5_10c, &*c12_16c, &*c4_6c]),
+    Some(Interval::closed(
+      LimitValue::Limit(4),
+      LimitValue::Limit(16)
+    ))
+  );
+  assert_eq!(
+    Interval::enclose_all(vec![&*o10_12c, &*_2o]),
+    Some(Interval::over(
+      LimitValue::Limitless,
+      false,
+      LimitValue::Limit(12),
+      true
+    ))
+  );
+  assert_eq!(Interval::enclose_all(Vec::<&Interval<i32>>::new()), None);
+  assert_eq!(
+    Interval::enclose_all(vec![&*c5_10c, &*empty, &*c12_16c]),
+    Some(Interval::closed(
+      LimitValue::Limit(5),
+      LimitValue::Limit(16)
+    ))
+  );
+  assert_eq!(Interval::enclose_all(vec![&*empty]), None);
+}
+
+#[test]
+fn test53_limit_accessors() {
+  let lower = o10_12c.lower_limit();
+  assert!(lower.is_lower() && lower.is_open());
+  assert_eq!(lower.as_value(), &LimitValue::Limit(10));
+  let upper = o10_12c.upper_limit();
+  assert!(upper.is_upper() && upper.is_closed());
+  assert_eq!(upper.as_value(), &LimitValue::Limit(12));
+  assert!(_2o.lower_limit().is_infinity());
+}
+
+#[test]
+fn test54_span() {
+  assert_eq!(
+    c5_10c.span(&c12_16c),
+    Interval::closed(LimitValue::Limit(5), LimitValue::Limit(16))
+  );
+  assert_eq!(c12_16c.span(&c5_10c), c5_10c.span(&c12_16c));
+  assert_eq!(c1_10c.span(&c4_6c), *c1_10c);
+  assert_eq!(
+    o10_12c.span(&c1_1c),
+    Interval::closed(LimitValue::Limit(1), LimitValue::Limit(12))
+  );
+  assert_eq!(c5_10c.span(&o1_1o), *c5_10c);
+  assert_eq!(o1_1o.span(&c5_10c), *c5_10c);
+}
+
+#[test]
+fn test55_abuts() {
+  let c1_3o = Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(3), false);
+  let c3_5c = Interval::closed(LimitValue::Limit(3), LimitValue::Limit(5));
+  assert!(c1_3o.abuts(&c3_5c));
+  assert!(c3_5c.abuts(&c1_3o));
+  assert!(c5_10c.abuts(&o10_12c));
+
+  let o1_3o = Interval::open(LimitValue::Limit(1), LimitValue::Limit(3));
+  let o3_5c = Interval::over(LimitValue::Limit(3), false, LimitValue::Limit(5), true);
+  assert!(!o1_3o.abuts(&o3_5c));
+  assert!(!c5_10c.abuts(&c5_15c));
+  assert!(!c5_10c.abuts(&c12_16c));
+  assert!(!c1_1o.abuts(&c1_10c));
+}
+
+#[test]
+fn test56_try_constructors() {
+  assert_eq!(
+    Interval::try_closed(LimitValue::Limit(5), LimitValue::Limit(10)).unwrap(),
+    *c5_10c
+  );
+  assert_eq!(
+    Interval::try_over(LimitValue::Limit(10), false, LimitValue::Limit(12), true).unwrap(),
+    *o10_12c
+  );
+  assert_eq!(
+    Interval::try_open(LimitValue::Limit(1), LimitValue::Limit(1)).unwrap(),
+    *o1_1o
+  );
+  assert_eq!(
+    Interval::try_new(
+      IntervalLimit::lower(true, LimitValue::Limit(1)),
+      IntervalLimit::upper(false, LimitValue::Limit(1))
+    )
+    .unwrap(),
+    *c1_1c
+  );
+  assert!(matches!(
+    Interval::try_closed(LimitValue::Limit(10), LimitValue::Limit(5)),
+    Err(Error::UpperBelowLower)
+  ));
+  assert!(matches!(
+    Interval::try_open(LimitValue::Limit(2), LimitValue::Limit(1)),
+    Err(Error::UpperBelowLower)
+  ));
+  assert!(matches!(
+    Interval::try_over(LimitValue::Limit(1), true, LimitValue::Limit(0), true),
+    Err(Error::UpperBelowLower)
+  ));
+  assert!(matches!(
+    Interval::try_new(
+      IntervalLimit::upper(true, LimitValue::Limit(1)),
+      IntervalLimit::lower(true, LimitValue::Limit(5))
+    ),
+    Err(Error::UpperBelowLower)
+  ));
+}
+
+#[test]
+fn test57_empty_and_all() {
+  assert!(Interval::<i32>::empty().is_empty());
+  assert_eq!(
+    Interval::<i32>::empty(),
+    Interval::open(LimitValue::Limit(0), LimitValue::Limit(0))
+  );
+  assert_eq!(Interval::empty_at(1), *empty);
+  assert!(Interval::empty_at("a".to_string()).is_empty());
+
+  assert_eq!(Interval::<i32>::all(), *all);
+  assert_eq!(Interval::<i32>::all(), Interval::default());
+  assert!(Interval::<i32>::all().includes(&LimitValue::Limit(i32::MIN)));
+}
+
+#[test]
+fn test58_into_bounds() {
+  assert_eq!(
+    c5_10c.clone().into_bounds(),
+    (Bound::Included(5), Bound::Included(10))
+  );
+  assert_eq!(
+    o10_12c.clone().into_bounds(),
+    (Bound::Excluded(10), Bound::Included(12))
+  );
+  assert_eq!(
+    Interval::<i32>::under(LimitValue::Limit(3)).into_bounds(),
+    (Bound::Unbounded, Bound::Excluded(3))
+  );
+  for interval in [&*c5_10c, &*o10_12c, &*o1_1o, &*c1_1c, &*all] {
+    let (lower, upper) = interval.clone().into_bounds();
+    assert_eq!(Interval::from_bounds(lower, upper).unwrap(), *interval);
+  }
+}
+
+#[test]
+fn test59_ord() {
+  let mut intervals = vec![
+    c5_15c.clone(),
+    o1_1o.clone(),
+    c5_10c.clone(),
+    o10_12c.clone(),
+    all.clone(),
+    c1_10c.clone(),
+  ];
+  intervals.sort();
+  assert_eq!(
+    intervals,
+    vec![
+      all.clone(),
+      c1_10c.clone(),
+      c5_10c.clone(),
+      c5_15c.clone(),
+      o10_12c.clone(),
+      o1_1o.clone(),
+    ]
+  );
+  assert_eq!(empty.cmp(&o1_1o), std::cmp::Ordering::Equal);
+  assert!(*c1_1c < *c1_10c);
+  assert_eq!(
+    c1_1c.cmp(&Interval::over(
+      LimitValue::Limit(1),
+      true,
+      LimitValue::Limit(1),
+      false
+    )),
+    std::cmp::Ordering::Equal
+  );
+
+  let set = vec![c5_10c.clone(), c1_10c.clone(), c5_10c.clone()]
+    .into_iter()
+    .collect::<std::collections::BTreeSet<_>>();
+  assert_eq!(set.len(), 2);
+  assert_eq!(set.iter().next(), Some(&*c1_10c));
+}
+
+#[test]
+fn test60_values_without_display() {
+  #[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+  struct Version(u32, u32);
+
+  let interval = Interval::over(
+    LimitValue::Limit(Version(1, 2)),
+    true,
+    LimitValue::Limit(Version(2, 0)),
+    false,
+  );
+  assert!(interval.includes(&LimitValue::Limit(Version(1, 9))));
+  assert!(!interval.includes(&LimitValue::Limit(Version(2, 0))));
+  let later = Interval::and_more(LimitValue::Limit(Version(1, 5)));
+  assert!(
+    interval.intersect(&later)
+      == Interval::over(
+        LimitValue::Limit(Version(1, 5)),
+        true,
+        LimitValue::Limit(Version(2, 0)),
+        false,
+      )
+  );
+  assert_eq!(crate::IntervalSeq::new(vec![interval, later]).len(), 2);
+}
+
+#[test]
+fn test61_hash_consistent_with_eq() {
+  use std::collections::hash_map::DefaultHasher;
+  use std::hash::{Hash, Hasher};
+
+  let hash_of = |interval: &Interval<Decimal>| {
+    let mut hasher = DefaultHasher::new();
+    interval.hash(&mut hasher);
+    hasher.finish()
+  };
+  let a = Interval::closed(
+    LimitValue::Limit(Decimal::new(10, 1)),
+    LimitValue::Limit(Decimal::new(20, 1)),
+  );
+  let b = Interval::closed(
+    LimitValue::Limit(Decimal::new(100, 2)),
+    LimitValue::Limit(Decimal::new(2, 0)),
+  );
+  assert_eq!(a, b);
+  assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+#[should_panic]
+fn test62_relative_tolerance_out_of_range() {
+  Interval::relative_tolerance(i32::MIN, 1);
+}
+
+#[test]
+fn test63_split_by() {
+  let fetched = IntervalSeq::new(vec![
+    Interval::closed(LimitValue::Limit(0), LimitValue::Limit(10)),
+    Interval::closed(LimitValue::Limit(20), LimitValue::Limit(30)),
+    Interval::closed(LimitValue::Limit(25), LimitValue::Limit(40)),
+  ]);
+  let requested = Interval::closed(LimitValue::Limit(5), LimitValue::Limit(35));
+  let (mut covered, mut uncovered) = requested.split_by(&fetched);
+  assert_eq!(
+    covered.iter().cloned().collect::<Vec<_>>(),
+    vec![
+      Interval::closed(LimitValue::Limit(5), LimitValue::Limit(10)),
+      Interval::closed(LimitValue::Limit(20), LimitValue::Limit(35)),
+    ]
+  );
+  assert_eq!(
+    uncovered.iter().cloned().collect::<Vec<_>>(),
+    vec![Interval::open(LimitValue::Limit(10), LimitValue::Limit(20))]
+  );
+
+  let (covered, mut uncovered) = requested.split_by(&IntervalSeq::empty());
+  assert!(covered.is_empty());
+  assert_eq!(
+    uncovered.iter().cloned().collect::<Vec<_>>(),
+    vec![requested]
+  );
+}
+
+#[test]
+fn test64_union() {
+  let closed = |lower, upper| Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper));
+  let o0_0o = Interval::open(LimitValue::Limit(0), LimitValue::Limit(0));
+  let o12_20o = Interval::open(LimitValue::Limit(12), LimitValue::Limit(20));
+  let values = |mut seq: IntervalSeq<i32>| seq.iter().cloned().collect::<Vec<_>>();
+
+  assert_eq!(
+    values(closed(1, 5).union(&closed(3, 8))),
+    vec![closed(1, 8)]
+  );
+  assert_eq!(values(c5_10c.union(&o10_12c)), vec![closed(5, 12)]);
+  assert_eq!(
+    values(closed(6, 8).union(&closed(1, 3))),
+    vec![closed(1, 3), closed(6, 8)]
+  );
+  assert_eq!(
+    values(o10_12c.union(&o12_20o)),
+    vec![Interval::open(LimitValue::Limit(10), LimitValue::Limit(20))]
+  );
+  let o10_12o = Interval::open(LimitValue::Limit(10), LimitValue::Limit(12));
+  assert_eq!(
+    values(o10_12o.union(&o12_20o)),
+    vec![o10_12o.clone(), o12_20o.clone()]
+  );
+  assert_eq!(values(o0_0o.union(&closed(1, 3))), vec![closed(1, 3)]);
+  assert!(o0_0o.union(&o0_0o).is_empty());
+}
+
+#[test]
+fn test65_subtract() {
+  let closed = |lower, upper| Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper));
+  let o0_0o = Interval::open(LimitValue::Limit(0), LimitValue::Limit(0));
+  let values = |mut seq: IntervalSeq<i32>| seq.iter().cloned().collect::<Vec<_>>();
+
+  assert_eq!(
+    values(closed(0, 10).subtract(&closed(3, 5))),
+    vec![
+      Interval::over(LimitValue::Limit(0), true, LimitValue::Limit(3), false),
+      Interval::over(LimitValue::Limit(5), false, LimitValue::Limit(10), true),
+    ]
+  );
+  assert_eq!(values(c5_10c.subtract(&o10_12c)), vec![c5_10c.clone()]);
+  assert_eq!(
+    values(closed(10, 20).subtract(&o10_12c)),
+    vec![
+      closed(10, 10),
+      Interval::over(LimitValue::Limit(12), false, LimitValue::Limit(20), true),
+    ]
+  );
+  assert_eq!(
+    values(closed(10, 12).subtract(&closed(1, 11))),
+    vec![Interval::over(
+      LimitValue::Limit(11),
+      false,
+      LimitValue::Limit(12),
+      true
+    )]
+  );
+  assert_eq!(values(c5_10c.subtract(&o0_0o)), vec![c5_10c.clone()]);
+  assert!(closed(3, 5).subtract(&closed(0, 10)).is_empty());
+  assert!(o0_0o.subtract(&c5_10c).is_empty());
+}
+
+#[test]
+fn test66_complement() {
+  let o0_0o = Interval::open(LimitValue::Limit(0), LimitValue::Limit(0));
+  let whole = Interval::open(LimitValue::Limitless, LimitValue::Limitless);
+  let values = |mut seq: IntervalSeq<i32>| seq.iter().cloned().collect::<Vec<_>>();
+
+  assert_eq!(
+    values(Interval::over(LimitValue::Limit(3), true, LimitValue::Limit(5), false).complement()),
+    vec![
+      Interval::under(LimitValue::Limit(3)),
+      Interval::and_more(LimitValue::Limit(5)),
+    ]
+  );
+  assert_eq!(
+    values(Interval::and_more(LimitValue::Limit(5)).complement()),
+    vec![Interval::under(LimitValue::Limit(5))]
+  );
+  assert_eq!(values(o0_0o.complement()), vec![whole.clone()]);
+  assert!(whole.complement().is_empty());
+}
+
+#[test]
+fn test67_symmetric_difference() {
+  let closed = |lower, upper| Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper));
+  let o0_0o = Interval::open(LimitValue::Limit(0), LimitValue::Limit(0));
+  let c20_25c = Interval::closed(LimitValue::Limit(20), LimitValue::Limit(25));
+  let values = |mut seq: IntervalSeq<i32>| seq.iter().cloned().collect::<Vec<_>>();
+
+  assert_eq!(
+    values(closed(1, 5).symmetric_difference(&closed(3, 8))),
+    vec![
+      Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(3), false),
+      Interval::over(LimitValue::Limit(5), false, LimitValue::Limit(8), true),
+    ]
+  );
+  assert_eq!(
+    values(closed(3, 4).symmetric_difference(&closed(0, 10))),
+    vec![
+      Interval::over(LimitValue::Limit(0), true, LimitValue::Limit(3), false),
+      Interval::over(LimitValue::Limit(4), false, LimitValue::Limit(10), true),
+    ]
+  );
+  assert_eq!(
+    values(c5_10c.symmetric_difference(&o10_12c)),
+    vec![closed(5, 12)]
+  );
+  assert_eq!(
+    values(c5_10c.symmetric_difference(&c20_25c)),
+    vec![c5_10c.clone(), c20_25c.clone()]
+  );
+  assert_eq!(
+    values(c5_10c.symmetric_difference(&o0_0o)),
+    vec![c5_10c.clone()]
+  );
+  assert!(c5_10c.symmetric_difference(&c5_10c).is_empty());
+}
@@ -0,0 +1,67 @@
+use crate::Interval;
+
+/// Whether the invariants are checked, i.e. the `invariants` feature is enabled in a debug build.
+pub(crate) const ENABLED: bool = cfg!(all(debug_assertions, feature = "invariants"));
+
+/// Assert that the named invariant of a structure holds.
+///
+/// The condition is evaluated only when the invariants are checked.
+///
+/// - params
+///     - structure: the name of the structure
+///     - invariant: the description of the invariant
+///     - holds: a function that verifies the invariant
+/// - panic
+///     - if the invariants are checked and `holds` returns `false`
+pub(crate) fn check(structure: &str, invariant: &str, holds: impl FnOnce() -> bool) {
+  if ENABLED && !holds() {
+    panic!("{} invariant violated: {}", structure, invariant)
+  }
+}
+
+/// Assert that every interval is well-formed, i.e. its lower limit is not above its upper limit.
+///
+/// - params
+///     - structure: the name of the structure
+///     - intervals: the members of the structure
+pub(crate) fn check_well_formed<'a, T>(
+  structure: &str,
+  intervals: impl IntoIterator<Item = &'a Interval<T>>,
+) where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + 'a,
+{
+  check(
+    structure,
+    "members have the lower limit below the upper limit",
+    || intervals.into_iter().all(|e| e.lower <= e.upper),
+  );
+}
+
+/// Assert that the intervals are non-empty, sorted by their lower limits and disjoint.
+///
+/// - params
+///     - structure: the name of the structure
+///     - intervals: the members of the structure in their storage order
+pub(crate) fn check_sorted_disjoint<'a, T>(
+  structure: &str,
+  intervals: impl IntoIterator<Item = &'a Interval<T>> + Clone,
+) where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + 'a,
+{
+  check_well_formed(structure, intervals.clone());
+  check(structure, "members are non-empty", || {
+    intervals.clone().into_iter().all(|e| !e.is_empty())
+  });
+  let pairs = || {
+    intervals
+      .clone()
+      .into_iter()
+      .zip(intervals.clone().into_iter().skip(1))
+  };
+  check(structure, "members are sorted by the lower limits", || {
+    pairs().all(|(a, b)| a.lower <= b.lower)
+  });
+  check(structure, "members are disjoint", || {
+    pairs().all(|(a, b)| !a.intersects(b))
+  });
+}
@@ -0,0 +1,46 @@
+use crate::invariants;
+use crate::{Interval, IntervalMap, IntervalSeq, LimitValue, RangeSet};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_mutations_keep_invariants() {
+  let mut map = IntervalMap::new();
+  map.insert(closed(0, 100), "base");
+  map.insert(closed(10, 20), "discount");
+  map.insert(closed(-10, 5), "early");
+  assert!(map.try_insert(closed(200, 300), "late").is_ok());
+
+  let mut set = RangeSet::new();
+  set.insert_range(&closed(0, 10));
+  set.insert_range(&closed(11, 20));
+  set.remove_range(&closed(5, 6));
+  set.insert(6);
+
+  let mut seq = IntervalSeq::new(vec![closed(5, 10), closed(0, 20)]);
+  seq.append(&closed(3, 4));
+  assert_eq!(seq.iter().count(), 3);
+}
+
+#[test]
+#[should_panic(expected = "IntervalMap invariant violated: members are sorted by the lower limits")]
+fn test02_unsorted_members() {
+  invariants::check_sorted_disjoint("IntervalMap", &[closed(5, 6), closed(0, 1)]);
+}
+
+#[test]
+#[should_panic(expected = "IntervalMap invariant violated: members are disjoint")]
+fn test03_overlapping_members() {
+  invariants::check_sorted_disjoint("IntervalMap", &[closed(0, 5), closed(5, 6)]);
+}
+
+#[test]
+#[should_panic(expected = "IntervalMap invariant violated: members are non-empty")]
+fn test04_empty_members() {
+  invariants::check_sorted_disjoint(
+    "IntervalMap",
+    &[Interval::open(LimitValue::Limit(1), LimitValue::Limit(1))],
+  );
+}
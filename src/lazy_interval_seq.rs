@@ -0,0 +1,291 @@
+use std::iter::Peekable;
+
+use crate::{Interval, IntervalSeq};
+
+/// A structure that represents an interval sequence evaluated lazily from an iterator.
+///
+/// The source iterator is expected to yield intervals sorted by their lower limits.
+/// Operations on this structure are applied one interval at a time, without materializing intermediate `Vec`s.
+pub struct LazyIntervalSeq<T, I>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+{
+  iter: I,
+}
+
+impl<T, I> LazyIntervalSeq<T, I>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+{
+  /// Generate a lazy interval sequence.
+  ///
+  /// - params
+  ///     - iter: an iterator of intervals sorted by their lower limits
+  /// - return: `LazyIntervalSeq`
+  pub fn new(iter: impl IntoIterator<IntoIter = I, Item = Interval<T>>) -> Self {
+    Self {
+      iter: iter.into_iter(),
+    }
+  }
+
+  /// Clip each interval to the given interval `bounds`, dropping the intervals that do not intersect it.
+  ///
+  /// The iteration stops as soon as an interval starts above `bounds`.
+  ///
+  /// - params
+  ///     - bounds: a bounding interval
+  /// - return: clipped lazy interval sequence
+  pub fn clip(self, bounds: Interval<T>) -> LazyIntervalSeq<T, Clip<T, I>> {
+    LazyIntervalSeq {
+      iter: Clip {
+        iter: self.iter,
+        bounds,
+        done: false,
+      },
+    }
+  }
+
+  /// Merge overlapping or adjacent intervals, dropping empty intervals.
+  ///
+  /// - return: coalesced lazy interval sequence
+  pub fn coalesce(self) -> LazyIntervalSeq<T, Coalesce<T, I>> {
+    LazyIntervalSeq {
+      iter: Coalesce {
+        iter: self.iter.peekable(),
+      },
+    }
+  }
+
+  /// Intersect this lazy interval sequence with another stream of intervals sorted by their lower limits.
+  ///
+  /// Both sides are coalesced on the fly, so the result is sorted and its intervals do not overlap.
+  ///
+  /// - params
+  ///     - other: an iterator of intervals sorted by their lower limits
+  /// - return: intersected lazy interval sequence
+  pub fn intersect<J>(
+    self,
+    other: impl IntoIterator<IntoIter = J, Item = Interval<T>>,
+  ) -> LazyIntervalSeq<T, Intersect<T, I, J>>
+  where
+    J: Iterator<Item = Interval<T>>,
+  {
+    LazyIntervalSeq {
+      iter: Intersect {
+        left: Coalesce {
+          iter: self.iter.peekable(),
+        }
+        .peekable(),
+        right: Coalesce {
+          iter: other.into_iter().peekable(),
+        }
+        .peekable(),
+      },
+    }
+  }
+
+  /// Subtract another stream of intervals sorted by their lower limits from this lazy interval sequence.
+  ///
+  /// Both sides are coalesced on the fly, so the result is sorted and its intervals do not overlap.
+  ///
+  /// - params
+  ///     - other: an iterator of intervals sorted by their lower limits
+  /// - return: subtracted lazy interval sequence
+  pub fn difference<J>(
+    self,
+    other: impl IntoIterator<IntoIter = J, Item = Interval<T>>,
+  ) -> LazyIntervalSeq<T, Difference<T, I, J>>
+  where
+    J: Iterator<Item = Interval<T>>,
+  {
+    LazyIntervalSeq {
+      iter: Difference {
+        left: Coalesce {
+          iter: self.iter.peekable(),
+        },
+        right: Coalesce {
+          iter: other.into_iter().peekable(),
+        }
+        .peekable(),
+        current: None,
+      },
+    }
+  }
+
+  /// Evaluate this lazy interval sequence into an `IntervalSeq`.
+  ///
+  /// - return: `IntervalSeq`
+  pub fn to_seq(self) -> IntervalSeq<T> {
+    IntervalSeq::new(self.iter)
+  }
+}
+
+impl<T, I> Iterator for LazyIntervalSeq<T, I>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+{
+  type Item = Interval<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    self.iter.next()
+  }
+}
+
+/// An iterator that clips intervals to a bounding interval. See [`LazyIntervalSeq::clip`].
+pub struct Clip<T, I>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+{
+  iter: I,
+  bounds: Interval<T>,
+  done: bool,
+}
+
+impl<T, I> Iterator for Clip<T, I>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+{
+  type Item = Interval<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    while !self.done {
+      match self.iter.next() {
+        None => self.done = true,
+        Some(interval) if self.bounds.is_below(interval.as_lower_limit()) => self.done = true,
+        Some(interval) => {
+          let clipped = interval.intersect(&self.bounds);
+          if !clipped.is_empty() {
+            return Some(clipped);
+          }
+        }
+      }
+    }
+    None
+  }
+}
+
+/// An iterator that merges overlapping or adjacent intervals. See [`LazyIntervalSeq::coalesce`].
+pub struct Coalesce<T, I>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+{
+  iter: Peekable<I>,
+}
+
+impl<T, I> Iterator for Coalesce<T, I>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+{
+  type Item = Interval<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let mut current = self.iter.find(|e| !e.is_empty())?;
+    while let Some(next) = self.iter.peek() {
+      if next.is_empty() {
+        self.iter.next();
+      } else if current.intersects(next) || current.abuts(next) {
+        current = current.span(next);
+        self.iter.next();
+      } else {
+        break;
+      }
+    }
+    Some(current)
+  }
+}
+
+/// An iterator that intersects two sorted streams of intervals. See [`LazyIntervalSeq::intersect`].
+pub struct Intersect<T, I, J>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+  J: Iterator<Item = Interval<T>>,
+{
+  left: Peekable<Coalesce<T, I>>,
+  right: Peekable<Coalesce<T, J>>,
+}
+
+impl<T, I, J> Iterator for Intersect<T, I, J>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+  J: Iterator<Item = Interval<T>>,
+{
+  type Item = Interval<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      let left = self.left.peek()?;
+      let right = self.right.peek()?;
+      let intersection = left.intersect(right);
+      if left.upper <= right.upper {
+        self.left.next();
+      } else {
+        self.right.next();
+      }
+      if !intersection.is_empty() {
+        return Some(intersection);
+      }
+    }
+  }
+}
+
+/// An iterator that subtracts a sorted stream of intervals from another. See [`LazyIntervalSeq::difference`].
+pub struct Difference<T, I, J>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+  J: Iterator<Item = Interval<T>>,
+{
+  left: Coalesce<T, I>,
+  right: Peekable<Coalesce<T, J>>,
+  /// the part of the current left interval that is not yet subtracted
+  current: Option<Interval<T>>,
+}
+
+impl<T, I, J> Iterator for Difference<T, I, J>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  I: Iterator<Item = Interval<T>>,
+  J: Iterator<Item = Interval<T>>,
+{
+  type Item = Interval<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    loop {
+      let current = match self.current.take() {
+        Some(current) => current,
+        None => self.left.next()?,
+      };
+      let right = match self.right.peek() {
+        Some(right) => right,
+        None => return Some(current),
+      };
+      if !right.intersects(&current) {
+        if right.upper < current.upper {
+          self.right.next();
+          self.current = Some(current);
+          continue;
+        }
+        return Some(current);
+      }
+      let below = right.left_complement_relative_to(&current);
+      self.current = right
+        .right_complement_relative_to(&current)
+        .filter(|e| !e.is_empty());
+      if self.current.is_some() {
+        self.right.next();
+      }
+      if let Some(below) = below.filter(|e| !e.is_empty()) {
+        return Some(below);
+      }
+    }
+  }
+}
@@ -0,0 +1,108 @@
+use crate::{Interval, LazyIntervalSeq, LimitValue};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+fn open(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::open(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_clip() {
+  let lazy = LazyIntervalSeq::new(vec![
+    closed(1, 3),
+    closed(5, 10),
+    closed(12, 20),
+    closed(30, 40),
+  ])
+  .clip(closed(2, 15));
+  assert_eq!(
+    lazy.collect::<Vec<_>>(),
+    vec![closed(2, 3), closed(5, 10), closed(12, 15)]
+  );
+}
+
+#[test]
+fn test02_clip_stops_above_bounds() {
+  let source = (0..).map(|i| closed(i * 10, i * 10 + 5));
+  let lazy = LazyIntervalSeq::new(source).clip(closed(12, 33));
+  assert_eq!(
+    lazy.collect::<Vec<_>>(),
+    vec![closed(12, 15), closed(20, 25), closed(30, 33)]
+  );
+}
+
+#[test]
+fn test03_coalesce() {
+  let lazy = LazyIntervalSeq::new(vec![
+    closed(1, 3),
+    open(2, 5),
+    open(4, 4),
+    closed(5, 6),
+    open(6, 8),
+    closed(10, 12),
+  ])
+  .coalesce();
+  assert_eq!(
+    lazy.collect::<Vec<_>>(),
+    vec![
+      Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(8), false),
+      closed(10, 12)
+    ]
+  );
+}
+
+#[test]
+fn test04_intersect() {
+  let left = vec![closed(1, 5), closed(8, 12), closed(20, 30)];
+  let right = vec![closed(3, 4), closed(4, 9), open(12, 25), closed(28, 28)];
+  let lazy = LazyIntervalSeq::new(left).intersect(right);
+  assert_eq!(
+    lazy.to_seq().into_iter().collect::<Vec<_>>(),
+    vec![
+      closed(3, 5),
+      closed(8, 9),
+      Interval::over(LimitValue::Limit(20), true, LimitValue::Limit(25), false),
+      closed(28, 28)
+    ]
+  );
+}
+
+#[test]
+fn test05_difference() {
+  let left = vec![closed(1, 5), closed(8, 12), closed(20, 30), closed(40, 50)];
+  let right = vec![
+    closed(3, 4),
+    closed(4, 9),
+    open(12, 25),
+    closed(28, 28),
+    closed(35, 36),
+  ];
+  let lazy = LazyIntervalSeq::new(left).difference(right);
+  assert_eq!(
+    lazy.collect::<Vec<_>>(),
+    vec![
+      Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(3), false),
+      Interval::over(LimitValue::Limit(9), false, LimitValue::Limit(12), true),
+      Interval::over(LimitValue::Limit(25), true, LimitValue::Limit(28), false),
+      Interval::over(LimitValue::Limit(28), false, LimitValue::Limit(30), true),
+      closed(40, 50),
+    ]
+  );
+}
+
+#[test]
+fn test06_difference_is_lazy() {
+  let left = (0..).map(|i| closed(i * 10, i * 10 + 5));
+  let right = (0..).map(|i| closed(i * 10 + 2, i * 10 + 3));
+  let lazy = LazyIntervalSeq::new(left).difference(right);
+  assert_eq!(
+    lazy.take(3).collect::<Vec<_>>(),
+    vec![
+      Interval::over(LimitValue::Limit(0), true, LimitValue::Limit(2), false),
+      Interval::over(LimitValue::Limit(3), false, LimitValue::Limit(5), true),
+      Interval::over(LimitValue::Limit(10), true, LimitValue::Limit(12), false),
+    ]
+  );
+}
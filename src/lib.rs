@@ -1,31 +1,149 @@
+mod aliases;
+mod bitmap_set;
+mod containment_forest;
+mod discrete;
+pub mod encoding;
 mod errors;
+#[cfg(feature = "fuzzy")]
+mod fuzzy_interval;
 
 mod interval;
+mod interval_diff;
+mod interval_like;
 mod interval_limit;
+mod interval_map;
+mod interval_notation;
+mod interval_relation;
 mod interval_seq;
+mod interval_seq_arena;
+mod interval_seq_builder;
+mod interval_seq_cursor;
+mod invariants;
+mod lazy_interval_seq;
 mod limit_value;
+#[cfg(feature = "ordered-float")]
+mod ordered_float_interop;
+mod overlap_matrix;
+mod partial_interval;
+mod point_position;
+pub mod prelude;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "uom")]
+mod quantity;
+mod range_set;
+#[cfg(feature = "rangemap")]
+mod rangemap_interop;
+mod render;
+#[cfg(feature = "serde")]
+mod serde_interop;
+mod shared;
+mod sort_spec;
+mod time;
+mod typed_interval;
+mod uncertain_interval;
+pub mod windows;
 
+#[cfg(test)]
+mod bitmap_set_test;
+#[cfg(test)]
+mod containment_forest_test;
+#[cfg(test)]
+mod discrete_test;
+#[cfg(test)]
+mod encoding_test;
+#[cfg(all(test, feature = "fuzzy"))]
+mod fuzzy_interval_test;
+#[cfg(test)]
+mod interval_diff_test;
+#[cfg(test)]
+mod interval_like_test;
 #[cfg(test)]
 mod interval_limit_test;
 #[cfg(test)]
+mod interval_map_test;
+#[cfg(test)]
+mod interval_notation_test;
+#[cfg(test)]
+mod interval_relation_test;
+#[cfg(test)]
+mod interval_seq_arena_test;
+#[cfg(test)]
+mod interval_seq_builder_test;
+#[cfg(test)]
+mod interval_seq_cursor_test;
+#[cfg(test)]
 mod interval_seq_test;
 #[cfg(test)]
 mod interval_test;
+#[cfg(all(test, feature = "invariants"))]
+mod invariants_test;
+#[cfg(test)]
+mod lazy_interval_seq_test;
 #[cfg(test)]
 mod limit_value_test;
+#[cfg(all(test, feature = "ordered-float"))]
+mod ordered_float_interop_test;
+#[cfg(test)]
+mod partial_interval_test;
+#[cfg(test)]
+mod point_position_test;
+#[cfg(all(test, feature = "python"))]
+mod python_test;
+#[cfg(all(test, feature = "uom"))]
+mod quantity_test;
+#[cfg(test)]
+mod range_set_test;
+#[cfg(all(test, feature = "rangemap"))]
+mod rangemap_interop_test;
+#[cfg(test)]
+mod render_test;
+#[cfg(all(test, feature = "serde"))]
+mod serde_interop_test;
+#[cfg(test)]
+mod shared_test;
+#[cfg(test)]
+mod time_test;
+#[cfg(test)]
+mod typed_interval_test;
+#[cfg(test)]
+mod uncertain_interval_test;
+#[cfg(test)]
+mod windows_test;
 
+#[cfg(feature = "chrono")]
+pub use crate::aliases::DateInterval;
+pub use crate::aliases::{IntInterval, Span};
+pub use crate::bitmap_set::BitmapSet;
+pub use crate::containment_forest::{ContainmentForest, ContainmentNode};
+#[cfg(feature = "derive")]
+pub use intervals_rs_derive::IntervalNewtype;
+pub use crate::discrete::{Discrete, DiscretizePolicy};
+pub use crate::encoding::Encode;
 pub use crate::errors::Error;
+#[cfg(feature = "fuzzy")]
+pub use crate::fuzzy_interval::FuzzyInterval;
 pub use crate::limit_value::LimitValue;
 pub use crate::interval_limit::IntervalLimit;
 pub use crate::interval::Interval;
-pub use crate::interval_seq::IntervalSeq;
-use std::cmp::Ordering;
-
-pub fn to_ordering(n: i8) -> Ordering {
-  match n {
-    -1 => Ordering::Less,
-    0 => Ordering::Equal,
-    1 => Ordering::Greater,
-    _ => panic!(),
-  }
-}
+pub use crate::interval_diff::IntervalDiff;
+pub use crate::interval_like::IntervalLike;
+pub use crate::interval_map::IntervalMap;
+pub use crate::interval_notation::{IntervalFormatter, IntervalNotation};
+pub use crate::interval_relation::{IntervalRelation, RelationReport};
+pub use crate::interval_seq::{EmptyPolicy, IntervalCluster, IntervalSeq, TimelineSegment};
+pub use crate::interval_seq_arena::IntervalSeqArena;
+pub use crate::interval_seq_builder::IntervalSeqBuilder;
+pub use crate::interval_seq_cursor::IntervalSeqCursor;
+pub use crate::lazy_interval_seq::{Clip, Coalesce, Difference, Intersect, LazyIntervalSeq};
+#[cfg(feature = "uom")]
+pub use crate::quantity::QuantityValue;
+pub use crate::overlap_matrix::OverlapMatrix;
+pub use crate::partial_interval::PartialInterval;
+pub use crate::point_position::PointPosition;
+pub use crate::range_set::RangeSet;
+pub use crate::sort_spec::SortSpec;
+pub use crate::typed_interval::{
+  BoundKind, Closed, ClosedInterval, HalfOpenInterval, Open, OpenInterval, TypedInterval,
+};
+pub use crate::uncertain_interval::{Tristate, UncertainInterval};
@@ -5,7 +5,7 @@ use crate::Error;
 use std::hash::{Hash, Hasher};
 
 /// A structure that represents a limit value.
-#[derive(Debug, Clone, Eq, Ord)]
+#[derive(Debug, Clone, Eq)]
 pub enum LimitValue<T> {
   /// finite limit value
   Limit(T),
@@ -13,12 +13,12 @@ pub enum LimitValue<T> {
   Limitless,
 }
 
-impl<T: ToString> Hash for LimitValue<T> {
+impl<T: Hash> Hash for LimitValue<T> {
   fn hash<H: Hasher>(&self, state: &mut H) {
     match self {
       LimitValue::Limit(value) => {
         "Limit".hash(state);
-        value.to_string().hash(state)
+        value.hash(state)
       }
       LimitValue::Limitless => {
         "Limitless".hash(state);
@@ -54,6 +54,17 @@ impl<T: PartialOrd> PartialOrd for LimitValue<T> {
   }
 }
 
+impl<T: Ord> Ord for LimitValue<T> {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self, other) {
+      (LimitValue::Limitless, LimitValue::Limitless) => Ordering::Equal,
+      (LimitValue::Limit(_), LimitValue::Limitless) => Ordering::Greater,
+      (LimitValue::Limitless, LimitValue::Limit(_)) => Ordering::Less,
+      (LimitValue::Limit(value), LimitValue::Limit(other_value)) => value.cmp(other_value),
+    }
+  }
+}
+
 impl<T> From<Option<T>> for LimitValue<T> {
   fn from(value: Option<T>) -> Self {
     match value {
@@ -83,7 +94,7 @@ impl<T> LimitValue<T> {
   }
 
   /// Get the limit value.
-  pub fn as_value_or<'a, TF>(&'a self, default: TF) -> &T
+  pub fn as_value_or<'a, TF>(&'a self, default: TF) -> &'a T
   where
     TF: Fn() -> &'a T,
   {
@@ -92,6 +103,32 @@ impl<T> LimitValue<T> {
       LimitValue::Limitless => default(),
     }
   }
+
+  /// Get the limit value, or the given `default` if this limit is infinite.
+  ///
+  /// Use `as_value_or` for a default that is computed lazily.
+  ///
+  /// - params
+  ///     - default: a default value
+  /// - return: the limit value or `default`
+  pub fn value_or<'a>(&'a self, default: &'a T) -> &'a T {
+    match self {
+      LimitValue::Limit(a) => a,
+      LimitValue::Limitless => default,
+    }
+  }
+
+  /// Take the limit value, or the given `default` if this limit is infinite.
+  ///
+  /// - params
+  ///     - default: a default value
+  /// - return: the limit value or `default`
+  pub fn into_value_or(self, default: T) -> T {
+    match self {
+      LimitValue::Limit(a) => a,
+      LimitValue::Limitless => default,
+    }
+  }
 }
 
 impl<T: Display> Display for LimitValue<T> {
@@ -9,3 +9,15 @@ fn it_works() {
   assert!(LimitValue::Limitless < LimitValue::Limit(1));
   assert!(LimitValue::Limit(1) > LimitValue::Limitless);
 }
+
+#[test]
+fn test01_value_or() {
+  let zero = 0;
+  assert_eq!(LimitValue::Limit(5).value_or(&zero), &5);
+  assert_eq!(LimitValue::Limitless.value_or(&zero), &0);
+  assert_eq!(LimitValue::Limit(5).into_value_or(0), 5);
+  assert_eq!(
+    LimitValue::<String>::Limitless.into_value_or("none".to_string()),
+    "none"
+  );
+}
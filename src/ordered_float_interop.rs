@@ -0,0 +1,79 @@
+//! Conversions between `f64` values and intervals of `OrderedFloat<f64>`, which satisfies the `Ord` bound.
+//!
+//! As in `OrderedFloat`, NaN is ordered above every other value, including positive infinity.
+
+use std::ops::{Range, RangeInclusive};
+
+use ordered_float::OrderedFloat;
+
+use crate::{Interval, IntervalLike, LimitValue};
+
+impl Interval<OrderedFloat<f64>> {
+  /// Generate an interval of `f64` values.
+  ///
+  /// - params
+  ///     - lower: lower limit
+  ///     - lower_included: specify `true` if the lower limit is included in the interval (closed lower limit).
+  ///     - upper: upper limit
+  ///     - upper_included: specify `true` if the upper limit is included in the interval (closed upper limit)
+  /// - return: an interval
+  /// - panic
+  ///     - if the lower limit is greater than the upper limit
+  pub fn over_f64(lower: f64, lower_included: bool, upper: f64, upper_included: bool) -> Self {
+    Self::over(
+      LimitValue::Limit(OrderedFloat(lower)),
+      lower_included,
+      LimitValue::Limit(OrderedFloat(upper)),
+      upper_included,
+    )
+  }
+
+  /// Generate a closed interval of `f64` values.
+  ///
+  /// - params
+  ///     - lower: lower limit
+  ///     - upper: upper limit
+  /// - return: a closed interval
+  /// - panic
+  ///     - if the lower limit is greater than the upper limit
+  pub fn closed_f64(lower: f64, upper: f64) -> Self {
+    Self::over_f64(lower, true, upper, true)
+  }
+
+  /// Generate an open interval of `f64` values.
+  ///
+  /// - params
+  ///     - lower: lower limit
+  ///     - upper: upper limit
+  /// - return: an open interval
+  /// - panic
+  ///     - if the lower limit is greater than the upper limit
+  pub fn open_f64(lower: f64, upper: f64) -> Self {
+    Self::over_f64(lower, false, upper, false)
+  }
+}
+
+impl From<Range<f64>> for Interval<OrderedFloat<f64>> {
+  /// Convert the range into a half-open interval, or an empty interval if the range is empty like `1.0..1.0`.
+  fn from(range: Range<f64>) -> Self {
+    let (start, end) = (OrderedFloat(range.start), OrderedFloat(range.end));
+    if start < end {
+      Self::over(
+        LimitValue::Limit(start),
+        true,
+        LimitValue::Limit(end),
+        false,
+      )
+    } else {
+      Self::empty_at(start)
+    }
+  }
+}
+
+impl From<RangeInclusive<f64>> for Interval<OrderedFloat<f64>> {
+  /// Convert the range into a closed interval, or an empty interval if the range is reversed.
+  fn from(range: RangeInclusive<f64>) -> Self {
+    let (start, end) = range.into_inner();
+    (OrderedFloat(start)..=OrderedFloat(end)).to_interval()
+  }
+}
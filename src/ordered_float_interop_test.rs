@@ -0,0 +1,35 @@
+use ordered_float::OrderedFloat;
+
+use crate::{Interval, LimitValue};
+
+#[test]
+fn test01_constructors() {
+  let interval = Interval::closed_f64(0.5, 1.5);
+  assert_eq!(
+    interval,
+    Interval::closed(
+      LimitValue::Limit(OrderedFloat(0.5)),
+      LimitValue::Limit(OrderedFloat(1.5))
+    )
+  );
+  assert!(interval.includes(&LimitValue::Limit(OrderedFloat(1.0))));
+  assert!(!Interval::open_f64(0.5, 1.5).includes(&LimitValue::Limit(OrderedFloat(1.5))));
+  assert_eq!(
+    Interval::over_f64(0.0, true, 1.0, false).length(),
+    Some(OrderedFloat(1.0))
+  );
+  assert!(
+    Interval::closed_f64(f64::NEG_INFINITY, 0.0).includes(&LimitValue::Limit(OrderedFloat(-1e300)))
+  );
+}
+
+#[test]
+fn test02_from_ranges() {
+  assert_eq!(
+    Interval::from(0.5..1.5),
+    Interval::over_f64(0.5, true, 1.5, false)
+  );
+  assert_eq!(Interval::from(0.5..=1.5), Interval::closed_f64(0.5, 1.5));
+  assert!(Interval::from(1.5..0.5).is_empty());
+  assert!(Interval::from(1.0..1.0).is_empty());
+}
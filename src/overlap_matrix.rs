@@ -0,0 +1,72 @@
+/// A structure that represents a dense matrix of pairwise values between the elements of two interval sequences.
+///
+/// The values are stored row by row in a single buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlapMatrix<V> {
+  /// the number of rows
+  rows: usize,
+  /// the number of columns
+  columns: usize,
+  /// values in row-major order
+  values: Vec<V>,
+}
+
+impl<V> OverlapMatrix<V> {
+  /// Generate a matrix by evaluating `f` for every pair of a row and a column.
+  ///
+  /// - params
+  ///     - rows: the number of rows
+  ///     - columns: the number of columns
+  ///     - f: a function that computes the value of the given row and column
+  /// - return: `OverlapMatrix`
+  pub(crate) fn from_fn(rows: usize, columns: usize, mut f: impl FnMut(usize, usize) -> V) -> Self {
+    let mut values = Vec::with_capacity(rows * columns);
+    for row in 0..rows {
+      for column in 0..columns {
+        values.push(f(row, column));
+      }
+    }
+    Self {
+      rows,
+      columns,
+      values,
+    }
+  }
+
+  /// Gets the number of rows, i.e. the elements of the left interval sequence.
+  pub fn rows(&self) -> usize {
+    self.rows
+  }
+
+  /// Gets the number of columns, i.e. the elements of the right interval sequence.
+  pub fn columns(&self) -> usize {
+    self.columns
+  }
+
+  /// Gets the value of the given row and column.
+  ///
+  /// - params
+  ///     - row: a row index
+  ///     - column: a column index
+  /// - return: the value, or `None` if either index is out of bounds
+  pub fn get(&self, row: usize, column: usize) -> Option<&V> {
+    if row < self.rows && column < self.columns {
+      self.values.get(row * self.columns + column)
+    } else {
+      None
+    }
+  }
+
+  /// Gets the values of the given row.
+  ///
+  /// - params
+  ///     - row: a row index
+  /// - return: the values, or `None` if the index is out of bounds
+  pub fn row(&self, row: usize) -> Option<&[V]> {
+    if row < self.rows {
+      Some(&self.values[row * self.columns..(row + 1) * self.columns])
+    } else {
+      None
+    }
+  }
+}
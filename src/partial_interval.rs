@@ -0,0 +1,325 @@
+use std::cmp::Ordering;
+use std::ops::Sub;
+
+use crate::{Error, LimitValue};
+
+/// An interval of values that are only partially ordered, such as `f32` and `f64`.
+///
+/// `Interval` requires `T: Ord`, so this type offers the basic operations for `T: PartialOrd` instead.
+/// A value that is not comparable with itself, such as NaN, is rejected as a limit and never included.
+/// Apart from such values, the values are assumed to be comparable with each other.
+/// With the `ordered-float` feature, `Interval<OrderedFloat<f64>>` offers the full API of `Interval` instead.
+///
+/// Like `Interval`, an interval whose limits have the same value and only one of them is open
+/// is normalized to a single-element interval.
+#[derive(Debug, Clone)]
+pub struct PartialInterval<T> {
+  lower: LimitValue<T>,
+  lower_closed: bool,
+  upper: LimitValue<T>,
+  upper_closed: bool,
+}
+
+impl<T: Clone + PartialOrd> PartialEq for PartialInterval<T> {
+  /// Verify the identity of this interval and the given interval `other`.
+  ///
+  /// Like `Interval`, all the empty intervals are identical.
+  fn eq(&self, other: &Self) -> bool {
+    if self.is_empty() || other.is_empty() {
+      self.is_empty() && other.is_empty()
+    } else {
+      self.compare_lower(other) == Ordering::Equal && self.compare_upper(other) == Ordering::Equal
+    }
+  }
+}
+
+impl<T: Clone + PartialOrd> PartialInterval<T> {
+  /// Generate an interval.
+  ///
+  /// - params
+  ///     - lower: lower limit, Limitless means there is no limit.
+  ///     - lower_included: specify `true` if the lower limit is included in the interval (closed lower limit).
+  ///     - upper: upper limit, Limitless means there is no limit.
+  ///     - upper_included: specify `true` if the upper limit is included in the interval (closed upper limit)
+  /// - return: an interval
+  /// - panic
+  ///     - if a limit is not comparable with itself, such as NaN
+  ///     - if the lower limit is greater than the upper limit
+  pub fn over(
+    lower: LimitValue<T>,
+    lower_included: bool,
+    upper: LimitValue<T>,
+    upper_included: bool,
+  ) -> Self {
+    match Self::try_over(lower, lower_included, upper, upper_included) {
+      Ok(interval) => interval,
+      Err(Error::Incomparable) => panic!("a limit is not comparable with itself"),
+      Err(_) => panic!("the lower limit is not before or equal to the upper limit"),
+    }
+  }
+
+  /// Generate an interval, like `over` but without panicking.
+  ///
+  /// - params
+  ///     - lower: lower limit, Limitless means there is no limit.
+  ///     - lower_included: specify `true` if the lower limit is included in the interval (closed lower limit).
+  ///     - upper: upper limit, Limitless means there is no limit.
+  ///     - upper_included: specify `true` if the upper limit is included in the interval (closed upper limit)
+  /// - return: an interval, `Err(Error::Incomparable)` if a limit is not comparable with itself,
+  ///   or `Err(Error::UpperBelowLower)` if the upper limit is below the lower limit
+  pub fn try_over(
+    lower: LimitValue<T>,
+    lower_included: bool,
+    upper: LimitValue<T>,
+    upper_included: bool,
+  ) -> Result<Self, Error> {
+    let comparable = |limit: &LimitValue<T>| match limit {
+      LimitValue::Limit(value) => is_comparable(value),
+      LimitValue::Limitless => true,
+    };
+    if !comparable(&lower) || !comparable(&upper) {
+      return Err(Error::Incomparable);
+    }
+    let mut lower_closed = lower.is_limit() && lower_included;
+    let mut upper_closed = upper.is_limit() && upper_included;
+    if let (LimitValue::Limit(l), LimitValue::Limit(u)) = (&lower, &upper) {
+      match l.partial_cmp(u) {
+        Some(Ordering::Greater) => return Err(Error::UpperBelowLower),
+        Some(Ordering::Equal) if lower_closed ^ upper_closed => {
+          lower_closed = true;
+          upper_closed = true;
+        }
+        _ => {}
+      }
+    }
+    Ok(Self {
+      lower,
+      lower_closed,
+      upper,
+      upper_closed,
+    })
+  }
+
+  /// Generate a closed interval.
+  ///
+  /// - params
+  ///     - lower: lower limit, Limitless means there is no limit.
+  ///     - upper: upper limit, Limitless means there is no limit.
+  /// - return: a closed interval
+  /// - panic
+  ///     - if a limit is not comparable with itself, such as NaN
+  ///     - if the lower limit is greater than the upper limit
+  pub fn closed(lower: LimitValue<T>, upper: LimitValue<T>) -> Self {
+    Self::over(lower, true, upper, true)
+  }
+
+  /// Generate a closed interval, like `closed` but without panicking.
+  ///
+  /// - params
+  ///     - lower: lower limit, Limitless means there is no limit.
+  ///     - upper: upper limit, Limitless means there is no limit.
+  /// - return: a closed interval, or an error like `try_over`
+  pub fn try_closed(lower: LimitValue<T>, upper: LimitValue<T>) -> Result<Self, Error> {
+    Self::try_over(lower, true, upper, true)
+  }
+
+  /// Generate an open interval.
+  ///
+  /// - params
+  ///     - lower: lower limit, Limitless means there is no limit.
+  ///     - upper: upper limit, Limitless means there is no limit.
+  /// - return: an open interval
+  /// - panic
+  ///     - if a limit is not comparable with itself, such as NaN
+  ///     - if the lower limit is greater than the upper limit
+  pub fn open(lower: LimitValue<T>, upper: LimitValue<T>) -> Self {
+    Self::over(lower, false, upper, false)
+  }
+
+  /// Generate an open interval, like `open` but without panicking.
+  ///
+  /// - params
+  ///     - lower: lower limit, Limitless means there is no limit.
+  ///     - upper: upper limit, Limitless means there is no limit.
+  /// - return: an open interval, or an error like `try_over`
+  pub fn try_open(lower: LimitValue<T>, upper: LimitValue<T>) -> Result<Self, Error> {
+    Self::try_over(lower, false, upper, false)
+  }
+
+  /// Gets the lower limit.
+  pub fn as_lower_limit(&self) -> &LimitValue<T> {
+    &self.lower
+  }
+
+  /// Gets the upper limit.
+  pub fn as_upper_limit(&self) -> &LimitValue<T> {
+    &self.upper
+  }
+
+  /// Verify whether the lower limit is included (closed).
+  pub fn includes_lower_limit(&self) -> bool {
+    self.lower_closed
+  }
+
+  /// Verify whether the upper limit is included (closed).
+  pub fn includes_upper_limit(&self) -> bool {
+    self.upper_closed
+  }
+
+  /// Verify whether this interval is empty, i.e. open at the same value on both sides.
+  pub fn is_empty(&self) -> bool {
+    match (&self.lower, &self.upper) {
+      (LimitValue::Limit(l), LimitValue::Limit(u)) => {
+        l.partial_cmp(u) == Some(Ordering::Equal) && !self.lower_closed && !self.upper_closed
+      }
+      _ => false,
+    }
+  }
+
+  /// Verify whether the specified value `value` is included in this interval.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `true` if included, `false` otherwise, including when `value` is not comparable with itself
+  pub fn includes(&self, value: &T) -> bool {
+    let above_lower = match &self.lower {
+      LimitValue::Limit(l) if self.lower_closed => l <= value,
+      LimitValue::Limit(l) => l < value,
+      LimitValue::Limitless => true,
+    };
+    let below_upper = match &self.upper {
+      LimitValue::Limit(u) if self.upper_closed => value <= u,
+      LimitValue::Limit(u) => value < u,
+      LimitValue::Limitless => true,
+    };
+    is_comparable(value) && above_lower && below_upper
+  }
+
+  /// Verify whether this interval completely encloses the given interval `other`.
+  ///
+  /// - params
+  ///     - other: an interval to be compared
+  /// - return: `true` if `other` is empty or enclosed, `false` otherwise
+  pub fn covers(&self, other: &Self) -> bool {
+    other.is_empty()
+      || (self.compare_lower(other) != Ordering::Greater
+        && self.compare_upper(other) != Ordering::Less)
+  }
+
+  /// Verify whether there is a common part between this interval and the given interval `other`.
+  ///
+  /// - params
+  ///     - other: an interval to be compared
+  /// - return: `true` if they have a common part, `false` otherwise
+  pub fn intersects(&self, other: &Self) -> bool {
+    !self.intersect(other).is_empty()
+  }
+
+  /// Return the common part of this interval and the given interval `other`.
+  ///
+  /// - params
+  ///     - other: an interval
+  /// - return: the common part, or an empty interval if there is none
+  pub fn intersect(&self, other: &Self) -> Self {
+    if self.is_empty() {
+      return self.clone();
+    }
+    if other.is_empty() {
+      return other.clone();
+    }
+    let lower = if self.compare_lower(other) == Ordering::Less {
+      other
+    } else {
+      self
+    };
+    let upper = if self.compare_upper(other) == Ordering::Greater {
+      other
+    } else {
+      self
+    };
+    let overlapping = match (&lower.lower, &upper.upper) {
+      (LimitValue::Limit(l), LimitValue::Limit(u)) => match l.partial_cmp(u) {
+        Some(Ordering::Less) => true,
+        Some(Ordering::Equal) => lower.lower_closed && upper.upper_closed,
+        _ => false,
+      },
+      _ => true,
+    };
+    if overlapping {
+      Self {
+        lower: lower.lower.clone(),
+        lower_closed: lower.lower_closed,
+        upper: upper.upper.clone(),
+        upper_closed: upper.upper_closed,
+      }
+    } else {
+      Self {
+        lower: lower.lower.clone(),
+        lower_closed: false,
+        upper: lower.lower.clone(),
+        upper_closed: false,
+      }
+    }
+  }
+
+  /// Get the length of this interval, i.e. the upper limit minus the lower limit.
+  ///
+  /// - return: the length, or `None` if either limit is Limitless
+  pub fn length<D>(&self) -> Option<D>
+  where
+    T: Sub<Output = D>,
+  {
+    match (&self.lower, &self.upper) {
+      (LimitValue::Limit(l), LimitValue::Limit(u)) => Some(u.clone() - l.clone()),
+      _ => None,
+    }
+  }
+
+  fn compare_lower(&self, other: &Self) -> Ordering {
+    compare_limits(
+      (&self.lower, self.lower_closed),
+      (&other.lower, other.lower_closed),
+      true,
+    )
+  }
+
+  fn compare_upper(&self, other: &Self) -> Ordering {
+    compare_limits(
+      (&self.upper, self.upper_closed),
+      (&other.upper, other.upper_closed),
+      false,
+    )
+  }
+}
+
+fn is_comparable<T: PartialOrd>(value: &T) -> bool {
+  value.partial_cmp(value).is_some()
+}
+
+/// Compare two lower limits or two upper limits in ascending order of their positions.
+///
+/// Values that are not comparable with each other are treated as equal.
+fn compare_limits<T: PartialOrd>(
+  a: (&LimitValue<T>, bool),
+  b: (&LimitValue<T>, bool),
+  lower: bool,
+) -> Ordering {
+  let limitless = if lower {
+    Ordering::Less
+  } else {
+    Ordering::Greater
+  };
+  match (a.0, b.0) {
+    (LimitValue::Limitless, LimitValue::Limitless) => Ordering::Equal,
+    (LimitValue::Limitless, _) => limitless,
+    (_, LimitValue::Limitless) => limitless.reverse(),
+    (LimitValue::Limit(x), LimitValue::Limit(y)) => {
+      let closedness = match (a.1, b.1) {
+        (true, false) => limitless,
+        (false, true) => limitless.reverse(),
+        _ => Ordering::Equal,
+      };
+      x.partial_cmp(y).unwrap_or(Ordering::Equal).then(closedness)
+    }
+  }
+}
@@ -0,0 +1,92 @@
+use crate::{Error, LimitValue, PartialInterval};
+
+fn closed(lower: f64, upper: f64) -> PartialInterval<f64> {
+  PartialInterval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+fn over(
+  lower: f64,
+  lower_included: bool,
+  upper: f64,
+  upper_included: bool,
+) -> PartialInterval<f64> {
+  PartialInterval::over(
+    LimitValue::Limit(lower),
+    lower_included,
+    LimitValue::Limit(upper),
+    upper_included,
+  )
+}
+
+#[test]
+fn test01_constructors() {
+  let interval = closed(0.5, 1.5);
+  assert_eq!(interval.as_lower_limit(), &LimitValue::Limit(0.5));
+  assert!(interval.includes_lower_limit() && interval.includes_upper_limit());
+  assert_eq!(interval.length(), Some(1.0));
+  assert_eq!(over(1.0, true, 1.0, false), closed(1.0, 1.0));
+  assert!(PartialInterval::open(LimitValue::Limit(1.0), LimitValue::Limit(1.0)).is_empty());
+  assert_eq!(
+    PartialInterval::open(LimitValue::Limit(1.0), LimitValue::Limit(1.0)),
+    PartialInterval::open(LimitValue::Limit(2.0), LimitValue::Limit(2.0))
+  );
+  let unbounded = PartialInterval::<f64>::closed(LimitValue::Limitless, LimitValue::Limitless);
+  assert!(!unbounded.includes_lower_limit());
+  assert_eq!(unbounded.length::<f64>(), None);
+}
+
+#[test]
+fn test02_invalid_limits() {
+  assert!(matches!(
+    PartialInterval::try_closed(LimitValue::Limit(f64::NAN), LimitValue::Limit(1.0)),
+    Err(Error::Incomparable)
+  ));
+  assert!(matches!(
+    PartialInterval::try_open(LimitValue::Limitless, LimitValue::Limit(f32::NAN)),
+    Err(Error::Incomparable)
+  ));
+  assert!(matches!(
+    PartialInterval::try_closed(LimitValue::Limit(2.0), LimitValue::Limit(1.0)),
+    Err(Error::UpperBelowLower)
+  ));
+}
+
+#[test]
+#[should_panic(expected = "a limit is not comparable with itself")]
+fn test03_nan_limit_panics() {
+  closed(0.0, f64::NAN);
+}
+
+#[test]
+fn test04_includes() {
+  let interval = over(0.0, false, 1.0, true);
+  assert!(!interval.includes(&0.0));
+  assert!(interval.includes(&0.5));
+  assert!(interval.includes(&1.0));
+  assert!(!interval.includes(&1.5));
+  assert!(!interval.includes(&f64::NAN));
+  let all = PartialInterval::<f64>::open(LimitValue::Limitless, LimitValue::Limitless);
+  assert!(all.includes(&f64::INFINITY));
+  assert!(!all.includes(&f64::NAN));
+}
+
+#[test]
+fn test05_intersect_and_covers() {
+  let a = closed(0.0, 2.0);
+  let b = over(1.0, false, 3.0, true);
+  assert_eq!(a.intersect(&b), over(1.0, false, 2.0, true));
+  assert!(a.intersects(&b));
+  assert_eq!(a.intersect(&closed(2.0, 3.0)), closed(2.0, 2.0));
+  assert!(!a.intersects(&over(2.0, false, 3.0, true)));
+  assert!(!over(0.0, true, 1.0, false).intersects(&closed(1.0, 2.0)));
+  assert!(a.intersect(&closed(5.0, 6.0)).is_empty());
+
+  assert!(a.covers(&closed(0.5, 1.5)));
+  assert!(a.covers(&over(0.0, false, 2.0, false)));
+  assert!(!over(0.0, false, 2.0, true).covers(&a));
+  assert!(!a.covers(&b));
+  assert!(
+    PartialInterval::<f64>::closed(LimitValue::Limitless, LimitValue::Limit(0.0))
+      .covers(&closed(-1.0, 0.0))
+  );
+}
@@ -0,0 +1,42 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::{Interval, LimitValue};
+
+/// The position of a value relative to an interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointPosition {
+  /// the value is less than the lower limit
+  Below,
+  /// the value is equal to the lower limit, which may be open
+  OnLowerBound,
+  /// the value is between the limits
+  Within,
+  /// the value is equal to the upper limit, which may be open
+  OnUpperBound,
+  /// the value is greater than the upper limit
+  Above,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Interval<T> {
+  /// Classify the position of the specified value `value` relative to this interval.
+  ///
+  /// A value on a limit is reported as `OnLowerBound` or `OnUpperBound` whether the limit is closed or not,
+  /// so that an excluded limit can be told apart from a value out of range.
+  /// For an interval whose limits are equal, `OnLowerBound` takes precedence.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `PointPosition`
+  pub fn position_of(&self, value: &T) -> PointPosition {
+    let on = |limit: &LimitValue<T>| limit.as_value().ok().map(|e| e.cmp(value));
+    match (on(self.as_lower_limit()), on(self.as_upper_limit())) {
+      (Some(Ordering::Greater), _) => PointPosition::Below,
+      (Some(Ordering::Equal), _) => PointPosition::OnLowerBound,
+      (_, Some(Ordering::Less)) => PointPosition::Above,
+      (_, Some(Ordering::Equal)) => PointPosition::OnUpperBound,
+      _ => PointPosition::Within,
+    }
+  }
+}
@@ -0,0 +1,24 @@
+use crate::{Interval, LimitValue, PointPosition};
+
+#[test]
+fn test01_position_of() {
+  let interval = Interval::over(LimitValue::Limit(10), false, LimitValue::Limit(20), true);
+  assert_eq!(interval.position_of(&5), PointPosition::Below);
+  assert_eq!(interval.position_of(&10), PointPosition::OnLowerBound);
+  assert_eq!(interval.position_of(&15), PointPosition::Within);
+  assert_eq!(interval.position_of(&20), PointPosition::OnUpperBound);
+  assert_eq!(interval.position_of(&25), PointPosition::Above);
+}
+
+#[test]
+fn test02_position_of_special_intervals() {
+  let all = Interval::<i32>::open(LimitValue::Limitless, LimitValue::Limitless);
+  assert_eq!(all.position_of(&i32::MIN), PointPosition::Within);
+  let up_to = Interval::up_to(LimitValue::Limit(0));
+  assert_eq!(up_to.position_of(&-100), PointPosition::Within);
+  assert_eq!(up_to.position_of(&0), PointPosition::OnUpperBound);
+  assert_eq!(up_to.position_of(&1), PointPosition::Above);
+  let single = Interval::single(3);
+  assert_eq!(single.position_of(&3), PointPosition::OnLowerBound);
+  assert_eq!(single.position_of(&4), PointPosition::Above);
+}
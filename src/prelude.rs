@@ -0,0 +1,15 @@
+//! A prelude that re-exports the commonly used types and traits.
+//!
+//! ```
+//! use intervals_rs::prelude::*;
+//!
+//! let interval: IntInterval = Interval::closed(LimitValue::Limit(1), LimitValue::Limit(10));
+//! let seq = IntervalSeq::new(vec![interval]);
+//! assert_eq!(seq.len(), 1);
+//! ```
+#[cfg(feature = "chrono")]
+pub use crate::DateInterval;
+pub use crate::{
+  Discrete, DiscretizePolicy, EmptyPolicy, Encode, Error, IntInterval, Interval, IntervalLike,
+  IntervalLimit, IntervalMap, IntervalRelation, IntervalSeq, LimitValue, RangeSet, SortSpec, Span,
+};
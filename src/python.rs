@@ -0,0 +1,231 @@
+//! Python bindings of `Interval` and `IntervalSeq` over 64-bit integers.
+//!
+//! Register the classes to an extension module with [`register`].
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{Interval, IntervalNotation, IntervalSeq, LazyIntervalSeq, LimitValue};
+
+/// An interval of 64-bit integers, exposed to Python as `Interval`.
+#[pyclass(name = "Interval", frozen, skip_from_py_object)]
+#[derive(Clone)]
+pub struct PyInterval {
+  inner: Interval<i64>,
+}
+
+/// A sequence of intervals of 64-bit integers, exposed to Python as `IntervalSeq`.
+#[pyclass(name = "IntervalSeq")]
+pub struct PyIntervalSeq {
+  inner: IntervalSeq<i64>,
+}
+
+fn bound_of(value: Option<i64>, closed: bool) -> std::ops::Bound<i64> {
+  match value {
+    Some(value) if closed => std::ops::Bound::Included(value),
+    Some(value) => std::ops::Bound::Excluded(value),
+    None => std::ops::Bound::Unbounded,
+  }
+}
+
+#[pymethods]
+impl PyInterval {
+  /// Create an interval. `None` means that there is no limit.
+  #[new]
+  #[pyo3(signature = (lower, upper, lower_closed = true, upper_closed = true))]
+  fn new(
+    lower: Option<i64>,
+    upper: Option<i64>,
+    lower_closed: bool,
+    upper_closed: bool,
+  ) -> PyResult<Self> {
+    Interval::from_bounds(bound_of(lower, lower_closed), bound_of(upper, upper_closed))
+      .map(|inner| Self { inner })
+      .map_err(|_| PyValueError::new_err("the upper limit is below the lower limit"))
+  }
+
+  #[getter]
+  fn lower(&self) -> Option<i64> {
+    self.inner.lower_value().copied()
+  }
+
+  #[getter]
+  fn upper(&self) -> Option<i64> {
+    self.inner.upper_value().copied()
+  }
+
+  #[getter]
+  fn lower_closed(&self) -> bool {
+    self.inner.includes_lower_limit()
+  }
+
+  #[getter]
+  fn upper_closed(&self) -> bool {
+    self.inner.includes_upper_limit()
+  }
+
+  fn is_empty(&self) -> bool {
+    self.inner.is_empty()
+  }
+
+  fn is_single_element(&self) -> bool {
+    self.inner.is_single_element()
+  }
+
+  fn length(&self) -> Option<i64> {
+    self.inner.length()
+  }
+
+  fn includes(&self, value: i64) -> bool {
+    self.inner.includes(&LimitValue::Limit(value))
+  }
+
+  fn covers(&self, other: &Self) -> bool {
+    self.inner.covers(&other.inner)
+  }
+
+  fn intersects(&self, other: &Self) -> bool {
+    self.inner.intersects(&other.inner)
+  }
+
+  fn intersect(&self, other: &Self) -> Self {
+    Self {
+      inner: self.inner.intersect(&other.inner),
+    }
+  }
+
+  fn gap(&self, other: &Self) -> Self {
+    Self {
+      inner: self.inner.gap(&other.inner),
+    }
+  }
+
+  fn __contains__(&self, value: i64) -> bool {
+    self.includes(value)
+  }
+
+  fn __and__(&self, other: &Self) -> Self {
+    self.intersect(other)
+  }
+
+  fn __or__(&self, other: &Self) -> PyIntervalSeq {
+    PyIntervalSeq {
+      inner: IntervalSeq::new(vec![self.inner.clone(), other.inner.clone()]).coalesce(),
+    }
+  }
+
+  fn __eq__(&self, other: &Self) -> bool {
+    self.inner == other.inner
+  }
+
+  fn __repr__(&self) -> String {
+    format!(
+      "Interval({})",
+      self.inner.format_with(IntervalNotation::Standard)
+    )
+  }
+
+  fn __str__(&self) -> String {
+    self.inner.format_with(IntervalNotation::Standard)
+  }
+}
+
+#[pymethods]
+impl PyIntervalSeq {
+  /// Create an interval sequence from intervals.
+  #[new]
+  #[pyo3(signature = (intervals = Vec::new()))]
+  fn new(intervals: Vec<PyRef<'_, PyInterval>>) -> Self {
+    Self {
+      inner: IntervalSeq::new(intervals.iter().map(|e| e.inner.clone())),
+    }
+  }
+
+  fn append(&mut self, interval: &PyInterval) {
+    self.inner.append(&interval.inner);
+  }
+
+  fn is_empty(&self) -> bool {
+    self.inner.is_empty()
+  }
+
+  /// Return the intervals in the order of the sequence.
+  fn intervals(&mut self) -> Vec<PyInterval> {
+    self
+      .inner
+      .iter()
+      .map(|e| PyInterval { inner: e.clone() })
+      .collect()
+  }
+
+  /// Return the smallest interval that encompasses all the intervals, or `None` if empty.
+  fn extent(&self) -> Option<PyInterval> {
+    if self.inner.is_empty() {
+      None
+    } else {
+      Some(PyInterval {
+        inner: self.inner.extent(),
+      })
+    }
+  }
+
+  /// Return a new sequence where overlapping or adjacent intervals are merged.
+  fn coalesce(&self) -> Self {
+    Self {
+      inner: self.inner.coalesce(),
+    }
+  }
+
+  fn __len__(&self) -> usize {
+    self.inner.len()
+  }
+
+  fn __contains__(&self, value: i64) -> bool {
+    self
+      .inner
+      .as_slice()
+      .iter()
+      .any(|e| e.includes(&LimitValue::Limit(value)))
+  }
+
+  fn __and__(&self, other: &Self) -> Self {
+    Self {
+      inner: LazyIntervalSeq::new(self.inner.coalesced_intervals())
+        .intersect(other.inner.coalesced_intervals())
+        .to_seq(),
+    }
+  }
+
+  fn __or__(&self, other: &Self) -> Self {
+    Self {
+      inner: IntervalSeq::new(
+        self
+          .inner
+          .coalesced_intervals()
+          .into_iter()
+          .chain(other.inner.coalesced_intervals()),
+      )
+      .coalesce(),
+    }
+  }
+
+  fn __repr__(&self) -> String {
+    let intervals = self
+      .inner
+      .as_slice()
+      .iter()
+      .map(|e| e.format_with(IntervalNotation::Standard))
+      .collect::<Vec<String>>();
+    format!("IntervalSeq([{}])", intervals.join(", "))
+  }
+}
+
+/// Add the `Interval` and `IntervalSeq` classes to the given Python module.
+///
+/// - params
+///     - module: an extension module
+/// - return: `Err` if a class could not be added
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+  module.add_class::<PyInterval>()?;
+  module.add_class::<PyIntervalSeq>()?;
+  Ok(())
+}
@@ -0,0 +1,59 @@
+use pyo3::prelude::*;
+use pyo3::py_run;
+use pyo3::types::PyDict;
+
+use crate::python::{PyInterval, PyIntervalSeq};
+
+fn run(code: &str) {
+  Python::initialize();
+  Python::attach(|py| {
+    let locals = PyDict::new(py);
+    locals
+      .set_item("Interval", py.get_type::<PyInterval>())
+      .unwrap();
+    locals
+      .set_item("IntervalSeq", py.get_type::<PyIntervalSeq>())
+      .unwrap();
+    py_run!(py, *locals, code);
+  });
+}
+
+#[test]
+fn test01_interval() {
+  run(
+    r#"
+a = Interval(1, 10, upper_closed=False)
+b = Interval(5, None)
+assert repr(a) == "Interval([1, 10))"
+assert 1 in a and 10 not in a
+assert a.length() == 9 and b.length() is None
+assert (a & b) == Interval(5, 10, upper_closed=False)
+assert a.intersects(b) and not a.covers(b)
+assert str(Interval(1, 3).gap(Interval(5, 7))) == "(3, 5)"
+assert len(a | Interval(20, 30)) == 2
+try:
+    Interval(3, 1)
+    assert False
+except ValueError:
+    pass
+"#,
+  );
+}
+
+#[test]
+fn test02_interval_seq() {
+  run(
+    r#"
+s = IntervalSeq([Interval(1, 5), Interval(3, 8), Interval(10, 12)])
+assert len(s) == 3 and 7 in s and 9 not in s
+assert repr(s.coalesce()) == "IntervalSeq([[1, 8], [10, 12]])"
+assert str(s.extent()) == "[1, 12]"
+t = IntervalSeq([Interval(4, 11, lower_closed=False)])
+assert repr(s & t) == "IntervalSeq([(4, 8], [10, 11]])"
+assert repr(s | t) == "IntervalSeq([[1, 12]])"
+s.append(Interval(None, 0))
+assert [str(e) for e in s.intervals()][0] == "(-inf, 0]"
+assert IntervalSeq().extent() is None
+"#,
+  );
+}
@@ -0,0 +1,121 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Add, Div, Sub};
+
+use uom::num_traits::Num;
+use uom::si::{Dimension, Quantity, Units};
+use uom::Conversion;
+
+use crate::{Interval, LimitValue};
+
+/// A wrapper that lets a physical quantity of the `uom` crate be used as a limit value of an interval.
+///
+/// The quantity must be stored in a totally ordered type, such as `i32` or `i64`.
+/// It is displayed with its base units, e.g. `20 m^1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QuantityValue<Q>(pub Q);
+
+impl<Q> QuantityValue<Q> {
+  /// Get the wrapped quantity.
+  pub fn into_inner(self) -> Q {
+    self.0
+  }
+}
+
+impl<Q: Debug> Display for QuantityValue<Q> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{:?}", self.0)
+  }
+}
+
+impl<Q: Sub<Output = Q>> Sub for QuantityValue<Q> {
+  type Output = Q;
+
+  fn sub(self, rhs: Self) -> Self::Output {
+    self.0 - rhs.0
+  }
+}
+
+impl<Q: Clone + Eq + Ord> Interval<QuantityValue<Q>> {
+  /// Generate a closed interval of quantities.
+  ///
+  /// - params
+  ///     - lower: lower limit
+  ///     - upper: upper limit
+  /// - return: an interval
+  /// - panic
+  ///     - if `lower` is greater than `upper`
+  pub fn closed_quantity(lower: Q, upper: Q) -> Self {
+    Self::closed(
+      LimitValue::Limit(QuantityValue(lower)),
+      LimitValue::Limit(QuantityValue(upper)),
+    )
+  }
+
+  /// Generate an open interval of quantities.
+  ///
+  /// - params
+  ///     - lower: lower limit
+  ///     - upper: upper limit
+  /// - return: an interval
+  /// - panic
+  ///     - if `lower` is greater than `upper`
+  pub fn open_quantity(lower: Q, upper: Q) -> Self {
+    Self::open(
+      LimitValue::Limit(QuantityValue(lower)),
+      LimitValue::Limit(QuantityValue(upper)),
+    )
+  }
+
+  /// Generate an interval of quantities.
+  ///
+  /// - params
+  ///     - lower: lower limit
+  ///     - lower_included: specify `true` if the lower limit is included in the interval (closed lower limit)
+  ///     - upper: upper limit
+  ///     - upper_included: specify `true` if the upper limit is included in the interval (closed upper limit)
+  /// - return: an interval
+  /// - panic
+  ///     - if `lower` is greater than `upper`
+  pub fn over_quantity(lower: Q, lower_included: bool, upper: Q, upper_included: bool) -> Self {
+    Self::over(
+      LimitValue::Limit(QuantityValue(lower)),
+      lower_included,
+      LimitValue::Limit(QuantityValue(upper)),
+      upper_included,
+    )
+  }
+
+  /// Verify whether the specified quantity `value` is included in this interval.
+  ///
+  /// - params
+  ///     - value: a quantity
+  /// - return: `true` if included, `false` otherwise
+  pub fn includes_quantity(&self, value: Q) -> bool {
+    self.includes(&LimitValue::Limit(QuantityValue(value)))
+  }
+}
+
+impl<D, U, V> Interval<QuantityValue<Quantity<D, U, V>>>
+where
+  D: Dimension + ?Sized,
+  U: Units<V> + ?Sized,
+  V: Num + Conversion<V> + Clone + Eq + Ord,
+  Quantity<D, U, V>: Add<Output = Quantity<D, U, V>>
+    + Sub<Output = Quantity<D, U, V>>
+    + Div<V, Output = Quantity<D, U, V>>,
+{
+  /// Get the midpoint of this interval, in the units of the quantity.
+  ///
+  /// For integer storage types, the result is rounded toward the lower limit.
+  ///
+  /// - return: the midpoint, or `None` if either limit is Limitless
+  pub fn midpoint(&self) -> Option<Quantity<D, U, V>> {
+    match (self.as_lower_limit(), self.as_upper_limit()) {
+      (LimitValue::Limit(lower), LimitValue::Limit(upper)) => {
+        Some(lower.0.clone() + (upper.0.clone() - lower.0.clone()) / (V::one() + V::one()))
+      }
+      _ => None,
+    }
+  }
+}
@@ -0,0 +1,39 @@
+use uom::si::i64::{Length, ThermodynamicTemperature};
+use uom::si::length::{kilometer, meter};
+use uom::si::thermodynamic_temperature::kelvin;
+
+use crate::{Interval, QuantityValue};
+
+#[test]
+fn test01_length_and_midpoint() {
+  let interval = Interval::closed_quantity(Length::new::<meter>(500), Length::new::<kilometer>(2));
+  assert_eq!(interval.length(), Some(Length::new::<meter>(1500)));
+  assert_eq!(interval.midpoint(), Some(Length::new::<meter>(1250)));
+  assert!(interval.includes_quantity(Length::new::<kilometer>(1)));
+  assert!(!interval.includes_quantity(Length::new::<kilometer>(3)));
+}
+
+#[test]
+fn test02_interval_algebra() {
+  let operating = Interval::closed_quantity(
+    ThermodynamicTemperature::new::<kelvin>(273),
+    ThermodynamicTemperature::new::<kelvin>(353),
+  );
+  let safe = Interval::open_quantity(
+    ThermodynamicTemperature::new::<kelvin>(300),
+    ThermodynamicTemperature::new::<kelvin>(400),
+  );
+  assert_eq!(
+    operating.intersect(&safe),
+    Interval::over_quantity(
+      ThermodynamicTemperature::new::<kelvin>(300),
+      false,
+      ThermodynamicTemperature::new::<kelvin>(353),
+      true
+    )
+  );
+  assert_eq!(
+    QuantityValue(ThermodynamicTemperature::new::<kelvin>(300)).to_string(),
+    "300 K^1"
+  );
+}
@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use crate::discrete::closed_run_of;
+use crate::invariants;
+use crate::{Discrete, Interval, IntervalSeq, LimitValue};
+
+/// A structure that represents a set of discrete values stored compactly as maximal runs.
+///
+/// Each run is a closed interval `[start, end]`, and runs never overlap or touch each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T: Discrete + Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// runs keyed by their starts
+  runs: BTreeMap<T, T>,
+}
+
+impl<T: Discrete + Clone + Eq + Ord + PartialEq + PartialOrd> Default for RangeSet<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: Discrete + Clone + Eq + Ord + PartialEq + PartialOrd> RangeSet<T> {
+  /// Generate an empty set.
+  ///
+  /// - return: `RangeSet`
+  pub fn new() -> Self {
+    Self {
+      runs: BTreeMap::new(),
+    }
+  }
+
+  /// Return whether this set is empty.
+  pub fn is_empty(&self) -> bool {
+    self.runs.is_empty()
+  }
+
+  /// Gets the number of runs in this set.
+  pub fn run_count(&self) -> usize {
+    self.runs.len()
+  }
+
+  /// Verify whether the specified value `value` is included in this set.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `true` if included, `false` otherwise
+  pub fn contains(&self, value: &T) -> bool {
+    self
+      .runs
+      .range(..=value.clone())
+      .next_back()
+      .is_some_and(|(_, end)| end >= value)
+  }
+
+  /// Add a value to this set.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `true` if the value was not included yet, `false` otherwise
+  pub fn insert(&mut self, value: T) -> bool {
+    if self.contains(&value) {
+      false
+    } else {
+      self.insert_run(value.clone(), value);
+      true
+    }
+  }
+
+  /// Remove a value from this set.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `true` if the value was included, `false` otherwise
+  pub fn remove(&mut self, value: &T) -> bool {
+    if self.contains(value) {
+      self.remove_run(value.clone(), value.clone());
+      true
+    } else {
+      false
+    }
+  }
+
+  /// Add all the values included in the given interval `interval` to this set.
+  ///
+  /// - params
+  ///     - interval: an interval
+  pub fn insert_range(&mut self, interval: &Interval<T>) {
+    if let Some((start, end)) = closed_run_of(interval) {
+      self.insert_run(start, end);
+    }
+  }
+
+  /// Remove all the values included in the given interval `interval` from this set.
+  ///
+  /// - params
+  ///     - interval: an interval
+  pub fn remove_range(&mut self, interval: &Interval<T>) {
+    if let Some((start, end)) = closed_run_of(interval) {
+      self.remove_run(start, end);
+    }
+  }
+
+  /// Gets an iterator of the runs of this set as closed intervals, in ascending order.
+  pub fn iter(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+    self.runs.iter().map(|(start, end)| {
+      Interval::closed(
+        LimitValue::Limit(start.clone()),
+        LimitValue::Limit(end.clone()),
+      )
+    })
+  }
+
+  /// Gets an iterator of the values of this set, in ascending order.
+  pub fn values(&self) -> impl Iterator<Item = T> + '_ {
+    self.runs.iter().flat_map(|(start, end)| {
+      let end = end.clone();
+      std::iter::successors(
+        Some(start.clone()),
+        move |e| {
+          if *e < end {
+            e.succ()
+          } else {
+            None
+          }
+        },
+      )
+    })
+  }
+
+  /// Return the union of this set and the given set `other`.
+  pub fn union(&self, other: &Self) -> Self {
+    let mut result = self.clone();
+    for (start, end) in other.runs.iter() {
+      result.insert_run(start.clone(), end.clone());
+    }
+    result
+  }
+
+  /// Return the difference of this set and the given set `other` (the values of this set not included in `other`).
+  pub fn difference(&self, other: &Self) -> Self {
+    let mut result = self.clone();
+    for (start, end) in other.runs.iter() {
+      result.remove_run(start.clone(), end.clone());
+    }
+    result
+  }
+
+  /// Return the intersection of this set and the given set `other`.
+  pub fn intersection(&self, other: &Self) -> Self {
+    self.difference(&self.difference(other))
+  }
+
+  /// Return the runs of this set as an interval sequence.
+  pub fn to_interval_seq(&self) -> IntervalSeq<T> {
+    IntervalSeq::new(self.iter())
+  }
+
+  fn insert_run(&mut self, start: T, end: T) {
+    let mut start = start;
+    let mut end = end;
+    let adjacent_start = start.pred().unwrap_or_else(|| start.clone());
+    let previous = self
+      .runs
+      .range(..=start.clone())
+      .next_back()
+      .map(|(s, e)| (s.clone(), e.clone()));
+    if let Some((previous_start, previous_end)) = previous {
+      if previous_end >= adjacent_start {
+        self.runs.remove(&previous_start);
+        start = previous_start;
+        if previous_end > end {
+          end = previous_end;
+        }
+      }
+    }
+    let adjacent_end = end.succ().unwrap_or_else(|| end.clone());
+    let followings = self
+      .runs
+      .range(start.clone()..=adjacent_end)
+      .map(|(s, _)| s.clone())
+      .collect::<Vec<T>>();
+    for following_start in followings {
+      let following_end = self.runs.remove(&following_start).unwrap();
+      if following_end > end {
+        end = following_end;
+      }
+    }
+    self.runs.insert(start, end);
+    self.check_invariants();
+  }
+
+  fn remove_run(&mut self, start: T, end: T) {
+    let mut overlapped = self
+      .runs
+      .range(..start.clone())
+      .next_back()
+      .filter(|(_, e)| **e >= start)
+      .map(|(s, e)| (s.clone(), e.clone()))
+      .into_iter()
+      .collect::<Vec<(T, T)>>();
+    overlapped.extend(
+      self
+        .runs
+        .range(start.clone()..=end.clone())
+        .map(|(s, e)| (s.clone(), e.clone())),
+    );
+    for (run_start, run_end) in overlapped {
+      self.runs.remove(&run_start);
+      if run_start < start {
+        self.runs.insert(run_start, start.pred().unwrap());
+      }
+      if run_end > end {
+        self.runs.insert(end.succ().unwrap(), run_end);
+      }
+    }
+    self.check_invariants();
+  }
+
+  fn check_invariants(&self) {
+    invariants::check("RangeSet", "runs have the start not above the end", || {
+      self.runs.iter().all(|(start, end)| start <= end)
+    });
+    invariants::check("RangeSet", "runs neither overlap nor touch", || {
+      self
+        .runs
+        .iter()
+        .zip(self.runs.keys().skip(1))
+        .all(|((_, end), next_start)| end.succ().is_some_and(|e| e < *next_start))
+    });
+  }
+}
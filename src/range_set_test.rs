@@ -0,0 +1,91 @@
+use crate::{Interval, LimitValue, RangeSet};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_insert_and_remove() {
+  let mut set = RangeSet::new();
+  assert!(set.insert(3));
+  assert!(set.insert(5));
+  assert!(!set.insert(5));
+  assert_eq!(set.run_count(), 2);
+  assert!(set.insert(4));
+  assert_eq!(set.run_count(), 1);
+  assert_eq!(set.iter().collect::<Vec<_>>(), vec![closed(3, 5)]);
+
+  assert!(set.remove(&4));
+  assert!(!set.remove(&4));
+  assert_eq!(
+    set.iter().collect::<Vec<_>>(),
+    vec![closed(3, 3), closed(5, 5)]
+  );
+  assert!(set.contains(&3));
+  assert!(!set.contains(&4));
+  assert!(set.contains(&5));
+  assert!(!set.contains(&6));
+}
+
+#[test]
+fn test02_insert_range() {
+  let mut set = RangeSet::new();
+  set.insert_range(&closed(10, 20));
+  set.insert_range(&Interval::open(
+    LimitValue::Limit(20),
+    LimitValue::Limit(25),
+  ));
+  set.insert_range(&closed(0, 5));
+  set.insert_range(&Interval::open(LimitValue::Limit(3), LimitValue::Limit(4)));
+  assert_eq!(
+    set.iter().collect::<Vec<_>>(),
+    vec![closed(0, 5), closed(10, 24)]
+  );
+  set.insert_range(&closed(6, 9));
+  assert_eq!(set.iter().collect::<Vec<_>>(), vec![closed(0, 24)]);
+
+  set.remove_range(&Interval::open(LimitValue::Limit(2), LimitValue::Limit(8)));
+  assert_eq!(
+    set.iter().collect::<Vec<_>>(),
+    vec![closed(0, 2), closed(8, 24)]
+  );
+  assert_eq!(set.values().take(4).collect::<Vec<_>>(), vec![0, 1, 2, 8]);
+}
+
+#[test]
+fn test03_unbounded_range() {
+  let mut set: RangeSet<u8> = RangeSet::new();
+  set.insert_range(&Interval::and_more(LimitValue::Limit(250)));
+  assert!(set.contains(&255));
+  assert!(set.insert(249));
+  assert_eq!(
+    set.iter().collect::<Vec<_>>(),
+    vec![Interval::closed(
+      LimitValue::Limit(249),
+      LimitValue::Limit(255)
+    )]
+  );
+  set.remove_range(&Interval::under(LimitValue::Limit(252)));
+  assert_eq!(set.values().collect::<Vec<_>>(), vec![252, 253, 254, 255]);
+}
+
+#[test]
+fn test04_set_algebra() {
+  let mut a = RangeSet::new();
+  a.insert_range(&closed(0, 10));
+  a.insert_range(&closed(20, 30));
+  let mut b = RangeSet::new();
+  b.insert_range(&closed(5, 25));
+
+  assert_eq!(a.union(&b).iter().collect::<Vec<_>>(), vec![closed(0, 30)]);
+  assert_eq!(
+    a.intersection(&b).iter().collect::<Vec<_>>(),
+    vec![closed(5, 10), closed(20, 25)]
+  );
+  assert_eq!(
+    a.difference(&b).iter().collect::<Vec<_>>(),
+    vec![closed(0, 4), closed(26, 30)]
+  );
+  assert_eq!(a.difference(&a), RangeSet::new());
+  assert_eq!(a.to_interval_seq().len(), 2);
+}
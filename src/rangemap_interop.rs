@@ -0,0 +1,98 @@
+//! Conversions between the collections of this crate and those of the `rangemap` crate.
+
+use rangemap::{RangeInclusiveMap, RangeInclusiveSet, RangeSet, StepLite};
+
+use crate::discrete::closed_run_of;
+use crate::{Discrete, Interval, IntervalMap, IntervalSeq, LimitValue};
+
+impl<T> From<&RangeSet<T>> for IntervalSeq<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+{
+  /// Convert the ranges into half-open intervals.
+  fn from(set: &RangeSet<T>) -> Self {
+    IntervalSeq::new(set.iter().map(|e| {
+      Interval::over(
+        LimitValue::Limit(e.start.clone()),
+        true,
+        LimitValue::Limit(e.end.clone()),
+        false,
+      )
+    }))
+  }
+}
+
+impl<T> From<&RangeInclusiveSet<T>> for IntervalSeq<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + StepLite,
+{
+  /// Convert the ranges into closed intervals.
+  fn from(set: &RangeInclusiveSet<T>) -> Self {
+    IntervalSeq::new(set.iter().map(|e| {
+      Interval::closed(
+        LimitValue::Limit(e.start().clone()),
+        LimitValue::Limit(e.end().clone()),
+      )
+    }))
+  }
+}
+
+impl<T, V> From<&RangeInclusiveMap<T, V>> for IntervalMap<T, V>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + StepLite,
+  V: Clone + Eq,
+{
+  /// Convert the entries into entries of closed intervals.
+  fn from(map: &RangeInclusiveMap<T, V>) -> Self {
+    let mut result = IntervalMap::new();
+    for (range, value) in map.iter() {
+      result.insert(
+        Interval::closed(
+          LimitValue::Limit(range.start().clone()),
+          LimitValue::Limit(range.end().clone()),
+        ),
+        value.clone(),
+      );
+    }
+    result
+  }
+}
+
+impl<T> IntervalSeq<T>
+where
+  T: Discrete + Clone + Eq + Ord + PartialEq + PartialOrd + StepLite,
+{
+  /// Convert the element intervals into a `rangemap::RangeInclusiveSet`.
+  ///
+  /// Each interval becomes the inclusive range of the discrete values it includes, Limitless limits becoming
+  /// the bounds of the type. Overlapping or adjacent ranges are merged by the set.
+  ///
+  /// - return: `RangeInclusiveSet`
+  pub fn to_range_inclusive_set(&self) -> RangeInclusiveSet<T> {
+    self
+      .as_slice()
+      .iter()
+      .filter_map(closed_run_of)
+      .map(|(start, end)| start..=end)
+      .collect()
+  }
+}
+
+impl<T, V> IntervalMap<T, V>
+where
+  T: Discrete + Clone + Eq + Ord + PartialEq + PartialOrd + StepLite,
+  V: Clone + Eq,
+{
+  /// Convert the entries into a `rangemap::RangeInclusiveMap`.
+  ///
+  /// Each interval becomes the inclusive range of the discrete values it includes, Limitless limits becoming
+  /// the bounds of the type. Adjacent entries with equal values are merged by the map.
+  ///
+  /// - return: `RangeInclusiveMap`
+  pub fn to_range_inclusive_map(&self) -> RangeInclusiveMap<T, V> {
+    self
+      .iter()
+      .filter_map(|(e, v)| closed_run_of(e).map(|(start, end)| (start..=end, v.clone())))
+      .collect()
+  }
+}
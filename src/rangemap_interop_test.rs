@@ -0,0 +1,63 @@
+use rangemap::{RangeInclusiveMap, RangeInclusiveSet, RangeSet};
+
+use crate::{Interval, IntervalMap, IntervalSeq, LimitValue};
+
+fn closed(lower: u32, upper: u32) -> Interval<u32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_from_rangemap() {
+  let mut set = RangeSet::new();
+  set.insert(1..5);
+  set.insert(8..10);
+  let mut seq = IntervalSeq::from(&set);
+  assert_eq!(
+    seq.iter().collect::<Vec<_>>(),
+    vec![
+      &Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(5), false),
+      &Interval::over(LimitValue::Limit(8), true, LimitValue::Limit(10), false),
+    ]
+  );
+
+  let mut set = RangeInclusiveSet::new();
+  set.insert(1..=4);
+  let mut seq = IntervalSeq::from(&set);
+  assert_eq!(seq.iter().collect::<Vec<_>>(), vec![&closed(1, 4)]);
+
+  let mut map = RangeInclusiveMap::new();
+  map.insert(0..=9, "a");
+  map.insert(10..=19, "b");
+  let map = IntervalMap::from(&map);
+  assert_eq!(map.get(&LimitValue::Limit(12)), Some(&"b"));
+  assert_eq!(map.len(), 2);
+}
+
+#[test]
+fn test02_into_rangemap() {
+  let seq = IntervalSeq::new(vec![
+    closed(1, 4),
+    Interval::over(LimitValue::Limit(4), false, LimitValue::Limit(8), false),
+    Interval::and_more(LimitValue::Limit(100)),
+  ]);
+  let set = seq.to_range_inclusive_set();
+  assert_eq!(
+    set.iter().cloned().collect::<Vec<_>>(),
+    vec![1..=7, 100..=u32::MAX]
+  );
+
+  let mut map = IntervalMap::new();
+  map.insert(closed(0, 9), "a");
+  map.insert(
+    Interval::over(LimitValue::Limit(9), false, LimitValue::Limit(20), false),
+    "a",
+  );
+  assert_eq!(
+    map
+      .to_range_inclusive_map()
+      .iter()
+      .map(|(k, v)| (k.clone(), *v))
+      .collect::<Vec<_>>(),
+    vec![(0..=19, "a")]
+  );
+}
@@ -0,0 +1,216 @@
+use std::fmt::Display;
+use std::ops::Sub;
+
+use num_traits::ToPrimitive;
+
+use crate::{Interval, IntervalNotation, IntervalSeq, LimitValue};
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display> IntervalSeq<T> {
+  /// Render this interval sequence as an ASCII timeline for debugging.
+  ///
+  /// Each interval is drawn on its own line, sorted by the lower limits, as a bar against a common scale
+  /// that spans the finite limit values, followed by the interval itself.
+  /// Closed and open limits are drawn as `[`/`]` and `(`/`)`, Limitless limits as `<`/`>`,
+  /// single-element intervals as `|`, and the last line shows the scale.
+  ///
+  /// ```text
+  /// [======]        [1, 8]
+  ///     (=========] (5, 15]
+  ///            [==> [12, +inf)
+  /// 1            15
+  /// ```
+  ///
+  /// - params
+  ///     - width: the number of columns of the bars
+  /// - return: the rendered lines
+  /// - panic
+  ///     - if `width` is less than 2
+  pub fn render_ascii<D>(&self, width: usize) -> String
+  where
+    T: Sub<Output = D>,
+    D: ToPrimitive,
+  {
+    if width < 2 {
+      panic!("width must be at least 2: {}", width)
+    }
+    let mut intervals = self.as_slice().iter().collect::<Vec<&Interval<T>>>();
+    intervals.sort_by(|a, b| a.lower.cmp(&b.lower).then_with(|| a.upper.cmp(&b.upper)));
+    let scale = scale_of(intervals.iter().copied());
+    let column = |value: &LimitValue<T>, default: f64| {
+      (position_of(value, &scale, default) * (width - 1) as f64).round() as usize
+    };
+    let mut lines = vec![];
+    for interval in intervals {
+      let mut bar = vec![' '; width];
+      if interval.is_single_element() {
+        bar[column(interval.as_lower_limit(), 0.0)] = '|';
+      } else if !interval.is_empty() {
+        let from = column(interval.as_lower_limit(), 0.0);
+        let to = column(interval.as_upper_limit(), 1.0);
+        for e in bar.iter_mut().take(to + 1).skip(from) {
+          *e = '=';
+        }
+        bar[from] = if !interval.has_lower_limit() {
+          '<'
+        } else if interval.includes_lower_limit() {
+          '['
+        } else {
+          '('
+        };
+        bar[to] = if !interval.has_upper_limit() {
+          '>'
+        } else if interval.includes_upper_limit() {
+          ']'
+        } else {
+          ')'
+        };
+      }
+      lines.push(format!(
+        "{} {}",
+        bar.into_iter().collect::<String>(),
+        interval.format_with(IntervalNotation::Standard)
+      ));
+    }
+    if let Some((min, max)) = scale {
+      let (min, max) = (min.to_string(), max.to_string());
+      let padding = width.saturating_sub(min.len() + max.len()).max(1);
+      lines.push(format!("{}{}{}", min, " ".repeat(padding), max));
+    }
+    lines.join("\n")
+  }
+}
+
+#[cfg(feature = "svg")]
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display> IntervalSeq<T> {
+  /// Render this interval sequence as an SVG timeline.
+  ///
+  /// The intervals are laid out in the minimum number of lanes (see [`IntervalSeq::lanes`]),
+  /// against a common horizontal scale that spans the finite limit values.
+  /// Limitless limits reach the edge of the image.
+  ///
+  /// - params
+  ///     - width: the width of the image
+  ///     - lane_height: the height of each lane
+  /// - return: the SVG document
+  pub fn render_svg<D>(&self, width: f64, lane_height: f64) -> String
+  where
+    T: Sub<Output = D>,
+    D: ToPrimitive,
+  {
+    let lanes = self.lanes();
+    let bars = lanes
+      .iter()
+      .enumerate()
+      .flat_map(|(lane, intervals)| intervals.iter().map(move |e| (lane, e, None)))
+      .collect::<Vec<(usize, &Interval<T>, Option<String>)>>();
+    svg_of(&bars, lanes.len(), width, lane_height)
+  }
+}
+
+#[cfg(feature = "svg")]
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display, V: Display> crate::IntervalMap<T, V> {
+  /// Render this interval map as an SVG timeline in a single lane, labeling each entry with its value.
+  ///
+  /// - params
+  ///     - width: the width of the image
+  ///     - lane_height: the height of the lane
+  /// - return: the SVG document
+  pub fn render_svg<D>(&self, width: f64, lane_height: f64) -> String
+  where
+    T: Sub<Output = D>,
+    D: ToPrimitive,
+  {
+    let bars = self
+      .iter()
+      .map(|(interval, value)| (0, interval, Some(value.to_string())))
+      .collect::<Vec<(usize, &Interval<T>, Option<String>)>>();
+    svg_of(&bars, 1, width, lane_height)
+  }
+}
+
+#[cfg(feature = "svg")]
+fn svg_of<T, D>(
+  bars: &[(usize, &Interval<T>, Option<String>)],
+  lanes: usize,
+  width: f64,
+  lane_height: f64,
+) -> String
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Display + Sub<Output = D>,
+  D: ToPrimitive,
+{
+  let scale = scale_of(bars.iter().map(|(_, interval, _)| *interval));
+  let height = lanes as f64 * lane_height;
+  let mut svg = format!(
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.1}\" height=\"{:.1}\" viewBox=\"0 0 {:.1} {:.1}\">\n",
+    width, height, width, height
+  );
+  for (lane, interval, label) in bars {
+    let x = position_of(interval.as_lower_limit(), &scale, 0.0) * width;
+    let x_end = position_of(interval.as_upper_limit(), &scale, 1.0) * width;
+    let y = *lane as f64 * lane_height;
+    svg.push_str(&format!(
+      "  <rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\"><title>{}</title></rect>\n",
+      x,
+      y + lane_height * 0.1,
+      (x_end - x).max(1.0),
+      lane_height * 0.8,
+      escape(&interval.format_with(IntervalNotation::Standard))
+    ));
+    if let Some(label) = label {
+      svg.push_str(&format!(
+        "  <text x=\"{:.1}\" y=\"{:.1}\">{}</text>\n",
+        x,
+        y + lane_height * 0.7,
+        escape(label)
+      ));
+    }
+  }
+  svg.push_str("</svg>\n");
+  svg
+}
+
+#[cfg(feature = "svg")]
+fn escape(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+}
+
+/// Return the minimum and the maximum of the finite limit values of the given non-empty intervals.
+fn scale_of<'a, T>(intervals: impl Iterator<Item = &'a Interval<T>>) -> Option<(T, T)>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Display + 'a,
+{
+  let values = intervals
+    .filter(|e| !e.is_empty())
+    .flat_map(|e| vec![e.as_lower_limit(), e.as_upper_limit()])
+    .filter_map(|e| e.as_value().ok())
+    .collect::<Vec<&T>>();
+  values
+    .iter()
+    .min()
+    .zip(values.iter().max())
+    .map(|(min, max)| ((*min).clone(), (*max).clone()))
+}
+
+/// Return the position of the given limit value on the scale in `0.0..=1.0`, or `default` if it is Limitless.
+fn position_of<T, D>(value: &LimitValue<T>, scale: &Option<(T, T)>, default: f64) -> f64
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Display + Sub<Output = D>,
+  D: ToPrimitive,
+{
+  match (value, scale) {
+    (LimitValue::Limit(value), Some((min, max))) => {
+      let total = (max.clone() - min.clone()).to_f64().unwrap_or(0.0);
+      let offset = (value.clone() - min.clone()).to_f64().unwrap_or(0.0);
+      if total > 0.0 {
+        offset / total
+      } else {
+        0.0
+      }
+    }
+    _ => default,
+  }
+}
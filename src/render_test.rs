@@ -0,0 +1,76 @@
+use crate::{Interval, IntervalSeq, LimitValue};
+
+#[test]
+fn test01_render_ascii() {
+  let seq = IntervalSeq::new(vec![
+    Interval::and_more(LimitValue::Limit(12)),
+    Interval::closed(LimitValue::Limit(1), LimitValue::Limit(8)),
+    Interval::over(LimitValue::Limit(5), false, LimitValue::Limit(15), true),
+    Interval::single(10),
+  ]);
+  assert_eq!(
+    seq.render_ascii(15),
+    [
+      "[======]        [1, 8]",
+      "    (=========] (5, 15]",
+      "         |      {10}",
+      "           [==> [12, +inf)",
+      "1            15",
+    ]
+    .join("\n")
+  );
+}
+
+#[test]
+fn test02_render_ascii_without_scale() {
+  let seq = IntervalSeq::new(vec![Interval::closed(
+    LimitValue::<i32>::Limitless,
+    LimitValue::Limitless,
+  )]);
+  assert_eq!(seq.render_ascii(4), "<==> (-inf, +inf)");
+  assert_eq!(IntervalSeq::<i32>::empty().render_ascii(4), "");
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn test03_render_svg() {
+  let seq = IntervalSeq::new(vec![
+    Interval::closed(LimitValue::Limit(0), LimitValue::Limit(50)),
+    Interval::closed(LimitValue::Limit(25), LimitValue::Limit(100)),
+  ]);
+  assert_eq!(
+    seq.render_svg(200.0, 10.0),
+    [
+      "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"200.0\" height=\"20.0\" viewBox=\"0 0 200.0 20.0\">",
+      "  <rect x=\"0.0\" y=\"1.0\" width=\"100.0\" height=\"8.0\"><title>[0, 50]</title></rect>",
+      "  <rect x=\"50.0\" y=\"11.0\" width=\"150.0\" height=\"8.0\"><title>[25, 100]</title></rect>",
+      "</svg>",
+      "",
+    ]
+    .join("\n")
+  );
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn test04_render_map_svg() {
+  let mut map = crate::IntervalMap::new();
+  map.insert(
+    Interval::closed(LimitValue::Limit(0), LimitValue::Limit(10)),
+    "<a>",
+  );
+  map.insert(Interval::more_than(LimitValue::Limit(10)), "b");
+  assert_eq!(
+    map.render_svg(100.0, 10.0),
+    [
+      "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100.0\" height=\"10.0\" viewBox=\"0 0 100.0 10.0\">",
+      "  <rect x=\"0.0\" y=\"1.0\" width=\"100.0\" height=\"8.0\"><title>[0, 10]</title></rect>",
+      "  <text x=\"0.0\" y=\"7.0\">&lt;a&gt;</text>",
+      "  <rect x=\"100.0\" y=\"1.0\" width=\"1.0\" height=\"8.0\"><title>(10, +inf)</title></rect>",
+      "  <text x=\"100.0\" y=\"7.0\">b</text>",
+      "</svg>",
+      "",
+    ]
+    .join("\n")
+  );
+}
@@ -0,0 +1,145 @@
+use std::ops::Bound;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Interval, IntervalLimit, IntervalSeq, LimitValue};
+
+/// The serialized form of an interval limit, where a missing limit value means Limitless.
+#[derive(Serialize)]
+struct IntervalLimitRef<'a, T> {
+  value: Option<&'a T>,
+  closed: bool,
+  lower: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IntervalLimitData<T> {
+  value: Option<T>,
+  #[serde(default)]
+  closed: bool,
+  lower: bool,
+}
+
+/// The serialized form of an interval, where a missing limit value means Limitless.
+#[derive(Serialize)]
+struct IntervalRef<'a, T> {
+  lower: Option<&'a T>,
+  lower_closed: bool,
+  upper: Option<&'a T>,
+  upper_closed: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IntervalData<T> {
+  lower: Option<T>,
+  #[serde(default)]
+  lower_closed: bool,
+  upper: Option<T>,
+  #[serde(default)]
+  upper_closed: bool,
+}
+
+/// A limit value is serialized like an `Option`, with `None` for Limitless.
+impl<T: Serialize> Serialize for LimitValue<T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.as_value().ok().serialize(serializer)
+  }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for LimitValue<T> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    Ok(match Option::<T>::deserialize(deserializer)? {
+      Some(value) => LimitValue::Limit(value),
+      None => LimitValue::Limitless,
+    })
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Serialize> Serialize for IntervalLimit<T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    IntervalLimitRef {
+      value: self.finite_value(),
+      closed: self.is_closed(),
+      lower: self.is_lower(),
+    }
+    .serialize(serializer)
+  }
+}
+
+/// Deserialization rejects a closed Limitless limit instead of silently opening it.
+impl<'de, T> Deserialize<'de> for IntervalLimit<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Deserialize<'de>,
+{
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let data = IntervalLimitData::<T>::deserialize(deserializer)?;
+    match data.value {
+      Some(value) => Ok(IntervalLimit::new(
+        data.closed,
+        data.lower,
+        LimitValue::Limit(value),
+      )),
+      None if data.closed => Err(D::Error::custom("a Limitless limit must be open")),
+      None => Ok(IntervalLimit::new(false, data.lower, LimitValue::Limitless)),
+    }
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Serialize> Serialize for Interval<T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    IntervalRef {
+      lower: self.lower_value(),
+      lower_closed: self.includes_lower_limit(),
+      upper: self.upper_value(),
+      upper_closed: self.includes_upper_limit(),
+    }
+    .serialize(serializer)
+  }
+}
+
+/// Deserialization validates the data instead of trusting it:
+/// a Limitless limit must be open, and the upper limit must not be below the lower limit.
+impl<'de, T> Deserialize<'de> for Interval<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Deserialize<'de>,
+{
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let data = IntervalData::<T>::deserialize(deserializer)?;
+    let bound_of = |value: Option<T>, closed: bool, side: &str| match value {
+      Some(value) if closed => Ok(Bound::Included(value)),
+      Some(value) => Ok(Bound::Excluded(value)),
+      None if closed => Err(D::Error::custom(format!(
+        "the Limitless {} limit must be open",
+        side
+      ))),
+      None => Ok(Bound::Unbounded),
+    };
+    let lower = bound_of(data.lower, data.lower_closed, "lower")?;
+    let upper = bound_of(data.upper, data.upper_closed, "upper")?;
+    Interval::from_bounds(lower, upper)
+      .map_err(|_| D::Error::custom("the upper limit is below the lower limit"))
+  }
+}
+
+/// An interval sequence is serialized as a sequence of its element intervals in the storage order.
+///
+/// The sort order and the empty policy are not serialized, so a deserialized interval sequence has the defaults.
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Serialize> Serialize for IntervalSeq<T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(self.as_slice())
+  }
+}
+
+impl<'de, T> Deserialize<'de> for IntervalSeq<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Deserialize<'de>,
+{
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    Ok(IntervalSeq::new(Vec::<Interval<T>>::deserialize(
+      deserializer,
+    )?))
+  }
+}
@@ -0,0 +1,119 @@
+use crate::{Interval, IntervalLimit, IntervalSeq, LimitValue};
+
+fn from_json(json: &str) -> Result<Interval<i32>, serde_json::Error> {
+  serde_json::from_str(json)
+}
+
+#[test]
+fn test01_round_trip() {
+  let intervals = vec![
+    Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(10), false),
+    Interval::closed(LimitValue::Limit(3), LimitValue::Limit(3)),
+    Interval::open(LimitValue::Limit(3), LimitValue::Limit(3)),
+    Interval::and_more(LimitValue::Limit(5)),
+    Interval::open(LimitValue::Limitless, LimitValue::Limitless),
+  ];
+  for interval in intervals {
+    let json = serde_json::to_string(&interval).unwrap();
+    assert_eq!(from_json(&json).unwrap(), interval, "{}", json);
+  }
+}
+
+#[test]
+fn test02_layout() {
+  assert_eq!(
+    serde_json::to_string(&Interval::over(
+      LimitValue::Limit(1),
+      true,
+      LimitValue::Limitless,
+      false
+    ))
+    .unwrap(),
+    r#"{"lower":1,"lower_closed":true,"upper":null,"upper_closed":false}"#
+  );
+  assert_eq!(
+    from_json(r#"{"lower":1,"lower_closed":true}"#).unwrap(),
+    Interval::and_more(LimitValue::Limit(1))
+  );
+}
+
+#[test]
+fn test03_invalid_payloads() {
+  let error_of = |json: &str| from_json(json).unwrap_err().to_string();
+  assert!(error_of(r#"{"lower":10,"upper":1}"#).starts_with("the upper limit"));
+  assert!(error_of(r#"{"lower_closed":true,"upper":1}"#)
+    .starts_with("the Limitless lower limit must be open"));
+  assert!(
+    error_of(r#"{"upper_closed":true}"#).starts_with("the Limitless upper limit must be open")
+  );
+  assert!(error_of(r#"{"lower":1,"upper":2,"extra":0}"#).contains("unknown field"));
+  assert!(error_of(r#"{"lower":"1","upper":2}"#).contains("invalid type"));
+  assert!(
+    error_of(r#"[1, 2]"#).contains("invalid length") || error_of("[1, 2]").contains("invalid type")
+  );
+}
+
+#[test]
+fn test04_limit_value_and_interval_limit() {
+  assert_eq!(serde_json::to_string(&LimitValue::Limit(3)).unwrap(), "3");
+  assert_eq!(
+    serde_json::to_string(&LimitValue::<i32>::Limitless).unwrap(),
+    "null"
+  );
+  assert_eq!(
+    serde_json::from_str::<LimitValue<i32>>("null").unwrap(),
+    LimitValue::Limitless
+  );
+  assert_eq!(
+    serde_json::from_str::<LimitValue<i32>>("3").unwrap(),
+    LimitValue::Limit(3)
+  );
+
+  let limit = IntervalLimit::lower(true, LimitValue::Limit(3));
+  let json = serde_json::to_string(&limit).unwrap();
+  assert_eq!(json, r#"{"value":3,"closed":true,"lower":true}"#);
+  assert_eq!(
+    serde_json::from_str::<IntervalLimit<i32>>(&json).unwrap(),
+    limit
+  );
+  assert_eq!(
+    serde_json::from_str::<IntervalLimit<i32>>(r#"{"value":null,"lower":false}"#).unwrap(),
+    IntervalLimit::upper(false, LimitValue::Limitless)
+  );
+  let error_of = |json: &str| {
+    serde_json::from_str::<IntervalLimit<i32>>(json)
+      .unwrap_err()
+      .to_string()
+  };
+  assert!(error_of(r#"{"value":null,"closed":true,"lower":true}"#)
+    .starts_with("a Limitless limit must be open"));
+  assert!(error_of(r#"{"value":1}"#).contains("missing field `lower`"));
+}
+
+#[test]
+fn test05_interval_seq() {
+  let seq = IntervalSeq::new(vec![
+    Interval::closed(LimitValue::Limit(1), LimitValue::Limit(3)),
+    Interval::and_more(LimitValue::Limit(5)),
+  ]);
+  let json = serde_json::to_string(&seq).unwrap();
+  assert_eq!(
+    json,
+    r#"[{"lower":1,"lower_closed":true,"upper":3,"upper_closed":true},{"lower":5,"lower_closed":true,"upper":null,"upper_closed":false}]"#
+  );
+  let mut decoded = serde_json::from_str::<IntervalSeq<i32>>(&json).unwrap();
+  assert_eq!(
+    decoded.iter().cloned().collect::<Vec<_>>(),
+    vec![
+      Interval::closed(LimitValue::Limit(1), LimitValue::Limit(3)),
+      Interval::and_more(LimitValue::Limit(5)),
+    ]
+  );
+  assert!(serde_json::from_str::<IntervalSeq<i32>>("[]")
+    .unwrap()
+    .is_empty());
+  match serde_json::from_str::<IntervalSeq<i32>>(r#"[{"lower":3,"upper":1}]"#) {
+    Err(error) => assert!(error.to_string().starts_with("the upper limit")),
+    Ok(_) => panic!("crossed limits must be rejected"),
+  }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::{Interval, IntervalLimit, IntervalSeq, LimitValue};
+
+/// Map the limit value of a limit, keeping its open/closed state and side.
+fn map_limit<T, U>(limit: IntervalLimit<T>, f: impl FnOnce(T) -> U) -> IntervalLimit<U>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  U: Clone + Eq + Ord + PartialEq + PartialOrd,
+{
+  let closed = limit.is_closed();
+  let lower = limit.is_lower();
+  let value = match limit.into_value() {
+    LimitValue::Limit(value) => LimitValue::Limit(f(value)),
+    LimitValue::Limitless => LimitValue::Limitless,
+  };
+  IntervalLimit::new(closed, lower, value)
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Interval<T> {
+  /// Convert this interval into an interval whose limit values are shared through `Arc`.
+  ///
+  /// Cloning an `Interval<Arc<T>>`, and the operations that clone limit values, only increment reference counts,
+  /// which pays off for large limit values such as long strings or big decimals.
+  ///
+  /// - return: `Interval<Arc<T>>`
+  pub fn into_shared(self) -> Interval<Arc<T>> {
+    Interval {
+      lower: map_limit(self.lower, Arc::new),
+      upper: map_limit(self.upper, Arc::new),
+    }
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Interval<Arc<T>> {
+  /// Convert this interval into an interval that owns its limit values, cloning them only if they are still shared.
+  ///
+  /// - return: `Interval<T>`
+  pub fn into_unshared(self) -> Interval<T> {
+    let unwrap = |value: Arc<T>| Arc::try_unwrap(value).unwrap_or_else(|e| (*e).clone());
+    Interval {
+      lower: map_limit(self.lower, unwrap),
+      upper: map_limit(self.upper, unwrap),
+    }
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalSeq<T> {
+  /// Convert this interval sequence into an interval sequence whose limit values are shared through `Arc`.
+  ///
+  /// The sort specification and the policy on empty intervals are not carried over.
+  ///
+  /// - return: `IntervalSeq<Arc<T>>`
+  pub fn into_shared(self) -> IntervalSeq<Arc<T>> {
+    IntervalSeq::from_vec(
+      self
+        .into_vec()
+        .into_iter()
+        .map(Interval::into_shared)
+        .collect(),
+    )
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> IntervalSeq<Arc<T>> {
+  /// Convert this interval sequence into an interval sequence that owns its limit values.
+  ///
+  /// The sort specification and the policy on empty intervals are not carried over.
+  ///
+  /// - return: `IntervalSeq<T>`
+  pub fn into_unshared(self) -> IntervalSeq<T> {
+    IntervalSeq::from_vec(
+      self
+        .into_vec()
+        .into_iter()
+        .map(Interval::into_unshared)
+        .collect(),
+    )
+  }
+}
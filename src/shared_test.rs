@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::{Interval, IntervalSeq, LimitValue};
+
+fn closed(lower: &str, upper: &str) -> Interval<String> {
+  Interval::closed(
+    LimitValue::Limit(lower.to_string()),
+    LimitValue::Limit(upper.to_string()),
+  )
+}
+
+#[test]
+fn test01_round_trip() {
+  let intervals = vec![
+    closed("apple", "banana"),
+    Interval::more_than(LimitValue::Limit("cherry".to_string())),
+    Interval::open(LimitValue::Limitless, LimitValue::Limitless),
+  ];
+  for interval in intervals {
+    let shared = interval.clone().into_shared();
+    assert_eq!(shared.to_string(), interval.to_string());
+    assert_eq!(shared.into_unshared(), interval);
+  }
+}
+
+#[test]
+fn test02_operations_share_values() {
+  let a = closed("apple", "cherry").into_shared();
+  let b = closed("banana", "durian").into_shared();
+  let common = a.intersect(&b);
+  assert_eq!(common, closed("banana", "cherry").into_shared());
+  let lower = common.lower_value().unwrap();
+  assert!(Arc::ptr_eq(lower, b.lower_value().unwrap()));
+  assert_eq!(Arc::strong_count(lower), 2);
+}
+
+#[test]
+fn test03_seq() {
+  let seq = IntervalSeq::new(vec![closed("a", "c"), closed("b", "d")]);
+  let mut shared = seq.into_shared();
+  assert_eq!(
+    shared.coalesce().into_unshared().into_vec(),
+    vec![closed("a", "d")]
+  );
+  assert_eq!(shared.iter().count(), 2);
+}
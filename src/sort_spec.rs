@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::ops::Sub;
+use std::sync::Arc;
+
+use crate::Interval;
+
+type Comparator<T> = Arc<dyn Fn(&Interval<T>, &Interval<T>) -> Ordering + Send + Sync>;
+
+/// A composable specification of the order of intervals, made of keys compared one after another.
+///
+/// For example, "earliest start, longest first" is `SortSpec::new().by_lower().by_length().descending()`.
+/// Intervals that are equal in all the keys keep their relative order.
+pub struct SortSpec<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// comparators in the order of priority
+  comparators: Vec<Comparator<T>>,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Clone for SortSpec<T> {
+  fn clone(&self) -> Self {
+    Self {
+      comparators: self.comparators.clone(),
+    }
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Debug for SortSpec<T> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SortSpec")
+      .field("keys", &self.comparators.len())
+      .finish()
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> Default for SortSpec<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> SortSpec<T> {
+  /// Generate a specification without keys, under which all intervals are equal.
+  ///
+  /// - return: `SortSpec`
+  pub fn new() -> Self {
+    Self {
+      comparators: vec![],
+    }
+  }
+
+  /// Add a key that compares the intervals with the given function.
+  ///
+  /// - params
+  ///     - comparator: a function that compares two intervals
+  /// - return: `SortSpec`
+  pub fn by(
+    mut self,
+    comparator: impl Fn(&Interval<T>, &Interval<T>) -> Ordering + Send + Sync + 'static,
+  ) -> Self {
+    self.comparators.push(Arc::new(comparator));
+    self
+  }
+
+  /// Add a key that compares the lower limits, a Limitless lower limit being the smallest.
+  ///
+  /// - return: `SortSpec`
+  pub fn by_lower(self) -> Self
+  where
+    T: 'static,
+  {
+    self.by(|a, b| a.lower.cmp(&b.lower))
+  }
+
+  /// Add a key that compares the upper limits, a Limitless upper limit being the largest.
+  ///
+  /// - return: `SortSpec`
+  pub fn by_upper(self) -> Self
+  where
+    T: 'static,
+  {
+    self.by(|a, b| a.upper.cmp(&b.upper))
+  }
+
+  /// Add a key that compares the lengths, an unbounded interval being the longest.
+  ///
+  /// - return: `SortSpec`
+  pub fn by_length<D>(self) -> Self
+  where
+    T: Sub<Output = D> + 'static,
+    D: PartialOrd,
+  {
+    self.by(|a, b| match (a.length::<D>(), b.length::<D>()) {
+      (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+      (Some(_), None) => Ordering::Less,
+      (None, Some(_)) => Ordering::Greater,
+      (None, None) => Ordering::Equal,
+    })
+  }
+
+  /// Add a key that compares the values extracted from the intervals.
+  ///
+  /// - params
+  ///     - f: a function that extracts the key of an interval
+  /// - return: `SortSpec`
+  pub fn by_key<K: Ord>(self, f: impl Fn(&Interval<T>) -> K + Send + Sync + 'static) -> Self {
+    self.by(move |a, b| f(a).cmp(&f(b)))
+  }
+
+  /// Reverse the order of the last added key.
+  ///
+  /// - return: `SortSpec`
+  /// - panic
+  ///     - if no key has been added
+  pub fn descending(mut self) -> Self
+  where
+    T: 'static,
+  {
+    let last = self
+      .comparators
+      .pop()
+      .expect("descending requires a preceding key");
+    self.by(move |a, b| last(a, b).reverse())
+  }
+
+  /// Add all the keys of the given specification `other` after the keys of this specification.
+  ///
+  /// - params
+  ///     - other: a specification
+  /// - return: `SortSpec`
+  pub fn then(mut self, other: SortSpec<T>) -> Self {
+    self.comparators.extend(other.comparators);
+    self
+  }
+
+  /// Compare two intervals by the keys in the order of priority.
+  ///
+  /// - params
+  ///     - a: an interval
+  ///     - b: an interval
+  /// - return: the ordering of `a` relative to `b`
+  pub fn compare(&self, a: &Interval<T>, b: &Interval<T>) -> Ordering {
+    self
+      .comparators
+      .iter()
+      .map(|comparator| comparator(a, b))
+      .find(|ordering| *ordering != Ordering::Equal)
+      .unwrap_or(Ordering::Equal)
+  }
+}
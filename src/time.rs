@@ -0,0 +1,97 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::{Interval, LimitValue};
+
+impl Interval<SystemTime> {
+  /// Generate the half-open interval `[start, start + duration)`.
+  ///
+  /// - params
+  ///     - start: the start time
+  ///     - duration: the length of the interval
+  /// - return: an interval
+  /// - panic
+  ///     - if `start + duration` overflows
+  pub fn from_start(start: SystemTime, duration: Duration) -> Self {
+    Self::over(
+      LimitValue::Limit(start),
+      true,
+      LimitValue::Limit(start + duration),
+      false,
+    )
+  }
+
+  /// Get the elapsed time from the lower limit to the upper limit.
+  ///
+  /// - return: the duration, or `None` if either limit is Limitless
+  pub fn duration(&self) -> Option<Duration> {
+    match (self.as_lower_limit(), self.as_upper_limit()) {
+      (LimitValue::Limit(lower), LimitValue::Limit(upper)) => {
+        Some(upper.duration_since(*lower).unwrap_or_default())
+      }
+      _ => None,
+    }
+  }
+
+  /// Verify whether the current system time is included in this interval.
+  ///
+  /// - return: `true` if included, `false` otherwise
+  pub fn contains_now(&self) -> bool {
+    self.includes(&LimitValue::Limit(SystemTime::now()))
+  }
+}
+
+impl Interval<Instant> {
+  /// Generate the half-open interval `[start, start + duration)`.
+  ///
+  /// - params
+  ///     - start: the start instant
+  ///     - duration: the length of the interval
+  /// - return: an interval
+  /// - panic
+  ///     - if `start + duration` overflows
+  pub fn from_start(start: Instant, duration: Duration) -> Self {
+    Self::over(
+      LimitValue::Limit(start),
+      true,
+      LimitValue::Limit(start + duration),
+      false,
+    )
+  }
+
+  /// Generate the half-open interval `[origin + from, origin + to)` from offsets relative to an origin instant.
+  ///
+  /// - params
+  ///     - origin: the origin instant
+  ///     - from: the offset of the lower limit
+  ///     - to: the offset of the upper limit
+  /// - return: an interval
+  /// - panic
+  ///     - if `from` is greater than `to`
+  pub fn from_offsets(origin: Instant, from: Duration, to: Duration) -> Self {
+    Self::over(
+      LimitValue::Limit(origin + from),
+      true,
+      LimitValue::Limit(origin + to),
+      false,
+    )
+  }
+
+  /// Get the elapsed time from the lower limit to the upper limit.
+  ///
+  /// - return: the duration, or `None` if either limit is Limitless
+  pub fn duration(&self) -> Option<Duration> {
+    match (self.as_lower_limit(), self.as_upper_limit()) {
+      (LimitValue::Limit(lower), LimitValue::Limit(upper)) => {
+        Some(upper.saturating_duration_since(*lower))
+      }
+      _ => None,
+    }
+  }
+
+  /// Verify whether the current instant is included in this interval.
+  ///
+  /// - return: `true` if included, `false` otherwise
+  pub fn contains_now(&self) -> bool {
+    self.includes(&LimitValue::Limit(Instant::now()))
+  }
+}
@@ -0,0 +1,30 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::{Interval, LimitValue};
+
+#[test]
+fn test01_system_time() {
+  let now = SystemTime::now();
+  let interval =
+    Interval::<SystemTime>::from_start(now - Duration::from_secs(60), Duration::from_secs(120));
+  assert_eq!(interval.duration(), Some(Duration::from_secs(120)));
+  assert!(interval.contains_now());
+  assert!(!interval.includes(&LimitValue::Limit(now + Duration::from_secs(60))));
+
+  let past = Interval::<SystemTime>::from_start(SystemTime::UNIX_EPOCH, Duration::from_secs(1));
+  assert!(!past.contains_now());
+  assert_eq!(Interval::and_more(LimitValue::Limit(now)).duration(), None);
+}
+
+#[test]
+fn test02_instant() {
+  let origin = Instant::now();
+  let interval =
+    Interval::<Instant>::from_offsets(origin, Duration::from_millis(0), Duration::from_secs(3600));
+  assert_eq!(interval.duration(), Some(Duration::from_secs(3600)));
+  assert!(interval.contains_now());
+  assert_eq!(
+    interval,
+    Interval::<Instant>::from_start(origin, Duration::from_secs(3600))
+  );
+}
@@ -0,0 +1,176 @@
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::Bound;
+
+use crate::{Error, Interval, LimitValue};
+
+mod sealed {
+  pub trait Sealed {}
+}
+
+/// A marker trait for the kind of a limit, which is either `Closed` or `Open`.
+pub trait BoundKind: sealed::Sealed + Debug + Clone + Copy + PartialEq + Eq + Hash {
+  /// `true` if the limit is included in the interval
+  const CLOSED: bool;
+}
+
+/// A marker type for a limit that is included in the interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Closed;
+
+/// A marker type for a limit that is not included in the interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Open;
+
+impl sealed::Sealed for Closed {}
+impl sealed::Sealed for Open {}
+
+impl BoundKind for Closed {
+  const CLOSED: bool = true;
+}
+
+impl BoundKind for Open {
+  const CLOSED: bool = false;
+}
+
+/// A structure that represents a bounded interval whose open/closed states are fixed by its type.
+///
+/// It is convertible to and from the dynamic `Interval<T>`, so a library can enforce a convention
+/// such as half-open ranges at compile time and still use the operations of `Interval<T>`.
+///
+/// Like `Interval<T>`, an interval with equal limits of different kinds, e.g. `[1, 1)`, is the single element `{1}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypedInterval<T, L, U>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  L: BoundKind,
+  U: BoundKind,
+{
+  interval: Interval<T>,
+  kinds: PhantomData<(L, U)>,
+}
+
+/// An interval `[lower, upper]`.
+pub type ClosedInterval<T> = TypedInterval<T, Closed, Closed>;
+
+/// An interval `(lower, upper)`.
+pub type OpenInterval<T> = TypedInterval<T, Open, Open>;
+
+/// An interval `[lower, upper)`.
+pub type HalfOpenInterval<T> = TypedInterval<T, Closed, Open>;
+
+impl<T, L, U> TypedInterval<T, L, U>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  L: BoundKind,
+  U: BoundKind,
+{
+  /// Generate an interval.
+  ///
+  /// - params
+  ///     - lower: lower limit value
+  ///     - upper: upper limit value
+  /// - return: an interval
+  /// - panic
+  ///     - if the lower limit is greater than the upper limit
+  pub fn new(lower: T, upper: T) -> Self {
+    Self::try_new(lower, upper)
+      .unwrap_or_else(|_| panic!("the upper limit is below the lower limit"))
+  }
+
+  /// Generate an interval, rejecting crossed limits.
+  ///
+  /// - params
+  ///     - lower: lower limit value
+  ///     - upper: upper limit value
+  /// - return: an interval, or `Err(Error::UpperBelowLower)` if the upper limit is below the lower limit
+  pub fn try_new(lower: T, upper: T) -> Result<Self, Error> {
+    let bound_of = |value: T, closed: bool| {
+      if closed {
+        Bound::Included(value)
+      } else {
+        Bound::Excluded(value)
+      }
+    };
+    let interval = Interval::from_bounds(bound_of(lower, L::CLOSED), bound_of(upper, U::CLOSED))?;
+    Ok(Self {
+      interval,
+      kinds: PhantomData,
+    })
+  }
+
+  /// Gets the lower limit value.
+  pub fn lower(&self) -> &T {
+    self.interval.lower_value().unwrap()
+  }
+
+  /// Gets the upper limit value.
+  pub fn upper(&self) -> &T {
+    self.interval.upper_value().unwrap()
+  }
+
+  /// Gets the dynamic interval.
+  pub fn as_interval(&self) -> &Interval<T> {
+    &self.interval
+  }
+
+  /// Convert this interval into the dynamic interval.
+  pub fn into_interval(self) -> Interval<T> {
+    self.interval
+  }
+}
+
+impl<T, L, U> From<TypedInterval<T, L, U>> for Interval<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  L: BoundKind,
+  U: BoundKind,
+{
+  fn from(value: TypedInterval<T, L, U>) -> Self {
+    value.interval
+  }
+}
+
+/// The conversion fails, giving back the interval, if a limit is Limitless or its open/closed state differs from the type.
+///
+/// A single-element interval, whose limits are normalized to closed, converts to `ClosedInterval`
+/// and to the kinds with one open limit, e.g. `HalfOpenInterval`, which `new` normalizes the same way.
+/// It does not convert to `OpenInterval`, whose equal limits make an empty interval instead.
+impl<T, L, U> TryFrom<Interval<T>> for TypedInterval<T, L, U>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+  L: BoundKind,
+  U: BoundKind,
+{
+  type Error = Interval<T>;
+
+  fn try_from(interval: Interval<T>) -> Result<Self, Self::Error> {
+    let kinds_match = (interval.includes_lower_limit() == L::CLOSED
+      && interval.includes_upper_limit() == U::CLOSED)
+      || (L::CLOSED != U::CLOSED && interval.is_single_element());
+    let matches = matches!(interval.as_lower_limit(), LimitValue::Limit(_))
+      && matches!(interval.as_upper_limit(), LimitValue::Limit(_))
+      && kinds_match;
+    if matches {
+      Ok(Self {
+        interval,
+        kinds: PhantomData,
+      })
+    } else {
+      Err(interval)
+    }
+  }
+}
+
+impl<T, L, U> Display for TypedInterval<T, L, U>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Display,
+  L: BoundKind,
+  U: BoundKind,
+{
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.interval)
+  }
+}
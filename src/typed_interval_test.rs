@@ -0,0 +1,70 @@
+use std::convert::TryFrom;
+
+use crate::{
+  Closed, ClosedInterval, Error, HalfOpenInterval, Interval, LimitValue, Open, OpenInterval,
+  TypedInterval,
+};
+
+#[test]
+fn test01_new() {
+  let range = HalfOpenInterval::new(1, 10);
+  assert_eq!(range.lower(), &1);
+  assert_eq!(range.upper(), &10);
+  assert_eq!(
+    range.as_interval(),
+    &Interval::over(LimitValue::Limit(1), true, LimitValue::Limit(10), false)
+  );
+  assert!(range.as_interval().includes(&LimitValue::Limit(1)));
+  assert!(!range.as_interval().includes(&LimitValue::Limit(10)));
+  assert!(OpenInterval::new(3, 3).as_interval().is_empty());
+  assert!(matches!(
+    ClosedInterval::try_new(10, 1),
+    Err(Error::UpperBelowLower)
+  ));
+}
+
+#[test]
+#[should_panic]
+fn test02_new_panics_on_crossed_limits() {
+  ClosedInterval::new(10, 1);
+}
+
+#[test]
+fn test03_conversions() {
+  let interval: Interval<i32> = HalfOpenInterval::new(1, 10).into();
+  assert_eq!(
+    HalfOpenInterval::try_from(interval.clone()).unwrap(),
+    HalfOpenInterval::new(1, 10)
+  );
+  assert_eq!(ClosedInterval::try_from(interval.clone()), Err(interval));
+  let unbounded = Interval::and_more(LimitValue::Limit(1));
+  assert!(HalfOpenInterval::try_from(unbounded).is_err());
+  assert!(
+    ClosedInterval::try_from(Interval::closed(LimitValue::Limit(1), LimitValue::Limit(1))).is_ok()
+  );
+}
+
+#[test]
+fn test04_single_element_round_trip() {
+  type OpenClosedInterval<T> = TypedInterval<T, Open, Closed>;
+  let single = HalfOpenInterval::new(1, 1);
+  assert_eq!(single.as_interval(), &Interval::single(1));
+  assert_eq!(
+    HalfOpenInterval::try_from(single.clone().into_interval()),
+    Ok(single)
+  );
+  let single = OpenClosedInterval::new(1, 1);
+  assert_eq!(
+    OpenClosedInterval::try_from(single.clone().into_interval()),
+    Ok(single)
+  );
+  let empty = OpenInterval::new(1, 1);
+  assert_eq!(
+    OpenInterval::try_from(empty.clone().into_interval()),
+    Ok(empty)
+  );
+  assert_eq!(
+    OpenInterval::try_from(Interval::single(1)),
+    Err(Interval::single(1))
+  );
+}
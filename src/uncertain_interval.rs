@@ -0,0 +1,142 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+
+use crate::{Interval, LimitValue};
+
+/// A three-valued result of a predicate on uncertain intervals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tristate {
+  /// the predicate holds whatever the actual interval is
+  Yes,
+  /// the predicate does not hold whatever the actual interval is
+  No,
+  /// the predicate depends on the actual interval
+  Maybe,
+}
+
+impl Tristate {
+  fn of(certainly: bool, possibly: bool) -> Self {
+    if certainly {
+      Tristate::Yes
+    } else if possibly {
+      Tristate::Maybe
+    } else {
+      Tristate::No
+    }
+  }
+}
+
+/// A structure that represents an interval known only within bounds.
+///
+/// The actual interval covers the inner (guaranteed) interval and is covered by the outer (possible) interval.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UncertainInterval<T: Clone + Eq + Ord + PartialEq + PartialOrd> {
+  /// the interval that is certainly included
+  inner: Interval<T>,
+  /// the interval that possibly includes the actual interval
+  outer: Interval<T>,
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd> UncertainInterval<T> {
+  /// Generate an uncertain interval.
+  ///
+  /// - params
+  ///     - inner: the guaranteed interval, which may be empty
+  ///     - outer: the possible interval
+  /// - return: `UncertainInterval`
+  /// - panic
+  ///     - if `inner` is not empty and `outer` does not cover it
+  pub fn new(inner: Interval<T>, outer: Interval<T>) -> Self {
+    if !inner.is_empty() && !outer.covers(&inner) {
+      panic!("the outer interval does not cover the inner interval")
+    }
+    Self { inner, outer }
+  }
+
+  /// Generate an uncertain interval that is known exactly.
+  ///
+  /// - params
+  ///     - interval: the actual interval
+  /// - return: `UncertainInterval`
+  pub fn exact(interval: Interval<T>) -> Self {
+    Self {
+      inner: interval.clone(),
+      outer: interval,
+    }
+  }
+
+  /// Gets the guaranteed interval.
+  pub fn as_inner(&self) -> &Interval<T> {
+    &self.inner
+  }
+
+  /// Gets the possible interval.
+  pub fn as_outer(&self) -> &Interval<T> {
+    &self.outer
+  }
+
+  /// Verify whether the actual interval is known exactly.
+  pub fn is_exact(&self) -> bool {
+    self.inner == self.outer
+  }
+
+  /// Verify whether the actual interval includes the specified value `value`.
+  ///
+  /// - params
+  ///     - value: a value
+  /// - return: `Tristate`
+  pub fn includes(&self, value: &LimitValue<T>) -> Tristate {
+    Tristate::of(self.inner.includes(value), self.outer.includes(value))
+  }
+
+  /// Verify whether the actual interval intersects the actual interval of `other`.
+  ///
+  /// - params
+  ///     - other: an uncertain interval
+  /// - return: `Tristate`
+  pub fn intersects(&self, other: &Self) -> Tristate {
+    Tristate::of(
+      self.inner.intersects(&other.inner),
+      self.outer.intersects(&other.outer),
+    )
+  }
+
+  /// Verify whether the actual interval covers the actual interval of `other`.
+  ///
+  /// - params
+  ///     - other: an uncertain interval
+  /// - return: `Tristate`
+  pub fn covers(&self, other: &Self) -> Tristate {
+    Tristate::of(
+      self.inner.covers(&other.outer),
+      other.inner.is_empty() || self.outer.covers(&other.inner),
+    )
+  }
+
+  /// Return the common part of the actual intervals of this and `other`.
+  ///
+  /// - params
+  ///     - other: an uncertain interval
+  /// - return: `UncertainInterval`
+  pub fn intersect(&self, other: &Self) -> Self {
+    Self::new(
+      self.inner.intersect(&other.inner),
+      self.outer.intersect(&other.outer),
+    )
+  }
+
+  /// Return the smallest interval that encompasses the actual intervals of this and `other`.
+  ///
+  /// - params
+  ///     - other: an uncertain interval
+  /// - return: `UncertainInterval`
+  pub fn span(&self, other: &Self) -> Self {
+    Self::new(self.inner.span(&other.inner), self.outer.span(&other.outer))
+  }
+}
+
+impl<T: Clone + Eq + Ord + PartialEq + PartialOrd + Display> Display for UncertainInterval<T> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} within {}", self.inner, self.outer)
+  }
+}
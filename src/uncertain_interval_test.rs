@@ -0,0 +1,52 @@
+use crate::{Interval, LimitValue, Tristate, UncertainInterval};
+
+fn closed(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::closed(LimitValue::Limit(lower), LimitValue::Limit(upper))
+}
+
+#[test]
+fn test01_includes() {
+  let arrival = UncertainInterval::new(closed(10, 12), closed(8, 15));
+  assert_eq!(arrival.includes(&LimitValue::Limit(11)), Tristate::Yes);
+  assert_eq!(arrival.includes(&LimitValue::Limit(14)), Tristate::Maybe);
+  assert_eq!(arrival.includes(&LimitValue::Limit(20)), Tristate::No);
+  assert!(!arrival.is_exact());
+  assert!(UncertainInterval::exact(closed(1, 2)).is_exact());
+  assert_eq!(
+    arrival.to_string(),
+    format!("{} within {}", closed(10, 12), closed(8, 15))
+  );
+}
+
+#[test]
+fn test02_relations() {
+  let task = UncertainInterval::new(closed(10, 12), closed(8, 15));
+  let window = UncertainInterval::exact(closed(0, 20));
+  let slot = UncertainInterval::exact(closed(11, 14));
+  let later = UncertainInterval::exact(closed(16, 18));
+
+  assert_eq!(window.covers(&task), Tristate::Yes);
+  assert_eq!(slot.covers(&task), Tristate::No);
+  assert_eq!(
+    UncertainInterval::exact(closed(9, 14)).covers(&task),
+    Tristate::Maybe
+  );
+  assert_eq!(task.intersects(&slot), Tristate::Yes);
+  assert_eq!(task.intersects(&later), Tristate::No);
+  assert_eq!(
+    task.intersects(&UncertainInterval::exact(closed(13, 14))),
+    Tristate::Maybe
+  );
+}
+
+#[test]
+fn test03_lifted_operations() {
+  let a = UncertainInterval::new(closed(10, 12), closed(8, 15));
+  let b = UncertainInterval::new(closed(14, 16), closed(11, 20));
+  let common = a.intersect(&b);
+  assert!(common.as_inner().is_empty());
+  assert_eq!(common.as_outer(), &closed(11, 15));
+  let hull = a.span(&b);
+  assert_eq!(hull.as_inner(), &closed(10, 16));
+  assert_eq!(hull.as_outer(), &closed(8, 20));
+}
@@ -0,0 +1,334 @@
+//! Generators of fixed-width windows over a domain interval, and resampling of interval data onto them.
+//!
+//! All the window generators clip their windows to the domain: a window that reaches past a limit of the domain is
+//! truncated at that limit instead of being dropped.
+use std::ops::{Add, Rem, Sub};
+
+use num_traits::ToPrimitive;
+
+use crate::{Interval, IntervalMap, IntervalSeq, LimitValue};
+
+/// Generate overlapping windows of a fixed width over the given interval `domain`.
+///
+/// Each window is the half-open interval `[start, start + width)`, where `start` begins at the lower limit of `domain`
+/// and advances by `stride` while it lies within `domain`. The windows are clipped to `domain`,
+/// so the first window follows the open/closed state of the lower limit of `domain`
+/// and the trailing windows that reach past the upper limit of `domain` are truncated, not dropped.
+/// This is [`hopping`] with `stride` as the hop.
+///
+/// - params
+///     - domain: a bounded interval to be divided
+///     - width: the width of each window
+///     - stride: the distance between the starts of consecutive windows
+/// - return: windows sorted by their lower limits
+/// - panic
+///     - if `domain` has no lower or upper limit
+///     - if `width` or `stride` is not positive
+pub fn sliding<T, D>(domain: &Interval<T>, width: D, stride: D) -> IntervalSeq<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Add<D, Output = T> + Sub<Output = D>,
+  D: Clone + PartialOrd,
+{
+  hopping(domain, width, stride)
+}
+
+/// Generate non-overlapping windows of a fixed width that partition the given interval `domain`.
+///
+/// The windows are aligned to the lower limit of `domain`, and the last window is truncated at the upper limit of
+/// `domain` when `width` does not divide its length. See [`hopping`] for the details.
+///
+/// - params
+///     - domain: a bounded interval to be divided
+///     - width: the width of each window
+/// - return: windows sorted by their lower limits
+/// - panic
+///     - if `domain` has no lower or upper limit
+///     - if `width` is not positive
+pub fn tumbling<T, D>(domain: &Interval<T>, width: D) -> IntervalSeq<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Add<D, Output = T> + Sub<Output = D>,
+  D: Clone + PartialOrd,
+{
+  hopping(domain, width.clone(), width)
+}
+
+/// Generate non-overlapping windows of a fixed width that partition the given interval `domain`,
+/// aligned to the given `origin` (e.g. an epoch) instead of the lower limit of `domain`.
+///
+/// The first and last windows are truncated at the limits of `domain`. See [`hopping_aligned`] for the details.
+///
+/// - params
+///     - domain: a bounded interval to be divided
+///     - width: the width of each window
+///     - origin: a value at which a window starts
+/// - return: windows sorted by their lower limits
+/// - panic
+///     - if `domain` has no lower or upper limit
+///     - if `width` is not positive
+pub fn tumbling_aligned<T, D>(domain: &Interval<T>, width: D, origin: T) -> IntervalSeq<T>
+where
+  T: Clone
+    + Eq
+    + Ord
+    + PartialEq
+    + PartialOrd
+    + Add<D, Output = T>
+    + Sub<D, Output = T>
+    + Sub<Output = D>,
+  D: Clone + PartialOrd + Rem<Output = D>,
+{
+  hopping_aligned(domain, width.clone(), width, origin)
+}
+
+/// Generate windows of a fixed width whose starts are `hop` apart, aligned to the lower limit of the given interval `domain`.
+///
+/// The windows are the half-open intervals `[start, start + width)` on the grid of starts `lower + k * hop` (`k >= 0`)
+/// that intersect `domain`, clipped to `domain`.
+///
+/// - params
+///     - domain: a bounded interval to be divided
+///     - width: the width of each window
+///     - hop: the distance between the starts of consecutive windows
+/// - return: windows sorted by their lower limits
+/// - panic
+///     - if `domain` has no lower or upper limit
+///     - if `width` or `hop` is not positive
+pub fn hopping<T, D>(domain: &Interval<T>, width: D, hop: D) -> IntervalSeq<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Add<D, Output = T> + Sub<Output = D>,
+  D: Clone + PartialOrd,
+{
+  let (lower, _) = bounds_of(domain);
+  grid_windows(domain, width, hop, lower.clone())
+}
+
+/// Generate windows of a fixed width whose starts are `hop` apart, aligned to the given `origin` (e.g. an epoch).
+///
+/// The windows are the half-open intervals `[start, start + width)` on the grid of starts `origin + k * hop`
+/// that intersect `domain`, clipped to `domain`. So when `width` is greater than `hop`,
+/// the windows that start before `domain` but reach into it are also generated.
+///
+/// - params
+///     - domain: a bounded interval to be divided
+///     - width: the width of each window
+///     - hop: the distance between the starts of consecutive windows
+///     - origin: a value at which a window starts
+/// - return: windows sorted by their lower limits
+/// - panic
+///     - if `domain` has no lower or upper limit
+///     - if `width` or `hop` is not positive
+pub fn hopping_aligned<T, D>(domain: &Interval<T>, width: D, hop: D, origin: T) -> IntervalSeq<T>
+where
+  T: Clone
+    + Eq
+    + Ord
+    + PartialEq
+    + PartialOrd
+    + Add<D, Output = T>
+    + Sub<D, Output = T>
+    + Sub<Output = D>,
+  D: Clone + PartialOrd + Rem<Output = D>,
+{
+  let (lower, _) = bounds_of(domain);
+  let start = if *lower >= origin {
+    let previous = lower.clone() - (lower.clone() - origin) % hop.clone();
+    if previous == *lower {
+      previous
+    } else {
+      previous + hop.clone()
+    }
+  } else {
+    lower.clone() + (origin - lower.clone()) % hop.clone()
+  };
+  let following = grid_windows(domain, width.clone(), hop.clone(), start.clone());
+  // The grid starts before `start` lie below `domain` and may not be representable by `T` (e.g. below zero for
+  // unsigned integers), so those windows are built from their upper limits instead.
+  let mut values: Vec<Interval<T>> = vec![];
+  let mut end = start + width;
+  while end > lower.clone() + hop.clone() {
+    end = end - hop.clone();
+    values.push(
+      Interval::over(
+        LimitValue::Limit(lower.clone()),
+        true,
+        LimitValue::Limit(end.clone()),
+        false,
+      )
+      .intersect(domain),
+    );
+  }
+  values.reverse();
+  values.extend(following.into_vec());
+  IntervalSeq::from_vec(values)
+}
+
+/// Build session intervals from the given timestamps.
+///
+/// Timestamps closer than `gap` to the previous one belong to the same session,
+/// and each session is the closed interval from its first timestamp to its last timestamp.
+///
+/// - params
+///     - timestamps: timestamps in any order
+///     - gap: the minimum distance that separates two sessions
+/// - return: sessions sorted by their lower limits
+pub fn sessions<T, D>(timestamps: impl IntoIterator<Item = T>, gap: D) -> IntervalSeq<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Sub<Output = D>,
+  D: PartialOrd,
+{
+  sessions_of(timestamps.into_iter().map(|e| Interval::single(e)), gap)
+}
+
+/// Build session intervals from the given events, each of which is a (small) interval.
+///
+/// Events that intersect, or whose distance from the previous events is less than `gap`, belong to the same session,
+/// and each session is the smallest interval that encompasses its events. Empty events are dropped.
+///
+/// - params
+///     - events: event intervals in any order
+///     - gap: the minimum distance that separates two sessions
+/// - return: sessions sorted by their lower limits
+pub fn sessions_of<T, D>(events: impl IntoIterator<Item = Interval<T>>, gap: D) -> IntervalSeq<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Sub<Output = D>,
+  D: PartialOrd,
+{
+  let mut sorted = events
+    .into_iter()
+    .filter(|e| !e.is_empty())
+    .collect::<Vec<Interval<T>>>();
+  sorted.sort_by(|a, b| a.lower.cmp(&b.lower));
+  let mut values: Vec<Interval<T>> = vec![];
+  for event in sorted {
+    match values.last_mut() {
+      Some(last)
+        if last.intersects(&event) || last.gap(&event).length().is_some_and(|d| d < gap) =>
+      {
+        *last = last.span(&event);
+      }
+      _ => values.push(event),
+    }
+  }
+  IntervalSeq::from_vec(values)
+}
+
+/// Resample the coverage of the given interval sequence `seq` onto bins.
+///
+/// Each bin is paired with the fraction of it that is covered by `seq`, e.g. the availability ratio of each hour
+/// when the bins are generated by [`tumbling`].
+///
+/// - params
+///     - seq: an interval sequence
+///     - bins: bounded bins with positive lengths
+/// - return: pairs of a bin and its covered fraction in `0.0..=1.0`, in the order of `bins`
+pub fn resample_coverage<T, D>(
+  seq: &IntervalSeq<T>,
+  bins: impl IntoIterator<Item = Interval<T>>,
+) -> Vec<(Interval<T>, f64)>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Sub<Output = D>,
+  D: ToPrimitive,
+{
+  bins
+    .into_iter()
+    .map(|bin| {
+      let coverage = seq.coverage_of(&bin).unwrap_or(0.0);
+      (bin, coverage)
+    })
+    .collect()
+}
+
+/// Resample the values of the given interval map `map` onto bins.
+///
+/// The value of each entry is distributed to the bins in proportion to the length of the overlap,
+/// so the total is preserved when the bins cover all the entries.
+/// The value of a single-element entry goes to the bins that include it, and unbounded entries are ignored.
+///
+/// - params
+///     - map: an interval map of amounts
+///     - bins: bounded bins
+/// - return: pairs of a bin and the amount distributed to it, in the order of `bins`
+pub fn resample_values<T, D, V>(
+  map: &IntervalMap<T, V>,
+  bins: impl IntoIterator<Item = Interval<T>>,
+) -> Vec<(Interval<T>, f64)>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Sub<Output = D>,
+  D: ToPrimitive,
+  V: ToPrimitive,
+{
+  let length_of = |interval: &Interval<T>| interval.length().and_then(|d: D| d.to_f64());
+  bins
+    .into_iter()
+    .map(|bin| {
+      let amount = map
+        .iter()
+        .filter_map(|(interval, value)| {
+          let overlap = interval.intersect(&bin);
+          if overlap.is_empty() {
+            return None;
+          }
+          let value = value.to_f64()?;
+          let length = length_of(interval)?;
+          if length > 0.0 {
+            Some(value * length_of(&overlap)? / length)
+          } else {
+            Some(value)
+          }
+        })
+        .sum();
+      (bin, amount)
+    })
+    .collect()
+}
+
+/// Generate the windows `[start + k * hop, start + k * hop + width)` clipped to `domain`, while the starts lie within it.
+///
+/// A value is added to a start only if the sum does not pass the upper limit of `domain`,
+/// so a domain that ends near the maximum value of `T` does not overflow.
+fn grid_windows<T, D>(domain: &Interval<T>, width: D, hop: D, start: T) -> IntervalSeq<T>
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd + Add<D, Output = T> + Sub<Output = D>,
+  D: Clone + PartialOrd,
+{
+  let (_, upper) = bounds_of(domain);
+  let mut start = start;
+  let mut values: Vec<Interval<T>> = vec![];
+  while !domain.is_below(&LimitValue::Limit(start.clone())) {
+    // A non-positive width or hop never passes the rest of the domain, so it is always added and detected.
+    let rest = upper.clone() - start.clone();
+    let end = if width <= rest {
+      let end = start.clone() + width.clone();
+      if end <= start {
+        panic!("width must be positive");
+      }
+      LimitValue::Limit(end)
+    } else {
+      LimitValue::Limitless
+    };
+    let window =
+      Interval::over(LimitValue::Limit(start.clone()), true, end, false).intersect(domain);
+    if !window.is_empty() {
+      values.push(window);
+    }
+    if hop > rest {
+      break;
+    }
+    let next = start.clone() + hop.clone();
+    if next <= start {
+      panic!("hop must be positive");
+    }
+    start = next;
+  }
+  IntervalSeq::from_vec(values)
+}
+
+fn bounds_of<T>(domain: &Interval<T>) -> (&T, &T)
+where
+  T: Clone + Eq + Ord + PartialEq + PartialOrd,
+{
+  match (domain.as_lower_limit(), domain.as_upper_limit()) {
+    (LimitValue::Limit(lower), LimitValue::Limit(upper)) => (lower, upper),
+    _ => panic!("the domain is not bounded"),
+  }
+}
@@ -0,0 +1,248 @@
+use crate::windows;
+use crate::{Interval, IntervalMap, IntervalSeq, LimitValue};
+
+fn closed_open(lower: i32, upper: i32) -> Interval<i32> {
+  Interval::over(
+    LimitValue::Limit(lower),
+    true,
+    LimitValue::Limit(upper),
+    false,
+  )
+}
+
+#[test]
+fn test01_sliding() {
+  let domain = Interval::closed(LimitValue::Limit(0), LimitValue::Limit(10));
+  let windows = windows::sliding(&domain, 4, 2).into_vec();
+  assert_eq!(
+    windows,
+    vec![
+      closed_open(0, 4),
+      closed_open(2, 6),
+      closed_open(4, 8),
+      closed_open(6, 10),
+      Interval::closed(LimitValue::Limit(8), LimitValue::Limit(10)),
+      Interval::closed(LimitValue::Limit(10), LimitValue::Limit(10))
+    ]
+  );
+}
+
+#[test]
+fn test02_sliding_open_domain() {
+  let domain = Interval::open(LimitValue::Limit(0), LimitValue::Limit(7));
+  let windows = windows::sliding(&domain, 3, 3).into_vec();
+  assert_eq!(
+    windows,
+    vec![
+      Interval::open(LimitValue::Limit(0), LimitValue::Limit(3)),
+      closed_open(3, 6),
+      closed_open(6, 7)
+    ]
+  );
+  let windows = windows::sliding(&domain, 8, 2).into_vec();
+  assert_eq!(
+    windows,
+    vec![
+      Interval::open(LimitValue::Limit(0), LimitValue::Limit(7)),
+      closed_open(2, 7),
+      closed_open(4, 7),
+      closed_open(6, 7)
+    ]
+  );
+}
+
+#[test]
+#[should_panic]
+fn test03_sliding_zero_stride() {
+  let domain = Interval::closed(LimitValue::Limit(0), LimitValue::Limit(10));
+  windows::sliding(&domain, 4, 0);
+}
+
+#[test]
+#[should_panic]
+fn test04_sliding_unbounded() {
+  windows::sliding(&Interval::and_more(LimitValue::Limit(0)), 4, 2);
+}
+
+#[test]
+fn test05_tumbling() {
+  let domain = Interval::closed(LimitValue::Limit(0), LimitValue::Limit(10));
+  let windows = windows::tumbling(&domain, 4).into_vec();
+  assert_eq!(
+    windows,
+    vec![
+      closed_open(0, 4),
+      closed_open(4, 8),
+      Interval::closed(LimitValue::Limit(8), LimitValue::Limit(10))
+    ]
+  );
+  let domain = closed_open(0, 8);
+  assert_eq!(
+    windows::tumbling(&domain, 4).into_vec(),
+    vec![closed_open(0, 4), closed_open(4, 8)]
+  );
+}
+
+#[test]
+fn test06_tumbling_aligned() {
+  let domain = closed_open(7, 25);
+  let windows = windows::tumbling_aligned(&domain, 10, 0).into_vec();
+  assert_eq!(
+    windows,
+    vec![closed_open(7, 10), closed_open(10, 20), closed_open(20, 25)]
+  );
+  let domain = closed_open(-7, 5);
+  let windows = windows::tumbling_aligned(&domain, 10, 3).into_vec();
+  assert_eq!(windows, vec![closed_open(-7, 3), closed_open(3, 5)]);
+}
+
+#[test]
+fn test07_hopping() {
+  let domain = closed_open(0, 6);
+  let windows = windows::hopping(&domain, 4, 2).into_vec();
+  assert_eq!(
+    windows,
+    vec![closed_open(0, 4), closed_open(2, 6), closed_open(4, 6)]
+  );
+}
+
+#[test]
+fn test08_hopping_aligned() {
+  let domain = closed_open(5, 12);
+  let windows = windows::hopping_aligned(&domain, 6, 3, 0).into_vec();
+  assert_eq!(
+    windows,
+    vec![
+      closed_open(5, 6),
+      closed_open(5, 9),
+      closed_open(6, 12),
+      closed_open(9, 12)
+    ]
+  );
+}
+
+#[test]
+fn test09_sessions() {
+  let sessions = windows::sessions(vec![12, 1, 3, 30, 4, 15, 9], 4).into_vec();
+  assert_eq!(
+    sessions,
+    vec![
+      Interval::closed(LimitValue::Limit(1), LimitValue::Limit(4)),
+      Interval::closed(LimitValue::Limit(9), LimitValue::Limit(15)),
+      Interval::closed(LimitValue::Limit(30), LimitValue::Limit(30))
+    ]
+  );
+  assert!(windows::sessions(Vec::<i32>::new(), 4).is_empty());
+}
+
+#[test]
+fn test10_sessions_of() {
+  let sessions = windows::sessions_of(
+    vec![
+      closed_open(0, 2),
+      closed_open(3, 5),
+      closed_open(1, 4),
+      closed_open(10, 11),
+      closed_open(8, 9),
+    ],
+    3,
+  )
+  .into_vec();
+  assert_eq!(sessions, vec![closed_open(0, 5), closed_open(8, 11)]);
+}
+
+#[test]
+fn test11_resample_coverage() {
+  let availability = IntervalSeq::new(vec![closed_open(0, 30), closed_open(90, 150)]);
+  let bins = windows::tumbling(&closed_open(0, 180), 60);
+  assert_eq!(
+    windows::resample_coverage(&availability, bins),
+    vec![
+      (closed_open(0, 60), 0.5),
+      (closed_open(60, 120), 0.5),
+      (closed_open(120, 180), 0.5)
+    ]
+  );
+}
+
+#[test]
+fn test12_resample_values() {
+  let mut usage = IntervalMap::new();
+  usage.insert(closed_open(0, 40), 80);
+  usage.insert(closed_open(40, 50), 5);
+  usage.insert(Interval::single(75), 3);
+  let bins = windows::tumbling(&closed_open(0, 100), 25);
+  assert_eq!(
+    windows::resample_values(&usage, bins),
+    vec![
+      (closed_open(0, 25), 50.0),
+      (closed_open(25, 50), 35.0),
+      (closed_open(50, 75), 0.0),
+      (closed_open(75, 100), 3.0)
+    ]
+  );
+}
+
+#[test]
+fn test13_unsigned_domain_from_zero() {
+  let closed_open = |lower: u32, upper: u32| {
+    Interval::over(
+      LimitValue::Limit(lower),
+      true,
+      LimitValue::Limit(upper),
+      false,
+    )
+  };
+  let domain = Interval::closed(LimitValue::Limit(0u32), LimitValue::Limit(10));
+  assert_eq!(
+    windows::tumbling(&domain, 5u32).into_vec(),
+    vec![
+      closed_open(0, 5),
+      closed_open(5, 10),
+      Interval::closed(LimitValue::Limit(10), LimitValue::Limit(10))
+    ]
+  );
+  assert_eq!(
+    windows::hopping(&domain, 5u32, 5).into_vec(),
+    windows::tumbling(&domain, 5u32).into_vec()
+  );
+  let domain = closed_open(0, 10);
+  assert_eq!(
+    windows::tumbling_aligned(&domain, 4u32, 3).into_vec(),
+    vec![closed_open(0, 3), closed_open(3, 7), closed_open(7, 10)]
+  );
+  assert_eq!(
+    windows::hopping_aligned(&domain, 6u32, 3, 0).into_vec(),
+    vec![
+      closed_open(0, 3),
+      closed_open(0, 6),
+      closed_open(3, 9),
+      closed_open(6, 10),
+      closed_open(9, 10)
+    ]
+  );
+  let domain = Interval::closed(LimitValue::Limit(0usize), LimitValue::Limit(8));
+  assert_eq!(windows::tumbling(&domain, 4usize).len(), 3);
+}
+
+#[test]
+fn test14_domain_near_max() {
+  let domain = Interval::closed(LimitValue::Limit(250u8), LimitValue::Limit(u8::MAX));
+  assert_eq!(
+    windows::hopping(&domain, 4u8, 3).into_vec(),
+    vec![
+      Interval::over(LimitValue::Limit(250), true, LimitValue::Limit(254), false),
+      Interval::closed(LimitValue::Limit(253), LimitValue::Limit(u8::MAX)),
+    ]
+  );
+  assert_eq!(
+    windows::sliding(&domain, 4u8, 3).into_vec(),
+    windows::hopping(&domain, 4u8, 3).into_vec()
+  );
+  assert_eq!(windows::tumbling(&domain, 2u8).len(), 3);
+  let domain = Interval::closed(
+    LimitValue::Limit(i64::MAX - 10),
+    LimitValue::Limit(i64::MAX),
+  );
+  assert_eq!(windows::tumbling(&domain, 5i64).len(), 3);
+}